@@ -2,6 +2,7 @@ mod toolbox_cli;
 mod toolbox_cli_command_account;
 mod toolbox_cli_command_execution;
 mod toolbox_cli_command_find;
+mod toolbox_cli_command_grind;
 mod toolbox_cli_command_history;
 mod toolbox_cli_command_instruction;
 mod toolbox_cli_command_pda;
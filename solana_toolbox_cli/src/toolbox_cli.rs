@@ -10,6 +10,7 @@ use solana_toolbox_endpoint::ToolboxEndpoint;
 use crate::toolbox_cli_command_account::ToolboxCliCommandAccountArgs;
 use crate::toolbox_cli_command_execution::ToolboxCliCommandExecutionArgs;
 use crate::toolbox_cli_command_find::ToolboxCliCommandFindArgs;
+use crate::toolbox_cli_command_grind::ToolboxCliCommandGrindArgs;
 use crate::toolbox_cli_command_history::ToolboxCliCommandHistoryArgs;
 use crate::toolbox_cli_command_instruction::ToolboxCliCommandInstructionArgs;
 use crate::toolbox_cli_command_pda::ToolboxCliCommandPdaArgs;
@@ -112,6 +113,7 @@ pub enum ToolboxCliCommand {
     Account(ToolboxCliCommandAccountArgs),
     Execution(ToolboxCliCommandExecutionArgs),
     Find(ToolboxCliCommandFindArgs),
+    Grind(ToolboxCliCommandGrindArgs),
     History(ToolboxCliCommandHistoryArgs),
     Instruction(ToolboxCliCommandInstructionArgs),
     Pda(ToolboxCliCommandPdaArgs),
@@ -126,6 +128,7 @@ impl ToolboxCliCommand {
             ToolboxCliCommand::Account(args) => args.process(context).await,
             ToolboxCliCommand::Execution(args) => args.process(context).await,
             ToolboxCliCommand::Find(args) => args.process(context).await,
+            ToolboxCliCommand::Grind(args) => args.process(context).await,
             ToolboxCliCommand::History(args) => args.process(context).await,
             ToolboxCliCommand::Instruction(args) => args.process(context).await,
             ToolboxCliCommand::Pda(args) => args.process(context).await,
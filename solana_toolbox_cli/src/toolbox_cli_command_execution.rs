@@ -3,6 +3,7 @@ use clap::Args;
 use serde_json::json;
 use serde_json::Map;
 use serde_json::Value;
+use solana_toolbox_endpoint::ToolboxEndpoint;
 
 use crate::toolbox_cli_context::ToolboxCliContext;
 
@@ -24,8 +25,56 @@ impl ToolboxCliCommandExecutionArgs {
         let mut idl_service = context.create_service().await?;
         let signature = context.parse_signature(&self.signature)?;
         let execution = endpoint.get_execution(&signature).await?;
+        let mut json_events = vec![];
+        for event_info in idl_service
+            .infer_and_decode_execution_events(&mut endpoint, &execution)
+            .await?
+        {
+            let event_value = match event_info.value {
+                Ok(event_value) => event_value,
+                Err(error) => json!({
+                    "data": ToolboxEndpoint::encode_base16(&event_info.data),
+                    "decode_error": context.compute_error_json(error),
+                }),
+            };
+            json_events.push(json!({
+                "program_id": event_info.program_id.to_string(),
+                "name": context.compute_event_name(
+                    &event_info.program,
+                    &event_info.event
+                ),
+                "value": event_value,
+                "instruction_index": event_info.instruction_index,
+                "cpi_path": event_info
+                    .cpi_path
+                    .iter()
+                    .map(|program_id| program_id.to_string())
+                    .collect::<Vec<_>>(),
+            }));
+        }
+        let json_error = idl_service
+            .infer_and_decode_execution_error(&mut endpoint, &execution)
+            .await?
+            .map(|error_info| {
+                json!({
+                    "program_id": error_info.program_id.to_string(),
+                    "name": context.compute_error_name(
+                        &error_info.program,
+                        &error_info.error
+                    ),
+                    "code": error_info.error.code,
+                    "msg": error_info.error.msg,
+                    "docs": error_info.error.docs,
+                    "instruction_index": error_info.instruction_index,
+                })
+            });
+        let returns_values = idl_service
+            .infer_and_decode_execution_returns(&mut endpoint, &execution)
+            .await?;
         let mut json_instructions = vec![];
-        for instruction in execution.instructions {
+        for (instruction, return_value) in
+            execution.instructions.into_iter().zip(returns_values)
+        {
             let instruction_info = idl_service
                 .infer_and_decode_instruction(&mut endpoint, &instruction)
                 .await?; // TODO - better error handling
@@ -46,6 +95,13 @@ impl ToolboxCliCommandExecutionArgs {
                     }),
                 );
             }
+            let return_value = match return_value {
+                Some(Ok(return_value)) => return_value,
+                Some(Err(error)) => json!({
+                    "decode_error": context.compute_error_json(error),
+                }),
+                None => Value::Null,
+            };
             json_instructions.push(json!({
                 "program_id": instruction.program_id.to_string(),
                 "name": context.compute_instruction_name(
@@ -54,13 +110,16 @@ impl ToolboxCliCommandExecutionArgs {
                 ),
                 "payload": instruction_info.payload,
                 "addresses": json_addresses,
+                "return": return_value,
             }));
         }
         Ok(json!({
             "payer": execution.payer.to_string(),
             "instructions": json_instructions,
-            "logs": execution.logs, // TODO (MEDIUM) - parse events from logs (39VGeYvhykSQnAFyqARriRrU1DLjGj1jLstRoDShxK4EG5SNQtPM6NQM8dLBwt5kbn7poRn3cJj4xFPL1uKABz3h)
-            "error": execution.error, // TODO (MEDIUM) - could parse the error using the code
+            "events": json_events,
+            "logs": execution.logs,
+            "error": execution.error,
+            "error_decoded": json_error,
             "units_consumed": execution.units_consumed,
         }))
     }
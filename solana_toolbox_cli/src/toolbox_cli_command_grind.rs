@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use serde_json::Value;
+use solana_sdk::signature::write_keypair_file;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointGrindOptions;
+use solana_toolbox_endpoint::ToolboxEndpointGrindPattern;
+use tokio::task::spawn_blocking;
+
+use crate::toolbox_cli_context::ToolboxCliContext;
+
+#[derive(Debug, Clone, Args)]
+#[command(about = "Grind a vanity keypair or a Pda's seed")]
+pub struct ToolboxCliCommandGrindArgs {
+    #[arg(
+        long = "prefix",
+        alias = "starts-with",
+        value_name = "BASE58_PREFIX",
+        help = "The address must start with this prefix"
+    )]
+    prefix: Option<String>,
+    #[arg(
+        long = "suffix",
+        alias = "ends-with",
+        value_name = "BASE58_SUFFIX",
+        help = "The address must end with this suffix"
+    )]
+    suffix: Option<String>,
+    #[arg(
+        long = "ignore-case",
+        help = "Match the prefix and suffix case-insensitively"
+    )]
+    ignore_case: bool,
+    #[arg(
+        long = "threads",
+        value_name = "COUNT",
+        help = "Number of threads used (defaults to all available cores)"
+    )]
+    threads: Option<usize>,
+    #[arg(
+        long = "output",
+        alias = "outfile",
+        value_name = "KEYPAIR_FILE_PATH",
+        help = "Save the grinded keypair to a file"
+    )]
+    output: Option<String>,
+    #[arg(
+        long = "print-keypair",
+        help = "Print the grinded keypair's secret key in the output"
+    )]
+    print_keypair: bool,
+    #[arg(
+        long = "pda-program-id",
+        value_name = "PROGRAM_ID",
+        help = "Grind a Pda's seed for this ProgramId instead of a keypair"
+    )]
+    pda_program_id: Option<String>,
+    #[arg(
+        long = "pda-seed-before",
+        value_name = "SEED_TYPE:SEED_VALUE",
+        help = "The Pda's seeds placed before the grinded u64 seed"
+    )]
+    pda_seeds_before: Vec<String>,
+    #[arg(
+        long = "pda-seed-after",
+        value_name = "SEED_TYPE:SEED_VALUE",
+        help = "The Pda's seeds placed after the grinded u64 seed"
+    )]
+    pda_seeds_after: Vec<String>,
+    #[arg(
+        long = "pda-bump",
+        value_name = "BUMP",
+        help = "The Pda's bump must match this value"
+    )]
+    pda_bump: Option<u8>,
+}
+
+impl ToolboxCliCommandGrindArgs {
+    pub async fn process(&self, context: &ToolboxCliContext) -> Result<Value> {
+        let pattern = ToolboxEndpointGrindPattern {
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            ignore_case: self.ignore_case,
+        };
+        let options = ToolboxEndpointGrindOptions {
+            threads: self.threads,
+            progress: Some(Arc::new(|attempts| {
+                eprintln!("Grinding... {} attempts", attempts);
+            })),
+            ..Default::default()
+        };
+
+        let Some(pda_program_id) = &self.pda_program_id else {
+            if self.output.is_none() && !self.print_keypair {
+                return Err(anyhow!(
+                    "Grinding a keypair requires --output or --print-keypair"
+                ));
+            }
+            let keypair = spawn_blocking(move || {
+                ToolboxEndpoint::grind_keypair(&pattern, &options)
+            })
+            .await??
+            .ok_or_else(|| anyhow!("Grinding was cancelled"))?;
+            if let Some(output) = &self.output {
+                write_keypair_file(&keypair, output)
+                    .map_err(|error| anyhow!("{}", error))
+                    .context("Write Keypair File")?;
+            }
+            return Ok(json!({
+                "address": keypair.pubkey().to_string(),
+                "keypair": self.print_keypair.then(|| keypair.to_base58_string()),
+                "output": self.output,
+            }));
+        };
+
+        let program_id = context
+            .parse_key(pda_program_id)
+            .context("Parse ProgramId")?
+            .address();
+
+        let typedefs = HashMap::new();
+        let mut seeds_before_bytes = vec![];
+        for seed in &self.pda_seeds_before {
+            seeds_before_bytes.push(context.parse_seed(seed, &typedefs)?.2);
+        }
+        let mut seeds_after_bytes = vec![];
+        for seed in &self.pda_seeds_after {
+            seeds_after_bytes.push(context.parse_seed(seed, &typedefs)?.2);
+        }
+        let pda_bump = self.pda_bump;
+
+        let (seed_counter, address, bump) = spawn_blocking(move || {
+            let seeds_before_slices = seeds_before_bytes
+                .iter()
+                .map(|seed_bytes| &seed_bytes[..])
+                .collect::<Vec<_>>();
+            let seeds_after_slices = seeds_after_bytes
+                .iter()
+                .map(|seed_bytes| &seed_bytes[..])
+                .collect::<Vec<_>>();
+            ToolboxEndpoint::grind_pda(
+                &program_id,
+                &seeds_before_slices,
+                &seeds_after_slices,
+                &pattern,
+                pda_bump,
+                &options,
+            )
+        })
+        .await??
+        .ok_or_else(|| anyhow!("Grinding was cancelled"))?;
+
+        Ok(json!({
+            "seed": {
+                "value": seed_counter,
+                "type": "u64",
+                "bytes": {
+                    "base16": ToolboxEndpoint::encode_base16(
+                        &seed_counter.to_le_bytes()
+                    ),
+                },
+            },
+            "pda": {
+                "address": address.to_string(),
+                "bump": bump,
+            }
+        }))
+    }
+}
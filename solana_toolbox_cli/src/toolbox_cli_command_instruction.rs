@@ -7,6 +7,7 @@ use clap::Args;
 use serde_json::json;
 use serde_json::Map;
 use serde_json::Value;
+use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 use solana_toolbox_idl::ToolboxIdlPath;
@@ -196,7 +197,7 @@ impl ToolboxCliCommandInstructionArgs {
                         .serialize(),
                     )),
                 );
-                let mut signers = vec![];
+                let mut signers: Vec<&dyn Signer> = vec![];
                 for key in instruction_keys.values() {
                     if let Some(signer) = key.signer() {
                         signers.push(signer);
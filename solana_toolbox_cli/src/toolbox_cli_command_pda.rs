@@ -8,7 +8,6 @@ use serde_json::json;
 use serde_json::Value;
 use solana_sdk::pubkey::Pubkey;
 use solana_toolbox_endpoint::ToolboxEndpoint;
-use solana_toolbox_idl::ToolboxIdlTypeFlat;
 use solana_toolbox_idl::ToolboxIdlTypedef;
 
 use crate::toolbox_cli_context::ToolboxCliContext;
@@ -57,35 +56,19 @@ impl ToolboxCliCommandPdaArgs {
         let mut json_seeds = vec![];
         let mut seeds_bytes = vec![];
         for seed in &self.seeds {
-            if let Some((seed_type, seed_value)) = seed.split_once(":") {
-                let seed_type_flat = ToolboxIdlTypeFlat::try_parse(
-                    &context
-                        .parse_hjson(seed_type)
-                        .context("Parse Seed Type JSON")?,
-                )?;
-                let seed_type_full =
-                    seed_type_flat.try_hydrate(&HashMap::new(), &typedefs)?;
-                let seed_value = context
-                    .parse_hjson(seed_value)
-                    .context("Parse Seed Value JSON")?;
-                let mut seed_bytes = vec![];
-                seed_type_full.try_serialize(
-                    &seed_value,
-                    &mut seed_bytes,
-                    false,
-                )?;
-                json_seeds.push(json!({
-                    "value": seed_value,
-                    "type": seed_type_full.explained(),
-                    "bytes": {
-                        "base16": ToolboxEndpoint::encode_base16(&seed_bytes),
-                        "base58": ToolboxEndpoint::encode_base58(&seed_bytes),
-                        "base64": ToolboxEndpoint::encode_base64(&seed_bytes),
-                        "utf8_lossy": String::from_utf8_lossy(&seed_bytes)
-                    }
-                }));
-                seeds_bytes.push(seed_bytes);
-            }
+            let (seed_type_full, seed_value, seed_bytes) =
+                context.parse_seed(seed, &typedefs)?;
+            json_seeds.push(json!({
+                "value": seed_value,
+                "type": seed_type_full.explained(),
+                "bytes": {
+                    "base16": ToolboxEndpoint::encode_base16(&seed_bytes),
+                    "base58": ToolboxEndpoint::encode_base58(&seed_bytes),
+                    "base64": ToolboxEndpoint::encode_base64(&seed_bytes),
+                    "utf8_lossy": String::from_utf8_lossy(&seed_bytes)
+                }
+            }));
+            seeds_bytes.push(seed_bytes);
         }
 
         let mut seeds_slices = vec![];
@@ -1,10 +1,14 @@
+use std::collections::HashMap;
 use std::fs::exists;
 use std::fs::read_to_string;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Error;
 use anyhow::Result;
+use rpassword::prompt_password;
 use serde_json::json;
 use serde_json::Value;
 use solana_sdk::commitment_config::CommitmentConfig;
@@ -14,9 +18,14 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signature;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 use solana_toolbox_idl::ToolboxIdlAccount;
+use solana_toolbox_idl::ToolboxIdlError;
+use solana_toolbox_idl::ToolboxIdlEvent;
 use solana_toolbox_idl::ToolboxIdlInstruction;
 use solana_toolbox_idl::ToolboxIdlProgram;
 use solana_toolbox_idl::ToolboxIdlService;
+use solana_toolbox_idl::ToolboxIdlTypeFlat;
+use solana_toolbox_idl::ToolboxIdlTypeFull;
+use solana_toolbox_idl::ToolboxIdlTypedef;
 
 use crate::toolbox_cli_key::ToolboxCliKey;
 
@@ -91,6 +100,9 @@ impl ToolboxCliContext {
         {
             return Ok(ToolboxCliKey::Keypair(self.get_keypair()));
         }
+        if key == "ASK" || key.starts_with("prompt:") {
+            return Ok(ToolboxCliKey::Keypair(self.prompt_keypair(key)?));
+        }
         if exists(key)? {
             return Ok(ToolboxCliKey::Keypair(self.load_keypair(key)));
         }
@@ -113,6 +125,30 @@ impl ToolboxCliContext {
         Ok(Signature::from_str(value)?)
     }
 
+    pub fn parse_seed(
+        &self,
+        seed: &str,
+        typedefs: &HashMap<String, Arc<ToolboxIdlTypedef>>,
+    ) -> Result<(ToolboxIdlTypeFull, Value, Vec<u8>)> {
+        let (seed_type, seed_value) =
+            seed.split_once(":").ok_or_else(|| {
+                anyhow!("Invalid seed, expected SEED_TYPE:SEED_VALUE: {}", seed)
+            })?;
+        let seed_type_flat = ToolboxIdlTypeFlat::try_parse(
+            &self
+                .parse_hjson(seed_type)
+                .context("Parse Seed Type JSON")?,
+        )?;
+        let seed_type_full =
+            seed_type_flat.try_hydrate(&HashMap::new(), typedefs)?;
+        let seed_value = self
+            .parse_hjson(seed_value)
+            .context("Parse Seed Value JSON")?;
+        let mut seed_bytes = vec![];
+        seed_type_full.try_serialize(&seed_value, &mut seed_bytes, false)?;
+        Ok((seed_type_full, seed_value, seed_bytes))
+    }
+
     pub fn parse_hjson(&self, value: &str) -> Result<Value> {
         Ok(serde_hjson::from_str::<Value>(value)?)
     }
@@ -125,6 +161,40 @@ impl ToolboxCliContext {
         read_keypair_file(path).unwrap()
     }
 
+    pub fn prompt_keypair(&self, key: &str) -> Result<Keypair> {
+        let mnemonic =
+            self.prompt_secret(&format!("[{}] seed phrase: ", key))?;
+        let passphrase = self.prompt_secret(
+            "If this seed phrase has an associated passphrase, enter it now. Otherwise, press ENTER to continue: ",
+        )?;
+        if key == "ASK" {
+            return ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic_legacy(
+                &mnemonic,
+                &passphrase,
+            );
+        }
+        let query = key.split_once('?').map(|(_, query)| query).unwrap_or("");
+        let mut derivation_path = None;
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            derivation_path = match param.split_once('=') {
+                Some(("key", value)) => Some(value.to_string()),
+                Some(("full-path", value)) => Some(value.to_string()),
+                _ => {
+                    return Err(anyhow!("Invalid prompt parameter: {}", param))
+                },
+            };
+        }
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+            &mnemonic,
+            &passphrase,
+            derivation_path.as_deref(),
+        )
+    }
+
+    fn prompt_secret(&self, message: &str) -> Result<String> {
+        Ok(prompt_password(message)?)
+    }
+
     pub fn compute_error_json(&self, error: Error) -> Value {
         json!(error
             .chain()
@@ -143,6 +213,28 @@ impl ToolboxCliContext {
         format!("?.{}", account.name)
     }
 
+    pub fn compute_error_name(
+        &self,
+        program: &ToolboxIdlProgram,
+        error: &ToolboxIdlError,
+    ) -> String {
+        if let Some(program_name) = &program.metadata.name {
+            return format!("{}.{}", program_name, error.name);
+        }
+        format!("?.{}", error.name)
+    }
+
+    pub fn compute_event_name(
+        &self,
+        program: &ToolboxIdlProgram,
+        event: &ToolboxIdlEvent,
+    ) -> String {
+        if let Some(program_name) = &program.metadata.name {
+            return format!("{}.{}", program_name, event.name);
+        }
+        format!("?.{}", event.name)
+    }
+
     pub fn compute_instruction_name(
         &self,
         program: &ToolboxIdlProgram,
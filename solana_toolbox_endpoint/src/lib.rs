@@ -1,5 +1,6 @@
 mod toolbox_endpoint;
 mod toolbox_endpoint_execution;
+mod toolbox_endpoint_execution_flame;
 mod toolbox_endpoint_factory_common;
 mod toolbox_endpoint_factory_program_test;
 mod toolbox_endpoint_factory_rpc_client;
@@ -13,18 +14,24 @@ mod toolbox_endpoint_proxy_rpc_client_get_execution;
 mod toolbox_endpoint_proxy_rpc_client_search_addresses;
 mod toolbox_endpoint_proxy_rpc_client_search_signatures;
 mod toolbox_endpoint_proxy_rpc_client_simulate_transaction;
+mod toolbox_endpoint_remote_signer;
 mod toolbox_endpoint_util_account;
 mod toolbox_endpoint_util_address_lookup_table;
 mod toolbox_endpoint_util_compute_budget;
 mod toolbox_endpoint_util_consts;
 mod toolbox_endpoint_util_encoding;
 mod toolbox_endpoint_util_explorer;
+mod toolbox_endpoint_util_fee;
+mod toolbox_endpoint_util_grind;
 mod toolbox_endpoint_util_hash;
 mod toolbox_endpoint_util_instruction_decompile;
 mod toolbox_endpoint_util_instruction_process;
 mod toolbox_endpoint_util_instruction_simulate;
 mod toolbox_endpoint_util_print;
 mod toolbox_endpoint_util_program;
+#[cfg(feature = "has_loader_v4")]
+mod toolbox_endpoint_util_program_loader_v4;
+mod toolbox_endpoint_util_program_verify;
 mod toolbox_endpoint_util_spl_associated_token;
 mod toolbox_endpoint_util_spl_token;
 mod toolbox_endpoint_util_spl_token_metaplex_metadata;
@@ -38,10 +45,36 @@ mod toolbox_endpoint_util_versioned_transaction;
 pub use solana_program_test::processor as solana_program_test_processor;
 pub use toolbox_endpoint::ToolboxEndpoint;
 pub use toolbox_endpoint_execution::ToolboxEndpointExecution;
+pub use toolbox_endpoint_execution::ToolboxEndpointExecutionInnerInstruction;
 pub use toolbox_endpoint_execution::ToolboxEndpointExecutionStep;
 pub use toolbox_endpoint_execution::ToolboxEndpointExecutionStepCall;
+pub use toolbox_endpoint_execution_flame::ToolboxEndpointExecutionFlame;
 pub use toolbox_endpoint_factory_program_test::ToolboxEndpointProgramTestBuiltinProgram;
+pub use toolbox_endpoint_factory_program_test::ToolboxEndpointProgramTestOptions;
 pub use toolbox_endpoint_factory_program_test::ToolboxEndpointProgramTestPreloadedProgram;
+pub use toolbox_endpoint_factory_program_test::ToolboxEndpointProgramTestUpgradeableProgram;
 pub use toolbox_endpoint_logger::ToolboxEndpointLogger;
 pub use toolbox_endpoint_logger_history::ToolboxEndpointLoggerHistory;
 pub use toolbox_endpoint_logger_printer::ToolboxEndpointLoggerPrinter;
+pub use toolbox_endpoint_remote_signer::ToolboxEndpointRemoteSigner;
+pub use toolbox_endpoint_remote_signer::ToolboxEndpointRemoteSignerCallback;
+pub use toolbox_endpoint_util_compute_budget::ToolboxEndpointComputeBudget;
+pub use toolbox_endpoint_util_explorer::ToolboxEndpointCluster;
+pub use toolbox_endpoint_util_explorer::ToolboxEndpointExplorer;
+pub use toolbox_endpoint_util_fee::ToolboxEndpointFee;
+pub use toolbox_endpoint_util_grind::ToolboxEndpointGrindOptions;
+pub use toolbox_endpoint_util_grind::ToolboxEndpointGrindPattern;
+pub use toolbox_endpoint_util_grind::ToolboxEndpointGrindProgressCallback;
+pub use toolbox_endpoint_util_program::ToolboxEndpointProgramBufferWriteReport;
+pub use toolbox_endpoint_util_program_verify::ToolboxEndpointProgramVerification;
+pub use toolbox_endpoint_util_program_verify::ToolboxEndpointProgramVerificationDiff;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexEdition;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMasterEdition;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadata;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataCollection;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataCollectionDetails;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataCreator;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataProgrammableConfig;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataTokenStandard;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataUseMethod;
+pub use toolbox_endpoint_util_spl_token_metaplex_metadata::ToolboxEndpointSplTokenMetaplexMetadataUses;
@@ -3,7 +3,9 @@ use std::collections::HashSet;
 use anyhow::Result;
 use solana_sdk::account::Account;
 use solana_sdk::hash::Hash;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::Transaction;
 use solana_sdk::transaction::VersionedTransaction;
@@ -15,6 +17,8 @@ use crate::toolbox_endpoint_proxy::ToolboxEndpointProxy;
 pub struct ToolboxEndpoint {
     proxy: Box<dyn ToolboxEndpointProxy>,
     loggers: Vec<Box<dyn ToolboxEndpointLogger>>,
+    keypairs_seed: Option<String>,
+    keypairs_generated: u64,
 }
 
 impl From<Box<dyn ToolboxEndpointProxy>> for ToolboxEndpoint {
@@ -22,6 +26,8 @@ impl From<Box<dyn ToolboxEndpointProxy>> for ToolboxEndpoint {
         ToolboxEndpoint {
             proxy,
             loggers: vec![],
+            keypairs_seed: None,
+            keypairs_generated: 0,
         }
     }
 }
@@ -31,6 +37,28 @@ impl ToolboxEndpoint {
         self.loggers.push(logger);
     }
 
+    pub fn set_keypairs_seed(&mut self, keypairs_seed: Option<&str>) {
+        self.keypairs_seed = keypairs_seed.map(|seed| seed.to_string());
+        self.keypairs_generated = 0;
+    }
+
+    pub fn generate_keypair(&mut self) -> Keypair {
+        let Some(keypairs_seed) = &self.keypairs_seed else {
+            return Keypair::new();
+        };
+        let keypair = ToolboxEndpoint::keypair_from_seed_string_hash(&format!(
+            "{}:{}",
+            keypairs_seed, self.keypairs_generated
+        ))
+        .unwrap();
+        self.keypairs_generated += 1;
+        keypair
+    }
+
+    pub async fn get_genesis_hash(&mut self) -> Result<Hash> {
+        self.proxy.get_genesis_hash().await
+    }
+
     pub async fn get_latest_blockhash(&mut self) -> Result<Hash> {
         self.proxy.get_latest_blockhash().await
     }
@@ -57,6 +85,13 @@ impl ToolboxEndpoint {
         self.proxy.get_accounts(addresses).await
     }
 
+    pub async fn get_fee_for_message(
+        &mut self,
+        message: &VersionedMessage,
+    ) -> Result<Option<u64>> {
+        self.proxy.get_fee_for_message(message).await
+    }
+
     pub async fn simulate_transaction(
         &mut self,
         transaction: Transaction,
@@ -103,6 +138,23 @@ impl ToolboxEndpoint {
         Ok(processed)
     }
 
+    pub async fn process_versioned_transactions(
+        &mut self,
+        versioned_transactions: Vec<VersionedTransaction>,
+        verify_prelight: bool,
+    ) -> Result<Vec<Result<(Signature, ToolboxEndpointExecution)>>> {
+        let results = self
+            .proxy
+            .process_transactions(versioned_transactions, verify_prelight)
+            .await?;
+        for processed in results.iter().flatten() {
+            for logger in &self.loggers {
+                logger.on_processed(processed).await;
+            }
+        }
+        Ok(results)
+    }
+
     pub async fn request_airdrop(
         &mut self,
         to: &Pubkey,
@@ -145,6 +197,14 @@ impl ToolboxEndpoint {
             .await
     }
 
+    pub async fn set_sysvar_account(
+        &mut self,
+        address: &Pubkey,
+        data: &[u8],
+    ) -> Result<()> {
+        self.proxy.set_sysvar_account(address, data).await
+    }
+
     pub async fn forward_clock_unix_timestamp(
         &mut self,
         unix_timestamp_delta: u64,
@@ -164,4 +224,19 @@ impl ToolboxEndpoint {
     ) -> Result<()> {
         self.proxy.forward_clock_epoch(epoch_delta).await
     }
+
+    pub async fn set_clock_unix_timestamp(
+        &mut self,
+        unix_timestamp: i64,
+    ) -> Result<()> {
+        self.proxy.set_clock_unix_timestamp(unix_timestamp).await
+    }
+
+    pub async fn warp_to_slot(&mut self, slot: u64) -> Result<()> {
+        self.proxy.warp_to_slot(slot).await
+    }
+
+    pub async fn warp_to_epoch(&mut self, epoch: u64) -> Result<()> {
+        self.proxy.warp_to_epoch(epoch).await
+    }
 }
@@ -15,6 +15,8 @@ pub struct ToolboxEndpointExecution {
     pub payer: Pubkey,
     pub instructions: Vec<Instruction>,
     pub steps: Option<Vec<ToolboxEndpointExecutionStep>>,
+    pub inner_instructions:
+        Option<Vec<Vec<ToolboxEndpointExecutionInnerInstruction>>>,
     pub logs: Option<Vec<String>>,
     pub error: Option<TransactionError>,
     pub units_consumed: Option<u64>,
@@ -28,6 +30,12 @@ pub enum ToolboxEndpointExecutionStep {
     Call(ToolboxEndpointExecutionStepCall),
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolboxEndpointExecutionInnerInstruction {
+    pub instruction: Instruction,
+    pub stack_height: Option<u32>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ToolboxEndpointExecutionStepCall {
     pub program_id: Pubkey,
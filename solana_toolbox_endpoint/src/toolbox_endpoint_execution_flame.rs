@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use serde_json::json;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecutionStep;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecutionStepCall;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolboxEndpointExecutionFlame {
+    pub label: String,
+    pub program_id: Pubkey,
+    pub units_total: u64,
+    pub units_self: u64,
+    pub children: Vec<ToolboxEndpointExecutionFlame>,
+}
+
+impl ToolboxEndpointExecution {
+    pub fn compute_flames(&self) -> Vec<ToolboxEndpointExecutionFlame> {
+        ToolboxEndpointExecutionFlame::compute_from_steps(
+            self.steps.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+impl ToolboxEndpointExecutionFlame {
+    pub fn compute_from_steps(
+        steps: &[ToolboxEndpointExecutionStep],
+    ) -> Vec<ToolboxEndpointExecutionFlame> {
+        let mut flames = vec![];
+        for step in steps {
+            if let ToolboxEndpointExecutionStep::Call(step_call) = step {
+                flames.push(ToolboxEndpointExecutionFlame::compute_from_call(
+                    step_call,
+                ));
+            }
+        }
+        flames
+    }
+
+    pub fn compute_from_call(
+        step_call: &ToolboxEndpointExecutionStepCall,
+    ) -> ToolboxEndpointExecutionFlame {
+        let children =
+            ToolboxEndpointExecutionFlame::compute_from_steps(&step_call.steps);
+        let units_total = step_call
+            .consumed
+            .map(|(consumed, _)| consumed)
+            .unwrap_or(0);
+        let units_children =
+            children.iter().map(|child| child.units_total).sum::<u64>();
+        ToolboxEndpointExecutionFlame {
+            label: step_call.program_id.to_string(),
+            program_id: step_call.program_id,
+            units_total,
+            units_self: units_total.saturating_sub(units_children),
+            children,
+        }
+    }
+
+    // Folded stacks format (as used by flamegraph.pl, inferno and speedscope)
+    pub fn export_folded(flames: &[ToolboxEndpointExecutionFlame]) -> String {
+        let mut units_by_stack = BTreeMap::new();
+        for flame in flames {
+            flame.collect_folded(&mut vec![], &mut units_by_stack);
+        }
+        units_by_stack
+            .into_iter()
+            .filter(|(_, units)| *units > 0)
+            .map(|(stack, units)| format!("{} {}\n", stack, units))
+            .collect()
+    }
+
+    pub fn export_json(flames: &[ToolboxEndpointExecutionFlame]) -> Value {
+        json!(flames
+            .iter()
+            .map(|flame| json!({
+                "label": flame.label,
+                "program_id": flame.program_id.to_string(),
+                "units": {
+                    "total": flame.units_total,
+                    "self": flame.units_self,
+                },
+                "children": ToolboxEndpointExecutionFlame::export_json(
+                    &flame.children
+                ),
+            }))
+            .collect::<Vec<_>>())
+    }
+
+    fn collect_folded(
+        &self,
+        stack: &mut Vec<String>,
+        units_by_stack: &mut BTreeMap<String, u64>,
+    ) {
+        stack.push(self.label.replace(';', ":"));
+        *units_by_stack.entry(stack.join(";")).or_default() += self.units_self;
+        for child in &self.children {
+            child.collect_folded(stack, units_by_stack);
+        }
+        stack.pop();
+    }
+}
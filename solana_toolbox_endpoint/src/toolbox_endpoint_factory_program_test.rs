@@ -1,11 +1,30 @@
+use std::fs::read_dir;
+use std::fs::read_to_string;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
 use solana_program_runtime::invoke_context::BuiltinFunctionWithContext;
+use solana_program_test::find_file;
+use solana_program_test::read_file;
 use solana_program_test::ProgramTest;
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_sdk::clock::Clock;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 use crate::toolbox_endpoint_proxy::ToolboxEndpointProxy;
 use crate::toolbox_endpoint_proxy_program_test_context::ToolboxEndpointProxyProgramTestContext;
+use crate::toolbox_endpoint_util_program::PROGRAM_LOADER_V4_STATUS_FINALIZED;
+
+const PROGRAM_TEST_PAYER_LAMPORTS_DEFAULT: u64 = 1_000_000_000_000_000;
+const PROGRAM_TEST_SEEDED_UNIX_TIMESTAMP: i64 = 1_700_000_000;
 
+#[derive(Clone)]
 pub struct ToolboxEndpointProgramTestBuiltinProgram {
     pub id: Pubkey,
     pub name: &'static str,
@@ -39,16 +58,169 @@ macro_rules! toolbox_endpoint_program_test_builtin_program_anchor {
     };
 }
 
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct ToolboxEndpointProgramTestPreloadedProgram {
     pub id: Pubkey,
     pub path: &'static str,
+    pub loader_id: Option<Pubkey>,
+}
+
+impl ToolboxEndpointProgramTestPreloadedProgram {
+    pub fn new(
+        id: Pubkey,
+        path: &'static str,
+    ) -> ToolboxEndpointProgramTestPreloadedProgram {
+        ToolboxEndpointProgramTestPreloadedProgram {
+            id,
+            path,
+            loader_id: None,
+        }
+    }
+
+    pub fn with_loader_id(
+        mut self,
+        loader_id: Pubkey,
+    ) -> ToolboxEndpointProgramTestPreloadedProgram {
+        self.loader_id = Some(loader_id);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToolboxEndpointProgramTestUpgradeableProgram {
+    pub id: Pubkey,
+    pub upgrade_authority: Option<Pubkey>,
+    pub bytecode: Vec<u8>,
+}
+
+#[derive(Default)]
+pub struct ToolboxEndpointProgramTestOptions {
+    pub builtin_programs: Vec<ToolboxEndpointProgramTestBuiltinProgram>,
+    pub preloaded_programs: Vec<ToolboxEndpointProgramTestPreloadedProgram>,
+    pub upgradeable_programs: Vec<ToolboxEndpointProgramTestUpgradeableProgram>,
+    pub deactivated_features: Vec<Pubkey>,
+    pub compute_max_units: Option<u64>,
+    pub genesis_accounts: Vec<(Pubkey, Account)>,
+    pub genesis_accounts_json_paths: Vec<String>,
+    pub genesis_accounts_json_directories: Vec<String>,
+    pub payer: Option<Keypair>,
+    pub payer_lamports: Option<u64>,
+    pub seed: Option<String>,
+    pub simulated_inner_instructions: bool,
+}
+
+impl ToolboxEndpointProgramTestOptions {
+    pub fn new() -> ToolboxEndpointProgramTestOptions {
+        ToolboxEndpointProgramTestOptions {
+            ..Default::default()
+        }
+    }
+
+    pub fn with_builtin_program(
+        mut self,
+        builtin_program: ToolboxEndpointProgramTestBuiltinProgram,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.builtin_programs.push(builtin_program);
+        self
+    }
+
+    pub fn with_preloaded_program(
+        mut self,
+        preloaded_program: ToolboxEndpointProgramTestPreloadedProgram,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.preloaded_programs.push(preloaded_program);
+        self
+    }
+
+    pub fn with_upgradeable_program(
+        mut self,
+        upgradeable_program: ToolboxEndpointProgramTestUpgradeableProgram,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.upgradeable_programs.push(upgradeable_program);
+        self
+    }
+
+    pub fn with_deactivated_feature(
+        mut self,
+        feature_id: Pubkey,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.deactivated_features.push(feature_id);
+        self
+    }
+
+    pub fn with_compute_max_units(
+        mut self,
+        compute_max_units: u64,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.compute_max_units = Some(compute_max_units);
+        self
+    }
+
+    pub fn with_genesis_account(
+        mut self,
+        address: Pubkey,
+        account: Account,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.genesis_accounts.push((address, account));
+        self
+    }
+
+    pub fn with_genesis_account_json_path(
+        mut self,
+        path: &str,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.genesis_accounts_json_paths.push(path.to_string());
+        self
+    }
+
+    pub fn with_genesis_accounts_json_directory(
+        mut self,
+        directory: &str,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.genesis_accounts_json_directories
+            .push(directory.to_string());
+        self
+    }
+
+    pub fn with_payer(
+        mut self,
+        payer: Keypair,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn with_seed(
+        mut self,
+        seed: &str,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.seed = Some(seed.to_string());
+        self
+    }
+
+    // Processing does not expose the inner instructions on memnet, so they
+    // are taken from a simulation ran just before, doubling the cost
+    pub fn with_simulated_inner_instructions(
+        mut self,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.simulated_inner_instructions = true;
+        self
+    }
+
+    pub fn with_payer_lamports(
+        mut self,
+        payer_lamports: u64,
+    ) -> ToolboxEndpointProgramTestOptions {
+        self.payer_lamports = Some(payer_lamports);
+        self
+    }
 }
 
 impl ToolboxEndpoint {
     pub async fn new_program_test() -> ToolboxEndpoint {
-        ToolboxEndpoint::new_program_test_with_builtin_and_preloaded_programs(
-            &[],
-            &[],
+        ToolboxEndpoint::new_program_test_with_options(
+            ToolboxEndpointProgramTestOptions::new(),
         )
         .await
     }
@@ -77,8 +249,78 @@ impl ToolboxEndpoint {
         builtin_programs: &[ToolboxEndpointProgramTestBuiltinProgram],
         preloaded_programs: &[ToolboxEndpointProgramTestPreloadedProgram],
     ) -> ToolboxEndpoint {
+        ToolboxEndpoint::new_program_test_with_options(
+            ToolboxEndpointProgramTestOptions {
+                builtin_programs: builtin_programs.to_vec(),
+                preloaded_programs: preloaded_programs.to_vec(),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    pub async fn new_program_test_with_cloned_programs(
+        source: &mut ToolboxEndpoint,
+        program_ids: &[Pubkey],
+        preserve_upgrade_authority: bool,
+    ) -> Result<ToolboxEndpoint> {
+        let mut options = ToolboxEndpointProgramTestOptions::new();
+        for upgradeable_program in source
+            .get_program_test_upgradeable_programs(
+                program_ids,
+                preserve_upgrade_authority,
+            )
+            .await?
+        {
+            options = options.with_upgradeable_program(upgradeable_program);
+        }
+        ToolboxEndpoint::try_new_program_test_with_options(options).await
+    }
+
+    pub async fn get_program_test_upgradeable_programs(
+        &mut self,
+        program_ids: &[Pubkey],
+        preserve_upgrade_authority: bool,
+    ) -> Result<Vec<ToolboxEndpointProgramTestUpgradeableProgram>> {
+        let mut upgradeable_programs = vec![];
+        for program_id in program_ids {
+            let (_, upgrade_authority, bytecode) = self
+                .get_program_meta_and_bytecode(program_id)
+                .await
+                .with_context(|| anyhow!("Get Program: {}", program_id))?
+                .ok_or_else(|| {
+                    anyhow!("Could not get account: {} (program)", program_id)
+                })?;
+            upgradeable_programs.push(
+                ToolboxEndpointProgramTestUpgradeableProgram {
+                    id: *program_id,
+                    upgrade_authority: if preserve_upgrade_authority {
+                        upgrade_authority
+                    } else {
+                        None
+                    },
+                    bytecode,
+                },
+            );
+        }
+        Ok(upgradeable_programs)
+    }
+
+    pub async fn new_program_test_with_options(
+        options: ToolboxEndpointProgramTestOptions,
+    ) -> ToolboxEndpoint {
+        ToolboxEndpoint::try_new_program_test_with_options(options)
+            .await
+            .unwrap_or_else(|error| {
+                panic!("Could not create program test: {:#}", error)
+            })
+    }
+
+    pub async fn try_new_program_test_with_options(
+        options: ToolboxEndpointProgramTestOptions,
+    ) -> Result<ToolboxEndpoint> {
         let mut program_test = ProgramTest::default();
-        for builtin_program in builtin_programs {
+        for builtin_program in &options.builtin_programs {
             program_test.add_program(
                 builtin_program.name,
                 builtin_program.id,
@@ -86,20 +328,211 @@ impl ToolboxEndpoint {
             );
         }
         program_test.prefer_bpf(true);
-        for preloaded_program in preloaded_programs {
-            program_test.add_program(
-                preloaded_program.path,
-                preloaded_program.id,
-                None,
+        for preloaded_program in &options.preloaded_programs {
+            let loader_id = preloaded_program
+                .loader_id
+                .unwrap_or(ToolboxEndpoint::BPF_LOADER_2_PROGRAM_ID);
+            if loader_id == ToolboxEndpoint::BPF_LOADER_2_PROGRAM_ID {
+                program_test.add_program(
+                    preloaded_program.path,
+                    preloaded_program.id,
+                    None,
+                );
+            } else if loader_id == ToolboxEndpoint::LOADER_V4_PROGRAM_ID {
+                let program_bytecode = read_file(
+                    find_file(&format!("{}.so", preloaded_program.path))
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Could not find program file: {}",
+                                preloaded_program.path
+                            )
+                        })?,
+                );
+                let mut program_data = vec![];
+                program_data.extend_from_slice(&0u64.to_le_bytes());
+                program_data.extend_from_slice(preloaded_program.id.as_ref());
+                program_data.extend_from_slice(
+                    &PROGRAM_LOADER_V4_STATUS_FINALIZED.to_le_bytes(),
+                );
+                program_data.extend_from_slice(&program_bytecode);
+                program_test.add_account(
+                    preloaded_program.id,
+                    Account {
+                        lamports: Rent::default()
+                            .minimum_balance(program_data.len()),
+                        data: program_data,
+                        owner: ToolboxEndpoint::LOADER_V4_PROGRAM_ID,
+                        executable: true,
+                        rent_epoch: 0,
+                    },
+                );
+            } else {
+                return Err(anyhow!(
+                    "Unsupported preloaded program loader: {}",
+                    loader_id
+                ));
+            }
+        }
+        for upgradeable_program in &options.upgradeable_programs {
+            let program_data_address =
+                ToolboxEndpoint::find_program_data_from_program_id(
+                    &upgradeable_program.id,
+                );
+            let program_account_data =
+                bincode::serialize(&UpgradeableLoaderState::Program {
+                    programdata_address: program_data_address,
+                })
+                .unwrap();
+            let mut program_data_account_data =
+                bincode::serialize(&UpgradeableLoaderState::ProgramData {
+                    slot: 0,
+                    upgrade_authority_address: upgradeable_program
+                        .upgrade_authority,
+                })
+                .unwrap();
+            program_data_account_data.resize(
+                UpgradeableLoaderState::size_of_programdata_metadata(),
+                0,
+            );
+            program_data_account_data
+                .extend_from_slice(&upgradeable_program.bytecode);
+            program_test.add_account(
+                upgradeable_program.id,
+                Account {
+                    lamports: Rent::default()
+                        .minimum_balance(program_account_data.len()),
+                    data: program_account_data,
+                    owner: ToolboxEndpoint::BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+                    executable: true,
+                    rent_epoch: 0,
+                },
+            );
+            program_test.add_account(
+                program_data_address,
+                Account {
+                    lamports: Rent::default()
+                        .minimum_balance(program_data_account_data.len()),
+                    data: program_data_account_data,
+                    owner: ToolboxEndpoint::BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            );
+        }
+        for deactivated_feature in &options.deactivated_features {
+            program_test.deactivate_feature(*deactivated_feature);
+        }
+        if let Some(compute_max_units) = options.compute_max_units {
+            if i64::try_from(compute_max_units).is_err() {
+                return Err(anyhow!(
+                    "Invalid compute max units: {} (must fit in i64)",
+                    compute_max_units
+                ));
+            }
+            program_test.set_compute_max_units(compute_max_units);
+        }
+        for (address, account) in options.genesis_accounts {
+            program_test.add_account(address, account);
+        }
+        let mut genesis_accounts_json_paths =
+            options.genesis_accounts_json_paths.clone();
+        for genesis_accounts_json_directory in
+            &options.genesis_accounts_json_directories
+        {
+            let mut genesis_accounts_json_directory_paths =
+                read_dir(genesis_accounts_json_directory)
+                    .with_context(|| {
+                        anyhow!(
+                            "Could not read accounts directory: {}",
+                            genesis_accounts_json_directory
+                        )
+                    })?
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .filter(|path| {
+                        path.extension()
+                            .is_some_and(|extension| extension == "json")
+                    })
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect::<Vec<_>>();
+            genesis_accounts_json_directory_paths.sort();
+            genesis_accounts_json_paths
+                .extend(genesis_accounts_json_directory_paths);
+        }
+        for genesis_account_json_path in &genesis_accounts_json_paths {
+            let (address, account) =
+                ToolboxEndpoint::sanitize_and_decode_account_json(
+                    &read_to_string(genesis_account_json_path).with_context(
+                        || {
+                            anyhow!(
+                                "Could not read account file: {}",
+                                genesis_account_json_path
+                            )
+                        },
+                    )?,
+                )
+                .with_context(|| {
+                    anyhow!(
+                        "Could not decode account file: {}",
+                        genesis_account_json_path
+                    )
+                })?;
+            program_test.add_account(address, account);
+        }
+        let payer = options.payer.or_else(|| {
+            options.seed.as_ref().map(|seed| {
+                ToolboxEndpoint::keypair_from_seed_string_hash(&format!(
+                    "{}:payer",
+                    seed
+                ))
+                .unwrap()
+            })
+        });
+        if let Some(payer) = &payer {
+            program_test.add_account(
+                payer.pubkey(),
+                Account {
+                    lamports: options
+                        .payer_lamports
+                        .unwrap_or(PROGRAM_TEST_PAYER_LAMPORTS_DEFAULT),
+                    ..Default::default()
+                },
+            );
+        }
+        let mut program_test_context = program_test.start_with_context().await;
+        if options.seed.is_some() {
+            let mut clock = program_test_context
+                .banks_client
+                .get_sysvar::<Clock>()
+                .await
+                .unwrap();
+            clock.unix_timestamp = PROGRAM_TEST_SEEDED_UNIX_TIMESTAMP;
+            clock.epoch_start_timestamp = PROGRAM_TEST_SEEDED_UNIX_TIMESTAMP;
+            program_test_context.set_sysvar(&clock);
+        }
+        if let Some(payer) = payer {
+            program_test_context.payer = payer;
+        } else if let Some(payer_lamports) = options.payer_lamports {
+            program_test_context.set_account(
+                &program_test_context.payer.pubkey(),
+                &Account {
+                    lamports: payer_lamports,
+                    ..Default::default()
+                }
+                .into(),
             );
         }
         let mut proxy_program_test_context =
             ToolboxEndpointProxyProgramTestContext::new(
-                program_test.start_with_context().await,
+                program_test_context,
+                options.simulated_inner_instructions,
             );
         proxy_program_test_context.save_slot_unix_timestamp().await;
         let proxy: Box<dyn ToolboxEndpointProxy> =
             Box::new(proxy_program_test_context);
-        ToolboxEndpoint::from(proxy)
+        let mut endpoint = ToolboxEndpoint::from(proxy);
+        endpoint.set_keypairs_seed(options.seed.as_deref());
+        Ok(endpoint)
     }
 }
@@ -6,6 +6,12 @@ use crate::toolbox_endpoint_proxy::ToolboxEndpointProxy;
 use crate::toolbox_endpoint_proxy_rpc_client::ToolboxEndpointProxyRpcClient;
 
 impl ToolboxEndpoint {
+    pub const GENESIS_HASH_DEVNET: &str =
+        "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
+    pub const GENESIS_HASH_MAINNET_BETA: &str =
+        "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+    pub const GENESIS_HASH_TESTNET: &str =
+        "4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY";
     pub const PUBLIC_RPC_URL_DEVNET: &str = "https://api.devnet.solana.com";
     pub const PUBLIC_RPC_URL_MAINNET_BETA: &str =
         "https://api.mainnet-beta.solana.com";
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use anyhow::Result;
 use solana_sdk::account::Account;
 use solana_sdk::hash::Hash;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
@@ -11,6 +12,8 @@ use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
 
 #[async_trait::async_trait]
 pub trait ToolboxEndpointProxy {
+    async fn get_genesis_hash(&mut self) -> Result<Hash>;
+
     async fn get_latest_blockhash(&mut self) -> Result<Hash>;
 
     async fn get_slot_unix_timestamp(&mut self, slot: u64) -> Result<i64>;
@@ -27,6 +30,11 @@ pub trait ToolboxEndpointProxy {
         addresses: &[Pubkey],
     ) -> Result<Vec<Option<Account>>>;
 
+    async fn get_fee_for_message(
+        &mut self,
+        message: &VersionedMessage,
+    ) -> Result<Option<u64>>;
+
     async fn simulate_transaction(
         &mut self,
         versioned_transaction: VersionedTransaction,
@@ -39,6 +47,12 @@ pub trait ToolboxEndpointProxy {
         verify_prelight: bool,
     ) -> Result<(Signature, ToolboxEndpointExecution)>;
 
+    async fn process_transactions(
+        &mut self,
+        versioned_transactions: Vec<VersionedTransaction>,
+        verify_prelight: bool,
+    ) -> Result<Vec<Result<(Signature, ToolboxEndpointExecution)>>>;
+
     async fn request_airdrop(
         &mut self,
         address: &Pubkey,
@@ -65,6 +79,12 @@ pub trait ToolboxEndpointProxy {
         rewind_until: Option<Signature>,
     ) -> Result<Vec<Signature>>;
 
+    async fn set_sysvar_account(
+        &mut self,
+        address: &Pubkey,
+        data: &[u8],
+    ) -> Result<()>;
+
     async fn forward_clock_unix_timestamp(
         &mut self,
         unix_timestamp_delta: u64,
@@ -73,4 +93,13 @@ pub trait ToolboxEndpointProxy {
     async fn forward_clock_slot(&mut self, slot_delta: u64) -> Result<()>;
 
     async fn forward_clock_epoch(&mut self, epoch_delta: u64) -> Result<()>;
+
+    async fn set_clock_unix_timestamp(
+        &mut self,
+        unix_timestamp: i64,
+    ) -> Result<()>;
+
+    async fn warp_to_slot(&mut self, slot: u64) -> Result<()>;
+
+    async fn warp_to_epoch(&mut self, epoch: u64) -> Result<()>;
 }
@@ -10,44 +10,53 @@ use solana_program_test::ProgramTestBanksClientExt;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::account::Account;
 use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::clock::DEFAULT_TICKS_PER_SECOND;
 use solana_sdk::hash::Hash;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::inner_instruction::InnerInstructionsList;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::signer::Signer;
 use solana_sdk::slot_hashes::SlotHashes;
+use solana_sdk::stake_history::StakeHistory;
 use solana_sdk::system_instruction::transfer;
 use solana_sdk::sysvar::clock::Clock;
+use solana_sdk::sysvar::epoch_rewards::EpochRewards;
+use solana_sdk::sysvar::epoch_schedule::EpochSchedule;
+use solana_sdk::sysvar::last_restart_slot::LastRestartSlot;
+use solana_sdk::sysvar::rent::Rent;
 use solana_sdk::transaction::Transaction;
+use solana_sdk::transaction::TransactionError;
 use solana_sdk::transaction::VersionedTransaction;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecutionInnerInstruction;
 use crate::toolbox_endpoint_proxy::ToolboxEndpointProxy;
 
-const SLOTS_PER_EPOCH: u64 = 432_000;
-const SLOTS_PER_SECOND: u64 = 2;
-const SECONDS_PER_EPOCH: u64 = SLOTS_PER_EPOCH / SLOTS_PER_SECOND;
-
-// TODO - handle 100+ transaction in a single slot failing
 pub struct ToolboxEndpointProxyProgramTestContext {
     program_test_context: ProgramTestContext,
+    blockhash_last_used: Option<Hash>,
     unix_timestamp_by_slot: HashMap<u64, i64>,
     addresses_by_program_id: HashMap<Pubkey, HashSet<Pubkey>>,
     signatures_by_address: HashMap<Pubkey, Vec<Signature>>,
     execution_by_signature: HashMap<Signature, ToolboxEndpointExecution>,
+    simulated_inner_instructions: bool,
 }
 
 impl ToolboxEndpointProxyProgramTestContext {
     pub fn new(
         program_test_context: ProgramTestContext,
+        simulated_inner_instructions: bool,
     ) -> ToolboxEndpointProxyProgramTestContext {
         ToolboxEndpointProxyProgramTestContext {
             program_test_context,
+            blockhash_last_used: None,
             unix_timestamp_by_slot: Default::default(),
             addresses_by_program_id: Default::default(),
             signatures_by_address: Default::default(),
             execution_by_signature: Default::default(),
+            simulated_inner_instructions,
         }
     }
 
@@ -65,8 +74,28 @@ impl ToolboxEndpointProxyProgramTestContext {
 
 #[async_trait::async_trait]
 impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
+    async fn get_genesis_hash(&mut self) -> Result<Hash> {
+        Ok(self.program_test_context.genesis_config().hash())
+    }
+
     async fn get_latest_blockhash(&mut self) -> Result<Hash> {
-        Ok(self.program_test_context.last_blockhash)
+        // Identical transactions would collide if they reused the same blockhash
+        let latest_blockhash = match self.blockhash_last_used {
+            Some(blockhash_last_used) => {
+                self.program_test_context
+                    .banks_client
+                    .get_new_latest_blockhash(&blockhash_last_used)
+                    .await?
+            },
+            None => {
+                self.program_test_context
+                    .banks_client
+                    .get_latest_blockhash()
+                    .await?
+            },
+        };
+        self.program_test_context.last_blockhash = latest_blockhash;
+        Ok(latest_blockhash)
     }
 
     async fn get_slot_unix_timestamp(&mut self, slot: u64) -> Result<i64> {
@@ -111,6 +140,20 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
         Ok(accounts)
     }
 
+    async fn get_fee_for_message(
+        &mut self,
+        message: &VersionedMessage,
+    ) -> Result<Option<u64>> {
+        match message {
+            VersionedMessage::Legacy(message) => Ok(self
+                .program_test_context
+                .banks_client
+                .get_fee_for_message(message.clone())
+                .await?),
+            VersionedMessage::V0(_) => Ok(None),
+        }
+    }
+
     async fn simulate_transaction(
         &mut self,
         versioned_transaction: VersionedTransaction,
@@ -134,9 +177,17 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             .banks_client
             .simulate_transaction(versioned_transaction.clone())
             .await?;
-        let (payer, instructions) = self
-            .resolve_versioned_transaction(&versioned_transaction)
+        let (loaded_writable_addresses, loaded_readonly_addresses) = self
+            .resolve_versioned_transaction_loaded_addresses(
+                &versioned_transaction,
+            )
             .await?;
+        let (payer, instructions) =
+            ToolboxEndpoint::decompile_versioned_transaction_with_loaded_addresses(
+                &versioned_transaction,
+                &loaded_writable_addresses,
+                &loaded_readonly_addresses,
+            )?;
         if let Some(simulation_details) = outcome.simulation_details {
             return Ok(ToolboxEndpointExecution {
                 processed_time: None,
@@ -147,6 +198,17 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
                 steps: Some(ToolboxEndpointExecution::try_parse_steps(
                     &simulation_details.logs,
                 )?),
+                inner_instructions: simulation_details
+                    .inner_instructions
+                    .map(|inner_instructions| {
+                        ToolboxEndpointProxyProgramTestContext::decompile_inner_instructions(
+                            &versioned_transaction,
+                            &loaded_writable_addresses,
+                            &loaded_readonly_addresses,
+                            inner_instructions,
+                        )
+                    })
+                    .transpose()?,
                 logs: Some(simulation_details.logs),
                 units_consumed: Some(simulation_details.units_consumed),
             });
@@ -158,6 +220,7 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             instructions,
             error: outcome.result.transpose().err(),
             steps: None,
+            inner_instructions: None,
             logs: None,
             units_consumed: None,
         })
@@ -174,13 +237,27 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
         ToolboxEndpoint::verify_versioned_transaction_signatures(
             &versioned_transaction,
         )?;
+        let signature = versioned_transaction.signatures[0];
+        if self.execution_by_signature.contains_key(&signature) {
+            return Err(TransactionError::AlreadyProcessed.into());
+        }
+        // Simulate for the preflight, or for the inner instructions (when
+        // requested) which the banks processing metadata does not contain
+        let simulation =
+            if process_preflight || self.simulated_inner_instructions {
+                Some(
+                    self.program_test_context
+                        .banks_client
+                        .simulate_transaction(versioned_transaction.clone())
+                        .await?,
+                )
+            } else {
+                None
+            };
         if process_preflight {
-            if let Some(Err(error)) = self
-                .program_test_context
-                .banks_client
-                .simulate_transaction(versioned_transaction.clone())
-                .await?
-                .result
+            if let Some(Err(error)) = simulation
+                .as_ref()
+                .and_then(|simulation| simulation.result.clone())
             {
                 return Err(error.into());
             }
@@ -190,9 +267,32 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             .banks_client
             .process_transaction_with_metadata(versioned_transaction.clone())
             .await?;
-        let (payer, instructions) = self
-            .resolve_versioned_transaction(&versioned_transaction)
+        self.blockhash_last_used =
+            Some(*versioned_transaction.message.recent_blockhash());
+        let (loaded_writable_addresses, loaded_readonly_addresses) = self
+            .resolve_versioned_transaction_loaded_addresses(
+                &versioned_transaction,
+            )
             .await?;
+        let (payer, instructions) =
+            ToolboxEndpoint::decompile_versioned_transaction_with_loaded_addresses(
+                &versioned_transaction,
+                &loaded_writable_addresses,
+                &loaded_readonly_addresses,
+            )?;
+        let inner_instructions = simulation
+            .filter(|_| self.simulated_inner_instructions)
+            .and_then(|simulation| simulation.simulation_details)
+            .and_then(|simulation_details| simulation_details.inner_instructions)
+            .map(|inner_instructions| {
+                ToolboxEndpointProxyProgramTestContext::decompile_inner_instructions(
+                    &versioned_transaction,
+                    &loaded_writable_addresses,
+                    &loaded_readonly_addresses,
+                    inner_instructions,
+                )
+            })
+            .transpose()?;
         let mut transaction_accounts = HashSet::new();
         transaction_accounts.insert(payer);
         for instruction in &instructions {
@@ -212,7 +312,6 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             .await?;
         let clock_time = SystemTime::UNIX_EPOCH
             + Duration::from_secs(clock.unix_timestamp as u64);
-        let signature = Signature::new_unique();
         for transaction_account in transaction_accounts {
             self.push_signature_for_address(transaction_account, signature);
         }
@@ -226,6 +325,7 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
                 steps: Some(ToolboxEndpointExecution::try_parse_steps(
                     &metadata.log_messages,
                 )?),
+                inner_instructions,
                 logs: Some(metadata.log_messages),
                 units_consumed: Some(metadata.compute_units_consumed),
             },
@@ -236,6 +336,7 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
                 instructions,
                 error: outcome.result.err(),
                 steps: None,
+                inner_instructions,
                 logs: None,
                 units_consumed: None,
             },
@@ -245,6 +346,24 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
         Ok((signature, execution))
     }
 
+    async fn process_transactions(
+        &mut self,
+        versioned_transactions: Vec<VersionedTransaction>,
+        process_preflight: bool,
+    ) -> Result<Vec<Result<(Signature, ToolboxEndpointExecution)>>> {
+        let mut results = vec![];
+        for versioned_transaction in versioned_transactions {
+            results.push(
+                self.process_transaction(
+                    versioned_transaction,
+                    process_preflight,
+                )
+                .await,
+            );
+        }
+        Ok(results)
+    }
+
     async fn request_airdrop(
         &mut self,
         to: &Pubkey,
@@ -350,6 +469,43 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
         Ok(found_signatures)
     }
 
+    async fn set_sysvar_account(
+        &mut self,
+        address: &Pubkey,
+        data: &[u8],
+    ) -> Result<()> {
+        match *address {
+            ToolboxEndpoint::SYSVAR_CLOCK_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<Clock>(data)?),
+            ToolboxEndpoint::SYSVAR_RENT_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<Rent>(data)?),
+            ToolboxEndpoint::SYSVAR_SLOT_HASHES => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<SlotHashes>(data)?),
+            ToolboxEndpoint::SYSVAR_EPOCH_SCHEDULE_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<EpochSchedule>(data)?),
+            ToolboxEndpoint::SYSVAR_EPOCH_REWARDS_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<EpochRewards>(data)?),
+            ToolboxEndpoint::SYSVAR_STAKE_HISTORY_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<StakeHistory>(data)?),
+            ToolboxEndpoint::SYSVAR_LAST_RESTART_SLOT_ID => self
+                .program_test_context
+                .set_sysvar(&bincode::deserialize::<LastRestartSlot>(data)?),
+            _ => {
+                return Err(anyhow!(
+                    "Cannot set sysvar account: {} (unsupported)",
+                    address
+                ))
+            },
+        };
+        Ok(())
+    }
+
     async fn forward_clock_unix_timestamp(
         &mut self,
         unix_timestamp_delta: u64,
@@ -362,10 +518,11 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             .banks_client
             .get_sysvar::<Clock>()
             .await?;
+        let slot_delta = self.convert_seconds_to_slots(unix_timestamp_delta)?;
         let mut forwarded_clock = current_clock;
-        forwarded_clock.slot += unix_timestamp_delta * SLOTS_PER_SECOND;
+        forwarded_clock.slot += slot_delta;
         forwarded_clock.unix_timestamp += i64::try_from(unix_timestamp_delta)?;
-        forwarded_clock.epoch += unix_timestamp_delta / SECONDS_PER_EPOCH;
+        forwarded_clock.epoch += slot_delta / self.get_slots_per_epoch();
         self.update_slot(&forwarded_clock).await
     }
 
@@ -381,8 +538,8 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
         let mut forwarded_clock = current_clock;
         forwarded_clock.slot += slot_delta;
         forwarded_clock.unix_timestamp +=
-            i64::try_from(slot_delta / SLOTS_PER_SECOND)?;
-        forwarded_clock.epoch += slot_delta / SLOTS_PER_EPOCH;
+            self.convert_slots_to_seconds(slot_delta)?;
+        forwarded_clock.epoch += slot_delta / self.get_slots_per_epoch();
         self.update_slot(&forwarded_clock).await
     }
 
@@ -395,16 +552,123 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyProgramTestContext {
             .banks_client
             .get_sysvar::<Clock>()
             .await?;
+        let slot_delta = epoch_delta * self.get_slots_per_epoch();
         let mut forwarded_clock = current_clock;
-        forwarded_clock.slot += epoch_delta * SLOTS_PER_EPOCH;
+        forwarded_clock.slot += slot_delta;
         forwarded_clock.unix_timestamp +=
-            i64::try_from(epoch_delta * SECONDS_PER_EPOCH)?;
+            self.convert_slots_to_seconds(slot_delta)?;
         forwarded_clock.epoch += epoch_delta;
         self.update_slot(&forwarded_clock).await
     }
+
+    async fn set_clock_unix_timestamp(
+        &mut self,
+        unix_timestamp: i64,
+    ) -> Result<()> {
+        let mut updated_clock = self
+            .program_test_context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await?;
+        if updated_clock.epoch_start_timestamp > unix_timestamp {
+            updated_clock.epoch_start_timestamp = unix_timestamp;
+        }
+        updated_clock.unix_timestamp = unix_timestamp;
+        self.program_test_context.set_sysvar(&updated_clock);
+        self.save_slot_unix_timestamp().await;
+        Ok(())
+    }
+
+    async fn warp_to_slot(&mut self, slot: u64) -> Result<()> {
+        let old_clock = self
+            .program_test_context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await?;
+        if slot <= old_clock.slot {
+            return Err(anyhow!(
+                "Cannot warp to slot: {} (current slot: {})",
+                slot,
+                old_clock.slot
+            ));
+        }
+        self.program_test_context
+            .warp_to_slot(slot)
+            .map_err(|error| {
+                anyhow!("Could not warp to slot: {}: {:?}", slot, error)
+            })?;
+        let epoch_schedule = self
+            .program_test_context
+            .genesis_config()
+            .epoch_schedule
+            .clone();
+        let mut new_clock = self
+            .program_test_context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await?;
+        new_clock.slot = slot;
+        new_clock.unix_timestamp = old_clock.unix_timestamp
+            + self.convert_slots_to_seconds(slot - old_clock.slot)?;
+        new_clock.epoch = epoch_schedule.get_epoch(slot);
+        new_clock.leader_schedule_epoch =
+            epoch_schedule.get_leader_schedule_epoch(slot);
+        new_clock.epoch_start_timestamp = if new_clock.epoch == old_clock.epoch
+        {
+            old_clock.epoch_start_timestamp
+        } else {
+            new_clock.unix_timestamp
+                - self.convert_slots_to_seconds(
+                    slot - epoch_schedule
+                        .get_first_slot_in_epoch(new_clock.epoch),
+                )?
+        };
+        self.program_test_context.set_sysvar(&new_clock);
+        self.save_slot_unix_timestamp().await;
+        Ok(())
+    }
+
+    async fn warp_to_epoch(&mut self, epoch: u64) -> Result<()> {
+        let slot = self
+            .program_test_context
+            .genesis_config()
+            .epoch_schedule
+            .get_first_slot_in_epoch(epoch);
+        self.warp_to_slot(slot).await
+    }
 }
 
 impl ToolboxEndpointProxyProgramTestContext {
+    fn get_slots_per_epoch(&self) -> u64 {
+        self.program_test_context
+            .genesis_config()
+            .epoch_schedule
+            .slots_per_epoch
+    }
+
+    // Program test shortens the genesis tick duration to run faster, so the
+    // genesis ticks per slot are paired with the nominal cluster tick rate
+    fn convert_slots_to_seconds(&self, slots: u64) -> Result<i64> {
+        let ticks_per_slot =
+            self.program_test_context.genesis_config().ticks_per_slot;
+        Ok(i64::try_from(
+            u128::from(slots) * u128::from(ticks_per_slot)
+                / u128::from(DEFAULT_TICKS_PER_SECOND),
+        )?)
+    }
+
+    fn convert_seconds_to_slots(&self, seconds: u64) -> Result<u64> {
+        let ticks_per_slot =
+            self.program_test_context.genesis_config().ticks_per_slot;
+        if ticks_per_slot == 0 {
+            return Err(anyhow!("Genesis ticks per slot is zero"));
+        }
+        Ok(u64::try_from(
+            u128::from(seconds) * u128::from(DEFAULT_TICKS_PER_SECOND)
+                / u128::from(ticks_per_slot),
+        )?)
+    }
+
     async fn update_slot(&mut self, new_clock: &Clock) -> Result<()> {
         let old_hash = self.program_test_context.last_blockhash;
         let old_clock = self
@@ -476,10 +740,10 @@ impl ToolboxEndpointProxyProgramTestContext {
         }
     }
 
-    pub async fn resolve_versioned_transaction(
+    pub async fn resolve_versioned_transaction_loaded_addresses(
         &mut self,
         versioned_transaction: &VersionedTransaction,
-    ) -> Result<(Pubkey, Vec<Instruction>)> {
+    ) -> Result<(Vec<Pubkey>, Vec<Pubkey>)> {
         let mut resolved_address_lookup_tables = vec![];
         if let Some(message_address_table_lookups) =
             versioned_transaction.message.address_table_lookups()
@@ -500,9 +764,37 @@ impl ToolboxEndpointProxyProgramTestContext {
                 }
             }
         }
-        ToolboxEndpoint::decompile_versioned_transaction(
+        ToolboxEndpoint::decompile_versioned_transaction_loaded_addresses(
             versioned_transaction,
             &resolved_address_lookup_tables,
         )
     }
+
+    fn decompile_inner_instructions(
+        versioned_transaction: &VersionedTransaction,
+        loaded_writable_addresses: &[Pubkey],
+        loaded_readonly_addresses: &[Pubkey],
+        inner_instructions: InnerInstructionsList,
+    ) -> Result<Vec<Vec<ToolboxEndpointExecutionInnerInstruction>>> {
+        let mut compiled_inner_instructions = vec![];
+        for inner_instructions in inner_instructions {
+            compiled_inner_instructions.push(
+                inner_instructions
+                    .into_iter()
+                    .map(|inner_instruction| {
+                        (
+                            inner_instruction.instruction,
+                            Some(u32::from(inner_instruction.stack_height)),
+                        )
+                    })
+                    .collect(),
+            );
+        }
+        ToolboxEndpoint::decompile_versioned_transaction_inner_instructions(
+            versioned_transaction,
+            loaded_writable_addresses,
+            loaded_readonly_addresses,
+            &compiled_inner_instructions,
+        )
+    }
 }
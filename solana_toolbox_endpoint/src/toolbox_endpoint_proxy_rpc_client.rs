@@ -5,14 +5,19 @@ use std::time::Instant;
 
 use anyhow::anyhow;
 use anyhow::Result;
+use futures::future::join_all;
+use serde_json::json;
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_client::rpc_config::RpcAccountInfoConfig;
 use solana_client::rpc_config::RpcRequestAirdropConfig;
 use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_client::rpc_request::RpcRequest;
+use solana_client::rpc_response::Response;
 use solana_sdk::account::Account;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::hash::Hash;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::sysvar::clock::Clock;
@@ -42,6 +47,10 @@ impl ToolboxEndpointProxyRpcClient {
 
 #[async_trait::async_trait]
 impl ToolboxEndpointProxy for ToolboxEndpointProxyRpcClient {
+    async fn get_genesis_hash(&mut self) -> Result<Hash> {
+        Ok(self.rpc_client.get_genesis_hash().await?)
+    }
+
     async fn get_latest_blockhash(&mut self) -> Result<Hash> {
         Ok(self
             .rpc_client
@@ -100,6 +109,26 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyRpcClient {
             .value)
     }
 
+    async fn get_fee_for_message(
+        &mut self,
+        message: &VersionedMessage,
+    ) -> Result<Option<u64>> {
+        // The versioned encoding keeps the v0 prefix the RPC expects
+        let response = self
+            .rpc_client
+            .send::<Response<Option<u64>>>(
+                RpcRequest::GetFeeForMessage,
+                json!([
+                    ToolboxEndpoint::encode_versioned_message_base64(message)?,
+                    { "commitment": self.get_commitment().commitment.to_string() },
+                ]),
+            )
+            .await?;
+        Ok(Some(response.value.ok_or_else(|| {
+            anyhow!("Could not get fee for message: invalid blockhash")
+        })?))
+    }
+
     async fn simulate_transaction(
         &mut self,
         versioned_transaction: VersionedTransaction,
@@ -118,23 +147,32 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyRpcClient {
         process_preflight: bool,
     ) -> Result<(Signature, ToolboxEndpointExecution)> {
         let signature = self
-            .rpc_client
-            .send_transaction_with_config(
-                &versioned_transaction,
-                RpcSendTransactionConfig {
-                    skip_preflight: !process_preflight,
-                    preflight_commitment: Some(
-                        self.get_commitment().commitment,
-                    ),
-                    encoding: Some(UiTransactionEncoding::Base64),
-                    max_retries: None,
-                    min_context_slot: None,
-                },
-            )
+            .send_transaction(&versioned_transaction, process_preflight)
             .await?;
         self.wait_until_execution(&signature).await
     }
 
+    async fn process_transactions(
+        &mut self,
+        versioned_transactions: Vec<VersionedTransaction>,
+        process_preflight: bool,
+    ) -> Result<Vec<Result<(Signature, ToolboxEndpointExecution)>>> {
+        let sent = join_all(versioned_transactions.iter().map(
+            |versioned_transaction| {
+                self.send_transaction(versioned_transaction, process_preflight)
+            },
+        ))
+        .await;
+        let mut results = vec![];
+        for signature in sent {
+            results.push(match signature {
+                Ok(signature) => self.wait_until_execution(&signature).await,
+                Err(error) => Err(error),
+            });
+        }
+        Ok(results)
+    }
+
     async fn request_airdrop(
         &mut self,
         to: &Pubkey,
@@ -191,6 +229,17 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyRpcClient {
         .await
     }
 
+    async fn set_sysvar_account(
+        &mut self,
+        address: &Pubkey,
+        _data: &[u8],
+    ) -> Result<()> {
+        Err(anyhow!(
+            "Cannot set sysvar account on an RPC endpoint: {}",
+            address
+        ))
+    }
+
     async fn forward_clock_unix_timestamp(
         &mut self,
         unix_timestamp_delta: u64,
@@ -211,9 +260,55 @@ impl ToolboxEndpointProxy for ToolboxEndpointProxyRpcClient {
         let until_epoch = self.get_sysvar_clock().await?.epoch + epoch_delta;
         self.wait_until_clock(None, None, Some(until_epoch)).await
     }
+
+    async fn set_clock_unix_timestamp(
+        &mut self,
+        unix_timestamp: i64,
+    ) -> Result<()> {
+        let clock = self.get_sysvar_clock().await?;
+        if unix_timestamp < clock.unix_timestamp {
+            return Err(anyhow!(
+                "Cannot set clock unix_timestamp backward: {} (current: {})",
+                unix_timestamp,
+                clock.unix_timestamp
+            ));
+        }
+        self.wait_until_clock(Some(unix_timestamp), None, None)
+            .await
+    }
+
+    async fn warp_to_slot(&mut self, slot: u64) -> Result<()> {
+        self.wait_until_clock(None, Some(slot), None).await
+    }
+
+    async fn warp_to_epoch(&mut self, epoch: u64) -> Result<()> {
+        self.wait_until_clock(None, None, Some(epoch)).await
+    }
 }
 
 impl ToolboxEndpointProxyRpcClient {
+    async fn send_transaction(
+        &self,
+        versioned_transaction: &VersionedTransaction,
+        process_preflight: bool,
+    ) -> Result<Signature> {
+        Ok(self
+            .rpc_client
+            .send_transaction_with_config(
+                versioned_transaction,
+                RpcSendTransactionConfig {
+                    skip_preflight: !process_preflight,
+                    preflight_commitment: Some(
+                        self.get_commitment().commitment,
+                    ),
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    max_retries: None,
+                    min_context_slot: None,
+                },
+            )
+            .await?)
+    }
+
     async fn wait_until_execution(
         &mut self,
         signature: &Signature,
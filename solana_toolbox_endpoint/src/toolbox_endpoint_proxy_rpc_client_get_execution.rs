@@ -1,6 +1,7 @@
 use std::time::Duration;
 use std::time::SystemTime;
 
+use anyhow::anyhow;
 use anyhow::Result;
 use serde::Deserialize;
 use serde::Serialize;
@@ -60,6 +61,24 @@ struct GetTransactionResponseMeta {
     pub err: Option<TransactionError>,
     pub log_messages: Option<Vec<String>>,
     pub compute_units_consumed: Option<u64>,
+    pub inner_instructions:
+        Option<Vec<GetTransactionResponseMetaInnerInstructions>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct GetTransactionResponseMetaInnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<GetTransactionResponseMetaInnerInstruction>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct GetTransactionResponseMetaInnerInstruction {
+    pub program_id_index: u8,
+    pub accounts: Vec<u8>,
+    pub data: String,
+    pub stack_height: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -118,6 +137,8 @@ impl ToolboxEndpointProxyRpcClient {
                 );
             }
         }
+        let instructions_count =
+            response.transaction.message.instructions.len();
         let mut compiled_instructions = vec![];
         for response_instruction in response.transaction.message.instructions {
             compiled_instructions.push(CompiledInstruction {
@@ -137,6 +158,49 @@ impl ToolboxEndpointProxyRpcClient {
             &loaded_readonly_addresses,
             &compiled_instructions,
         )?;
+        let inner_instructions = match response.meta.inner_instructions {
+            Some(response_inner_instructions) => {
+                let mut compiled_inner_instructions =
+                    vec![vec![]; instructions_count];
+                for response_inner_instructions in response_inner_instructions {
+                    let index = usize::from(response_inner_instructions.index);
+                    let compiled_instructions = compiled_inner_instructions
+                        .get_mut(index)
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Invalid inner instructions index: {}",
+                                index
+                            )
+                        })?;
+                    for response_inner_instruction in
+                        response_inner_instructions.instructions
+                    {
+                        compiled_instructions.push((
+                            CompiledInstruction {
+                                program_id_index: response_inner_instruction
+                                    .program_id_index,
+                                accounts: response_inner_instruction.accounts,
+                                data:
+                                    ToolboxEndpoint::sanitize_and_decode_base58(
+                                        &response_inner_instruction.data,
+                                    )?,
+                            },
+                            response_inner_instruction.stack_height,
+                        ));
+                    }
+                }
+                Some(ToolboxEndpoint::decompile_transaction_inner_instructions(
+                    header.num_required_signatures,
+                    header.num_readonly_signed_accounts,
+                    header.num_readonly_unsigned_accounts,
+                    &static_addresses,
+                    &loaded_writable_addresses,
+                    &loaded_readonly_addresses,
+                    &compiled_inner_instructions,
+                )?)
+            },
+            None => None,
+        };
         Ok(Some(ToolboxEndpointExecution {
             processed_time: response.block_time.map(|block_time| {
                 SystemTime::UNIX_EPOCH + Duration::from_secs(block_time as u64)
@@ -151,6 +215,7 @@ impl ToolboxEndpointProxyRpcClient {
                 .as_ref()
                 .map(|logs| ToolboxEndpointExecution::try_parse_steps(logs))
                 .transpose()?,
+            inner_instructions,
             logs: response.meta.log_messages,
             units_consumed: response.meta.compute_units_consumed,
         }))
@@ -1,7 +1,13 @@
+use std::str::FromStr;
+
 use anyhow::anyhow;
 use anyhow::Result;
 use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::UiInstruction;
+use solana_transaction_status::UiParsedInstruction;
 use solana_transaction_status::UiTransactionEncoding;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
@@ -40,11 +46,17 @@ impl ToolboxEndpointProxyRpcClient {
                 ));
             }
         }
-        let (payer, instructions) =
-            ToolboxEndpoint::decompile_versioned_transaction(
+        let (loaded_writable_addresses, loaded_readonly_addresses) =
+            ToolboxEndpoint::decompile_versioned_transaction_loaded_addresses(
                 &versioned_transaction,
                 &resolved_address_lookup_tables,
             )?;
+        let (payer, instructions) =
+            ToolboxEndpoint::decompile_versioned_transaction_with_loaded_addresses(
+                &versioned_transaction,
+                &loaded_writable_addresses,
+                &loaded_readonly_addresses,
+            )?;
         let outcome = self
             .rpc_client
             .simulate_transaction_with_config(
@@ -56,10 +68,51 @@ impl ToolboxEndpointProxyRpcClient {
                     encoding: Some(UiTransactionEncoding::Base64),
                     accounts: None,
                     min_context_slot: None,
-                    inner_instructions: false,
+                    inner_instructions: true,
                 },
             )
             .await?;
+        let inner_instructions = match outcome.value.inner_instructions {
+            Some(ui_inner_instructions) => {
+                let mut account_keys = versioned_transaction
+                    .message
+                    .static_account_keys()
+                    .to_vec();
+                account_keys.extend_from_slice(&loaded_writable_addresses);
+                account_keys.extend_from_slice(&loaded_readonly_addresses);
+                let mut compiled_inner_instructions =
+                    vec![
+                        vec![];
+                        versioned_transaction.message.instructions().len()
+                    ];
+                for ui_inner_instructions in ui_inner_instructions {
+                    let index = usize::from(ui_inner_instructions.index);
+                    let compiled_instructions = compiled_inner_instructions
+                        .get_mut(index)
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Invalid inner instructions index: {}",
+                                index
+                            )
+                        })?;
+                    for ui_instruction in ui_inner_instructions.instructions {
+                        compiled_instructions.push(
+                            ToolboxEndpointProxyRpcClient::compile_ui_inner_instruction(
+                                &account_keys,
+                                ui_instruction,
+                            )?,
+                        );
+                    }
+                }
+                Some(ToolboxEndpoint::decompile_versioned_transaction_inner_instructions(
+                    &versioned_transaction,
+                    &loaded_writable_addresses,
+                    &loaded_readonly_addresses,
+                    &compiled_inner_instructions,
+                )?)
+            },
+            None => None,
+        };
         Ok(ToolboxEndpointExecution {
             processed_time: None,
             slot: outcome.context.slot,
@@ -72,8 +125,69 @@ impl ToolboxEndpointProxyRpcClient {
                 .as_ref()
                 .map(|logs| ToolboxEndpointExecution::try_parse_steps(logs))
                 .transpose()?,
+            inner_instructions,
             logs: outcome.value.logs,
             units_consumed: outcome.value.units_consumed,
         })
     }
+
+    // The RPC always parses the simulated inner instructions, fully parsed
+    // ones only keep their program and depth, not their accounts and data
+    fn compile_ui_inner_instruction(
+        account_keys: &[Pubkey],
+        ui_instruction: UiInstruction,
+    ) -> Result<(CompiledInstruction, Option<u32>)> {
+        let find_account_index = |address: &str| -> Result<u8> {
+            let address = Pubkey::from_str(address)?;
+            let account_index = account_keys
+                .iter()
+                .position(|account_key| *account_key == address)
+                .ok_or_else(|| {
+                    anyhow!("Unknown inner instruction account: {}", address)
+                })?;
+            Ok(u8::try_from(account_index)?)
+        };
+        Ok(match ui_instruction {
+            UiInstruction::Compiled(ui_compiled_instruction) => (
+                CompiledInstruction {
+                    program_id_index: ui_compiled_instruction.program_id_index,
+                    accounts: ui_compiled_instruction.accounts,
+                    data: ToolboxEndpoint::sanitize_and_decode_base58(
+                        &ui_compiled_instruction.data,
+                    )?,
+                },
+                ui_compiled_instruction.stack_height,
+            ),
+            UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(
+                ui_partially_decoded_instruction,
+            )) => (
+                CompiledInstruction {
+                    program_id_index: find_account_index(
+                        &ui_partially_decoded_instruction.program_id,
+                    )?,
+                    accounts: ui_partially_decoded_instruction
+                        .accounts
+                        .iter()
+                        .map(|account| find_account_index(account))
+                        .collect::<Result<_>>()?,
+                    data: ToolboxEndpoint::sanitize_and_decode_base58(
+                        &ui_partially_decoded_instruction.data,
+                    )?,
+                },
+                ui_partially_decoded_instruction.stack_height,
+            ),
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(
+                parsed_instruction,
+            )) => (
+                CompiledInstruction {
+                    program_id_index: find_account_index(
+                        &parsed_instruction.program_id,
+                    )?,
+                    accounts: vec![],
+                    data: vec![],
+                },
+                parsed_instruction.stack_height,
+            ),
+        })
+    }
 }
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+use solana_sdk::signer::SignerError;
+
+pub type ToolboxEndpointRemoteSignerCallback =
+    Arc<dyn Fn(&Pubkey, &[u8]) -> Result<Signature> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ToolboxEndpointRemoteSigner {
+    pubkey: Pubkey,
+    callback: ToolboxEndpointRemoteSignerCallback,
+}
+
+impl ToolboxEndpointRemoteSigner {
+    pub fn new(
+        pubkey: Pubkey,
+        callback: ToolboxEndpointRemoteSignerCallback,
+    ) -> ToolboxEndpointRemoteSigner {
+        ToolboxEndpointRemoteSigner { pubkey, callback }
+    }
+}
+
+impl std::fmt::Debug for ToolboxEndpointRemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolboxEndpointRemoteSigner")
+            .field("pubkey", &self.pubkey)
+            .finish()
+    }
+}
+
+impl Signer for ToolboxEndpointRemoteSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(
+        &self,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        let signature = (self.callback)(&self.pubkey, message)
+            .map_err(|error| SignerError::Custom(error.to_string()))?;
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::KeypairPubkeyMismatch);
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
@@ -1,4 +1,9 @@
+use std::fs::create_dir_all;
+use std::fs::write;
+use std::path::Path;
+
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
 use solana_sdk::account::Account;
 use solana_sdk::program_pack::IsInitialized;
@@ -15,6 +20,31 @@ impl ToolboxEndpoint {
         Ok(self.get_account(address).await?.unwrap_or_default())
     }
 
+    pub async fn dump_accounts(
+        &mut self,
+        addresses: &[Pubkey],
+        directory: &str,
+    ) -> Result<()> {
+        create_dir_all(directory).with_context(|| {
+            anyhow!("Could not create directory: {}", directory)
+        })?;
+        for address in addresses {
+            let account =
+                self.get_account(address).await?.ok_or_else(|| {
+                    anyhow!("Could not get account: {} (dump)", address)
+                })?;
+            let path = Path::new(directory).join(format!("{}.json", address));
+            write(
+                &path,
+                ToolboxEndpoint::encode_account_json(address, &account),
+            )
+            .with_context(|| {
+                anyhow!("Could not write account file: {}", path.display())
+            })?;
+        }
+        Ok(())
+    }
+
     pub async fn get_account_exists(
         &mut self,
         address: &Pubkey,
@@ -9,7 +9,6 @@ use solana_sdk::address_lookup_table::instruction::freeze_lookup_table;
 use solana_sdk::address_lookup_table::program;
 use solana_sdk::address_lookup_table::state::AddressLookupTable;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
@@ -60,8 +59,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_new(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         lookup_addresses: &[Pubkey],
     ) -> Result<Pubkey> {
         let slot_hashes = self.get_sysvar_slot_hashes().await?;
@@ -87,8 +86,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_extend(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         address_lookup_table: &Pubkey,
         lookup_addresses: &[Pubkey],
     ) -> Result<()> {
@@ -111,8 +110,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_freeze(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         address_lookup_table: &Pubkey,
     ) -> Result<()> {
         let instruction =
@@ -124,8 +123,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_deactivate(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         address_lookup_table: &Pubkey,
     ) -> Result<()> {
         let instruction =
@@ -137,8 +136,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_close(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         address_lookup_table: &Pubkey,
         spill: &Pubkey,
     ) -> Result<()> {
@@ -154,8 +153,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_address_lookup_table_postfix(
         &mut self,
-        payer: &Keypair,
-        authority: &Keypair,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
         address_lookup_table: &Pubkey,
     ) -> Result<()> {
         self.forward_clock_slot(1).await?;
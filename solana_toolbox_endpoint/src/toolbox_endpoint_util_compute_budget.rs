@@ -1,9 +1,44 @@
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+use anyhow::Result;
 use solana_sdk::compute_budget;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
+
+const COMPUTE_BUDGET_UNIT_LIMIT_PER_INSTRUCTION_DEFAULT: u32 = 200_000;
+const COMPUTE_BUDGET_UNIT_LIMIT_PER_INSTRUCTION_BUILTIN: u32 = 3_000;
+const COMPUTE_BUDGET_UNIT_LIMIT_MAX: u32 = 1_400_000;
+const COMPUTE_BUDGET_MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+const COMPUTE_BUDGET_BUILTIN_PROGRAM_IDS: [Pubkey; 12] = [
+    ToolboxEndpoint::SYSTEM_PROGRAM_ID,
+    ToolboxEndpoint::COMPUTE_BUDGET_PROGRAM_ID,
+    ToolboxEndpoint::BPF_LOADER_2_PROGRAM_ID,
+    ToolboxEndpoint::BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+    ToolboxEndpoint::LOADER_V4_PROGRAM_ID,
+    pubkey!("BPFLoader1111111111111111111111111111111111"),
+    pubkey!("Vote111111111111111111111111111111111111111"),
+    pubkey!("KeccakSecp256k11111111111111111111111111111"),
+    pubkey!("Ed25519SigVerify111111111111111111111111111"),
+    // Those are being migrated to core BPF programs, once the migration
+    // feature is active on a cluster they get the non-builtin default limit
+    pubkey!("Stake11111111111111111111111111111111111111"),
+    pubkey!("Config1111111111111111111111111111111111111"),
+    ToolboxEndpoint::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolboxEndpointComputeBudget {
+    pub unit_limit: Option<u32>,
+    pub unit_price_micro_lamports: Option<u64>,
+    pub heap_frame_bytes: Option<u32>,
+    pub loaded_accounts_data_size_limit: Option<u32>,
+}
 
 impl ToolboxEndpoint {
     pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = compute_budget::ID;
@@ -12,24 +47,149 @@ impl ToolboxEndpoint {
         instructions: &[Instruction],
         paid_compute_units: Option<u32>,
         micro_lamport_price_per_unit: Option<u64>,
+    ) -> Vec<Instruction> {
+        ToolboxEndpoint::generate_instructions_with_compute_budget_settings(
+            instructions,
+            &ToolboxEndpointComputeBudget {
+                unit_limit: paid_compute_units,
+                unit_price_micro_lamports: micro_lamport_price_per_unit,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn generate_instructions_with_compute_budget_settings(
+        instructions: &[Instruction],
+        compute_budget: &ToolboxEndpointComputeBudget,
     ) -> Vec<Instruction> {
         let mut generated_instructions = vec![];
-        if let Some(paid_compute_units) = paid_compute_units {
+        if let Some(unit_limit) = compute_budget.unit_limit {
             generated_instructions.push(
-                ComputeBudgetInstruction::set_compute_unit_limit(
-                    paid_compute_units,
-                ),
+                ComputeBudgetInstruction::set_compute_unit_limit(unit_limit),
             );
         }
-        if let Some(micro_lamport_price_per_unit) = micro_lamport_price_per_unit
+        if let Some(unit_price_micro_lamports) =
+            compute_budget.unit_price_micro_lamports
         {
             generated_instructions.push(
                 ComputeBudgetInstruction::set_compute_unit_price(
-                    micro_lamport_price_per_unit,
+                    unit_price_micro_lamports,
+                ),
+            );
+        }
+        if let Some(heap_frame_bytes) = compute_budget.heap_frame_bytes {
+            generated_instructions.push(
+                ComputeBudgetInstruction::request_heap_frame(heap_frame_bytes),
+            );
+        }
+        if let Some(loaded_accounts_data_size_limit) =
+            compute_budget.loaded_accounts_data_size_limit
+        {
+            generated_instructions.push(
+                ComputeBudgetInstruction::set_loaded_accounts_data_size_limit(
+                    loaded_accounts_data_size_limit,
                 ),
             );
         }
         generated_instructions.extend_from_slice(instructions);
         generated_instructions
     }
+
+    pub fn parse_compute_budget(
+        instructions: &[Instruction],
+    ) -> Result<ToolboxEndpointComputeBudget> {
+        let mut compute_budget = ToolboxEndpointComputeBudget::default();
+        let mut tags_seen = HashSet::new();
+        for instruction in instructions {
+            if instruction.program_id
+                != ToolboxEndpoint::COMPUTE_BUDGET_PROGRAM_ID
+            {
+                continue;
+            }
+            let data = &instruction.data;
+            let tag = *data.first().ok_or_else(|| {
+                anyhow!("Compute budget instruction is empty")
+            })?;
+            let payload = &data[1..];
+            // The runtime rejects transactions repeating a setting
+            if !tags_seen.insert(tag) {
+                return Err(anyhow!(
+                    "Duplicate compute budget instruction: {}",
+                    tag
+                ));
+            }
+            match tag {
+                1 => {
+                    compute_budget.heap_frame_bytes =
+                        Some(u32::from_le_bytes(payload.try_into()?))
+                },
+                2 => {
+                    compute_budget.unit_limit =
+                        Some(u32::from_le_bytes(payload.try_into()?))
+                },
+                3 => {
+                    compute_budget.unit_price_micro_lamports =
+                        Some(u64::from_le_bytes(payload.try_into()?))
+                },
+                4 => {
+                    compute_budget.loaded_accounts_data_size_limit =
+                        Some(u32::from_le_bytes(payload.try_into()?))
+                },
+                _ => {
+                    return Err(anyhow!(
+                        "Unknown compute budget instruction: {}",
+                        tag
+                    ))
+                },
+            }
+        }
+        Ok(compute_budget)
+    }
+
+    pub fn compute_effective_compute_unit_limit(
+        instructions: &[Instruction],
+    ) -> Result<u32> {
+        let compute_budget =
+            ToolboxEndpoint::parse_compute_budget(instructions)?;
+        if let Some(unit_limit) = compute_budget.unit_limit {
+            return Ok(unit_limit.min(COMPUTE_BUDGET_UNIT_LIMIT_MAX));
+        }
+        let mut unit_limit = 0u32;
+        for instruction in instructions {
+            unit_limit = unit_limit.saturating_add(
+                if COMPUTE_BUDGET_BUILTIN_PROGRAM_IDS
+                    .contains(&instruction.program_id)
+                {
+                    COMPUTE_BUDGET_UNIT_LIMIT_PER_INSTRUCTION_BUILTIN
+                } else {
+                    COMPUTE_BUDGET_UNIT_LIMIT_PER_INSTRUCTION_DEFAULT
+                },
+            );
+        }
+        Ok(unit_limit.min(COMPUTE_BUDGET_UNIT_LIMIT_MAX))
+    }
+
+    pub fn compute_prioritization_fee(
+        instructions: &[Instruction],
+    ) -> Result<u64> {
+        let compute_budget =
+            ToolboxEndpoint::parse_compute_budget(instructions)?;
+        let unit_price_micro_lamports =
+            compute_budget.unit_price_micro_lamports.unwrap_or(0);
+        let unit_limit = ToolboxEndpoint::compute_effective_compute_unit_limit(
+            instructions,
+        )?;
+        let fee_micro_lamports =
+            u128::from(unit_price_micro_lamports) * u128::from(unit_limit);
+        Ok(u64::try_from(
+            fee_micro_lamports
+                .div_ceil(COMPUTE_BUDGET_MICRO_LAMPORTS_PER_LAMPORT),
+        )?)
+    }
+
+    pub fn compute_execution_prioritization_fee(
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<u64> {
+        ToolboxEndpoint::compute_prioritization_fee(&execution.instructions)
+    }
 }
@@ -5,10 +5,21 @@ use anyhow::Context;
 use anyhow::Result;
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
+use bip39::Language;
+use bip39::Mnemonic;
+use serde_json::json;
+use serde_json::Value;
+use solana_sdk::account::Account;
 use solana_sdk::bs58;
+use solana_sdk::derivation_path::DerivationPath;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signature;
+use solana_sdk::signer::keypair::generate_seed_from_seed_phrase_and_passphrase;
+use solana_sdk::signer::keypair::keypair_from_seed;
+use solana_sdk::signer::keypair::keypair_from_seed_and_derivation_path;
+use solana_sdk::transaction::VersionedTransaction;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 
@@ -47,6 +58,48 @@ impl ToolboxEndpoint {
         serde_json::to_string(&bytes).unwrap()
     }
 
+    pub fn encode_versioned_transaction_base58(
+        versioned_transaction: &VersionedTransaction,
+    ) -> Result<String> {
+        Ok(ToolboxEndpoint::encode_base58(&bincode::serialize(
+            versioned_transaction,
+        )?))
+    }
+
+    pub fn encode_versioned_transaction_base64(
+        versioned_transaction: &VersionedTransaction,
+    ) -> Result<String> {
+        Ok(ToolboxEndpoint::encode_base64(&bincode::serialize(
+            versioned_transaction,
+        )?))
+    }
+
+    pub fn encode_versioned_message_base64(
+        versioned_message: &VersionedMessage,
+    ) -> Result<String> {
+        Ok(ToolboxEndpoint::encode_base64(&bincode::serialize(
+            versioned_message,
+        )?))
+    }
+
+    pub fn encode_account_json(address: &Pubkey, account: &Account) -> String {
+        serde_json::to_string_pretty(&json!({
+            "pubkey": address.to_string(),
+            "account": {
+                "lamports": account.lamports,
+                "data": [
+                    ToolboxEndpoint::encode_base64(&account.data),
+                    "base64",
+                ],
+                "owner": account.owner.to_string(),
+                "executable": account.executable,
+                "rentEpoch": account.rent_epoch,
+                "space": account.data.len(),
+            },
+        }))
+        .unwrap()
+    }
+
     pub fn sanitize_and_decode_base16(raw: &str) -> Result<Vec<u8>> {
         let sanitized = raw.replace(|c| !char::is_ascii_alphanumeric(&c), "");
         let mut bytes = vec![];
@@ -114,4 +167,129 @@ impl ToolboxEndpoint {
         Keypair::from_bytes(&decoded)
             .with_context(|| anyhow!("Decoding Keypair Bytes: {:?}", decoded))
     }
+
+    pub fn sanitize_and_decode_derivation_path(
+        raw: &str,
+    ) -> Result<DerivationPath> {
+        let sanitized = raw.trim();
+        if sanitized.starts_with('m') {
+            DerivationPath::from_absolute_path_str(sanitized)
+        } else {
+            DerivationPath::from_key_str(sanitized)
+        }
+        .with_context(|| anyhow!("Decoding Derivation Path: {}", raw))
+    }
+
+    pub fn sanitize_and_decode_keypair_mnemonic(
+        mnemonic: &str,
+        passphrase: &str,
+        derivation_path: Option<&str>,
+    ) -> Result<Keypair> {
+        let seed = generate_seed_from_seed_phrase_and_passphrase(
+            &ToolboxEndpoint::sanitize_and_validate_mnemonic(mnemonic)?,
+            passphrase,
+        );
+        let derivation_path = derivation_path
+            .map(ToolboxEndpoint::sanitize_and_decode_derivation_path)
+            .transpose()?;
+        keypair_from_seed_and_derivation_path(&seed, derivation_path)
+            .map_err(|error| anyhow!("Decoding Keypair Mnemonic: {}", error))
+    }
+
+    pub fn sanitize_and_decode_keypair_mnemonic_legacy(
+        mnemonic: &str,
+        passphrase: &str,
+    ) -> Result<Keypair> {
+        let seed = generate_seed_from_seed_phrase_and_passphrase(
+            &ToolboxEndpoint::sanitize_and_validate_mnemonic(mnemonic)?,
+            passphrase,
+        );
+        keypair_from_seed(&seed).map_err(|error| {
+            anyhow!("Decoding Keypair Mnemonic (legacy): {}", error)
+        })
+    }
+
+    fn sanitize_and_validate_mnemonic(raw: &str) -> Result<String> {
+        let sanitized = raw
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+        // Never include the mnemonic itself in the error, it is a secret
+        Mnemonic::parse_in(Language::English, &sanitized)
+            .map_err(|error| anyhow!("Decoding Mnemonic: {}", error))?;
+        Ok(sanitized)
+    }
+
+    pub fn sanitize_and_decode_versioned_transaction_base58(
+        raw: &str,
+    ) -> Result<VersionedTransaction> {
+        let decoded = ToolboxEndpoint::sanitize_and_decode_base58(raw)
+            .with_context(|| {
+                anyhow!("Decoding VersionedTransaction Base58 String: {}", raw)
+            })?;
+        bincode::deserialize(&decoded).with_context(|| {
+            anyhow!("Decoding VersionedTransaction Bytes: {:?}", decoded)
+        })
+    }
+
+    pub fn sanitize_and_decode_versioned_transaction_base64(
+        raw: &str,
+    ) -> Result<VersionedTransaction> {
+        let decoded = ToolboxEndpoint::sanitize_and_decode_base64(raw)
+            .with_context(|| {
+                anyhow!("Decoding VersionedTransaction Base64 String: {}", raw)
+            })?;
+        bincode::deserialize(&decoded).with_context(|| {
+            anyhow!("Decoding VersionedTransaction Bytes: {:?}", decoded)
+        })
+    }
+
+    pub fn sanitize_and_decode_account_json(
+        raw: &str,
+    ) -> Result<(Pubkey, Account)> {
+        let value = serde_json::from_str::<Value>(raw)
+            .with_context(|| anyhow!("Decoding Account Json: {}", raw))?;
+        let address = ToolboxEndpoint::sanitize_and_decode_pubkey(
+            value["pubkey"]
+                .as_str()
+                .ok_or_else(|| anyhow!("Account Json missing pubkey"))?,
+        )?;
+        let account = &value["account"];
+        let data = &account["data"];
+        let data_encoding = data[1].as_str().unwrap_or("base64");
+        let data_encoded = data[0]
+            .as_str()
+            .ok_or_else(|| anyhow!("Account Json missing data"))?;
+        let data = match data_encoding {
+            "base64" => {
+                ToolboxEndpoint::sanitize_and_decode_base64(data_encoded)?
+            },
+            "base58" => {
+                ToolboxEndpoint::sanitize_and_decode_base58(data_encoded)?
+            },
+            _ => {
+                return Err(anyhow!(
+                    "Account Json unsupported data encoding: {}",
+                    data_encoding
+                ))
+            },
+        };
+        Ok((
+            address,
+            Account {
+                lamports: account["lamports"]
+                    .as_u64()
+                    .ok_or_else(|| anyhow!("Account Json missing lamports"))?,
+                data,
+                owner: ToolboxEndpoint::sanitize_and_decode_pubkey(
+                    account["owner"]
+                        .as_str()
+                        .ok_or_else(|| anyhow!("Account Json missing owner"))?,
+                )?,
+                executable: account["executable"].as_bool().unwrap_or(false),
+                rent_epoch: account["rentEpoch"].as_u64().unwrap_or(0),
+            },
+        ))
+    }
 }
@@ -1,20 +1,77 @@
-use std::collections::HashMap;
-
+use anyhow::Result;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolboxEndpointCluster {
+    MainnetBeta,
+    Devnet,
+    Testnet,
+    Custom,
+}
+
+impl ToolboxEndpointCluster {
+    pub fn from_url_or_moniker(url_or_moniker: &str) -> ToolboxEndpointCluster {
+        match ToolboxEndpoint::get_cluster_from_url_or_moniker(url_or_moniker) {
+            Some("mainnet-beta") => ToolboxEndpointCluster::MainnetBeta,
+            Some("devnet") => ToolboxEndpointCluster::Devnet,
+            Some("testnet") => ToolboxEndpointCluster::Testnet,
+            _ => ToolboxEndpointCluster::Custom,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToolboxEndpointCluster::MainnetBeta => "mainnet-beta",
+            ToolboxEndpointCluster::Devnet => "devnet",
+            ToolboxEndpointCluster::Testnet => "testnet",
+            ToolboxEndpointCluster::Custom => "custom",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolboxEndpointExplorer {
+    SolanaExplorer,
+    Solscan,
+    SolanaFm,
+    // Templates placeholders: {address}, {signature}, {signatures}, {message}, {cluster}, {rpc_url}
+    Custom {
+        address_url_template: String,
+        signature_url_template: String,
+        simulation_url_template: String,
+    },
+}
+
 impl ToolboxEndpoint {
+    pub async fn get_cluster_from_genesis_hash(
+        &mut self,
+    ) -> Result<ToolboxEndpointCluster> {
+        let genesis_hash = self.get_genesis_hash().await?.to_string();
+        Ok(
+            if genesis_hash == ToolboxEndpoint::GENESIS_HASH_MAINNET_BETA {
+                ToolboxEndpointCluster::MainnetBeta
+            } else if genesis_hash == ToolboxEndpoint::GENESIS_HASH_DEVNET {
+                ToolboxEndpointCluster::Devnet
+            } else if genesis_hash == ToolboxEndpoint::GENESIS_HASH_TESTNET {
+                ToolboxEndpointCluster::Testnet
+            } else {
+                ToolboxEndpointCluster::Custom
+            },
+        )
+    }
+
     pub fn compute_explorer_address_url(
         rpc_url: &str,
         address: &Pubkey,
     ) -> String {
-        ToolboxEndpoint::compute_explorer_url(
+        ToolboxEndpoint::compute_explorer_address_url_with_explorer(
+            &ToolboxEndpointExplorer::SolanaExplorer,
+            ToolboxEndpointCluster::from_url_or_moniker(rpc_url),
             rpc_url,
-            "address",
-            &address.to_string(),
-            &HashMap::new(),
+            address,
         )
     }
 
@@ -22,11 +79,11 @@ impl ToolboxEndpoint {
         rpc_url: &str,
         signature: &Signature,
     ) -> String {
-        ToolboxEndpoint::compute_explorer_url(
+        ToolboxEndpoint::compute_explorer_signature_url_with_explorer(
+            &ToolboxEndpointExplorer::SolanaExplorer,
+            ToolboxEndpointCluster::from_url_or_moniker(rpc_url),
             rpc_url,
-            "tx",
-            &signature.to_string(),
-            &HashMap::new(),
+            signature,
         )
     }
 
@@ -35,35 +92,136 @@ impl ToolboxEndpoint {
         transaction_signatures: &[Signature],
         transaction_message_serialized: &[u8],
     ) -> String {
-        let mut params = HashMap::new();
-        params.insert(
-            "signatures".to_string(),
-            format!(
-                "[{}]",
-                transaction_signatures
-                    .iter()
-                    .map(|signature| format!("\"{}\"", signature))
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-        );
-        params.insert(
-            "message".to_string(),
-            ToolboxEndpoint::encode_base64(transaction_message_serialized),
-        );
-        ToolboxEndpoint::compute_explorer_url(
+        ToolboxEndpoint::compute_explorer_simulation_url_with_explorer(
+            &ToolboxEndpointExplorer::SolanaExplorer,
+            ToolboxEndpointCluster::from_url_or_moniker(rpc_url),
             rpc_url,
-            "tx",
-            "inspector",
-            &params,
+            transaction_signatures,
+            transaction_message_serialized,
         )
     }
 
+    pub fn compute_explorer_address_url_with_explorer(
+        explorer: &ToolboxEndpointExplorer,
+        cluster: ToolboxEndpointCluster,
+        rpc_url: &str,
+        address: &Pubkey,
+    ) -> String {
+        let address = address.to_string();
+        match explorer {
+            ToolboxEndpointExplorer::Solscan => {
+                ToolboxEndpoint::compute_explorer_url(
+                    explorer,
+                    cluster,
+                    rpc_url,
+                    "account",
+                    &address,
+                    &[],
+                )
+            },
+            ToolboxEndpointExplorer::SolanaExplorer
+            | ToolboxEndpointExplorer::SolanaFm => {
+                ToolboxEndpoint::compute_explorer_url(
+                    explorer,
+                    cluster,
+                    rpc_url,
+                    "address",
+                    &address,
+                    &[],
+                )
+            },
+            ToolboxEndpointExplorer::Custom {
+                address_url_template,
+                ..
+            } => ToolboxEndpoint::compute_explorer_url_from_template(
+                address_url_template,
+                cluster,
+                rpc_url,
+                &[("{address}", &address)],
+            ),
+        }
+    }
+
+    pub fn compute_explorer_signature_url_with_explorer(
+        explorer: &ToolboxEndpointExplorer,
+        cluster: ToolboxEndpointCluster,
+        rpc_url: &str,
+        signature: &Signature,
+    ) -> String {
+        let signature = signature.to_string();
+        match explorer {
+            ToolboxEndpointExplorer::Custom {
+                signature_url_template,
+                ..
+            } => ToolboxEndpoint::compute_explorer_url_from_template(
+                signature_url_template,
+                cluster,
+                rpc_url,
+                &[("{signature}", &signature)],
+            ),
+            _ => ToolboxEndpoint::compute_explorer_url(
+                explorer,
+                cluster,
+                rpc_url,
+                "tx",
+                &signature,
+                &[],
+            ),
+        }
+    }
+
+    pub fn compute_explorer_simulation_url_with_explorer(
+        explorer: &ToolboxEndpointExplorer,
+        cluster: ToolboxEndpointCluster,
+        rpc_url: &str,
+        transaction_signatures: &[Signature],
+        transaction_message_serialized: &[u8],
+    ) -> String {
+        let signatures = format!(
+            "[{}]",
+            transaction_signatures
+                .iter()
+                .map(|signature| format!("\"{}\"", signature))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        let message =
+            ToolboxEndpoint::encode_base64(transaction_message_serialized);
+        match explorer {
+            ToolboxEndpointExplorer::Custom {
+                simulation_url_template,
+                ..
+            } => ToolboxEndpoint::compute_explorer_url_from_template(
+                simulation_url_template,
+                cluster,
+                rpc_url,
+                &[
+                    ("{signatures}", &ToolboxEndpoint::encode_url(&signatures)),
+                    ("{message}", &ToolboxEndpoint::encode_url(&message)),
+                ],
+            ),
+            // Only the solana explorer supports inspecting raw transactions
+            _ => ToolboxEndpoint::compute_explorer_url(
+                &ToolboxEndpointExplorer::SolanaExplorer,
+                cluster,
+                rpc_url,
+                "tx",
+                "inspector",
+                &[
+                    ("signatures".to_string(), signatures),
+                    ("message".to_string(), message),
+                ],
+            ),
+        }
+    }
+
     fn compute_explorer_url(
+        explorer: &ToolboxEndpointExplorer,
+        cluster: ToolboxEndpointCluster,
         rpc_url: &str,
         category: &str,
         payload: &str,
-        params: &HashMap<String, String>,
+        params: &[(String, String)],
     ) -> String {
         let mut args = vec![];
         for (param_name, param_content) in params {
@@ -73,27 +231,58 @@ impl ToolboxEndpoint {
                 ToolboxEndpoint::encode_url(param_content)
             ));
         }
-        match ToolboxEndpoint::get_cluster_from_url_or_moniker(rpc_url) {
-            Some("mainnet-beta") => {},
-            Some("devnet") => {
-                args.push("cluster=devnet".to_string());
-            },
-            Some("testnet") => {
-                args.push("cluster=testnet".to_string());
+        // Solscan cannot point to a custom RPC url, fallback to the solana explorer
+        let explorer = match (explorer, cluster) {
+            (
+                ToolboxEndpointExplorer::Solscan,
+                ToolboxEndpointCluster::Custom,
+            ) => &ToolboxEndpointExplorer::SolanaExplorer,
+            _ => explorer,
+        };
+        let base_url = match explorer {
+            ToolboxEndpointExplorer::Solscan => "https://solscan.io",
+            ToolboxEndpointExplorer::SolanaFm => "https://solana.fm",
+            _ => "https://explorer.solana.com",
+        };
+        let cluster_param = match explorer {
+            ToolboxEndpointExplorer::SolanaFm => match cluster {
+                ToolboxEndpointCluster::MainnetBeta => Some("mainnet-alpha"),
+                ToolboxEndpointCluster::Devnet => Some("devnet-solana"),
+                ToolboxEndpointCluster::Testnet => Some("testnet-solana"),
+                ToolboxEndpointCluster::Custom => Some("localnet-solana"),
             },
-            _ => {
-                args.push("cluster=custom".to_string());
-                args.push(format!(
-                    "customUrl={}",
-                    ToolboxEndpoint::encode_url(rpc_url)
-                ));
+            _ => match cluster {
+                ToolboxEndpointCluster::MainnetBeta => None,
+                cluster => Some(cluster.as_str()),
             },
         };
-        format!(
-            "https://explorer.solana.com/{}/{}?{}",
-            category,
-            payload,
-            args.join("&"),
-        )
+        if let Some(cluster_param) = cluster_param {
+            args.push(format!("cluster={}", cluster_param));
+        }
+        if cluster == ToolboxEndpointCluster::Custom {
+            args.push(format!(
+                "customUrl={}",
+                ToolboxEndpoint::encode_url(rpc_url)
+            ));
+        }
+        if args.is_empty() {
+            return format!("{}/{}/{}", base_url, category, payload);
+        }
+        format!("{}/{}/{}?{}", base_url, category, payload, args.join("&"))
+    }
+
+    fn compute_explorer_url_from_template(
+        template: &str,
+        cluster: ToolboxEndpointCluster,
+        rpc_url: &str,
+        replacements: &[(&str, &str)],
+    ) -> String {
+        let mut url = template
+            .replace("{cluster}", cluster.as_str())
+            .replace("{rpc_url}", &ToolboxEndpoint::encode_url(rpc_url));
+        for (placeholder, value) in replacements {
+            url = url.replace(placeholder, value);
+        }
+        url
     }
 }
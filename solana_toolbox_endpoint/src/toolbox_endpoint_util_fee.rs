@@ -0,0 +1,112 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction::SystemInstruction;
+
+use crate::toolbox_endpoint::ToolboxEndpoint;
+
+const FEE_PRECOMPILE_PROGRAM_IDS: [Pubkey; 3] = [
+    pubkey!("KeccakSecp256k11111111111111111111111111111"),
+    pubkey!("Ed25519SigVerify111111111111111111111111111"),
+    pubkey!("Secp256r1SigVerify1111111111111111111111111"),
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolboxEndpointFee {
+    pub signatures: u64,
+    pub base_fee: u64,
+    pub prioritization_fee: u64,
+    pub total_fee: u64,
+    pub rent_exempt_deposits: u64,
+}
+
+impl ToolboxEndpoint {
+    pub async fn compute_fee_for_instructions(
+        &mut self,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
+    ) -> Result<ToolboxEndpointFee> {
+        let message = ToolboxEndpoint::compile_versioned_transaction_message(
+            payer,
+            instructions,
+            resolved_address_lookup_tables,
+            self.get_latest_blockhash().await?,
+        )?;
+        self.compute_fee_for_message(&message).await
+    }
+
+    pub async fn compute_fee_for_message(
+        &mut self,
+        message: &VersionedMessage,
+    ) -> Result<ToolboxEndpointFee> {
+        // Program ids are always static, accounts are not needed here
+        let static_addresses = message.static_account_keys();
+        let mut instructions = vec![];
+        for compiled_instruction in message.instructions() {
+            instructions.push(Instruction {
+                program_id: *static_addresses
+                    .get(usize::from(compiled_instruction.program_id_index))
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Invalid program id index: {}",
+                            compiled_instruction.program_id_index
+                        )
+                    })?,
+                accounts: vec![],
+                data: compiled_instruction.data.clone(),
+            });
+        }
+        let mut signatures =
+            u64::from(message.header().num_required_signatures);
+        for instruction in &instructions {
+            if FEE_PRECOMPILE_PROGRAM_IDS.contains(&instruction.program_id) {
+                signatures +=
+                    u64::from(instruction.data.first().copied().unwrap_or(0));
+            }
+        }
+        let base_fee = signatures * ToolboxEndpoint::LAMPORTS_PER_SIGNATURE;
+        let prioritization_fee =
+            ToolboxEndpoint::compute_prioritization_fee(&instructions)?;
+        let total_fee = base_fee + prioritization_fee;
+        if let Some(endpoint_fee) = self.get_fee_for_message(message).await? {
+            if endpoint_fee != total_fee {
+                return Err(anyhow!(
+                    "Computed fee {} does not match the endpoint fee {}",
+                    total_fee,
+                    endpoint_fee
+                ));
+            }
+        }
+        let rent = self.get_sysvar_rent().await?;
+        let mut rent_exempt_deposits = 0;
+        for instruction in &instructions {
+            if instruction.program_id != ToolboxEndpoint::SYSTEM_PROGRAM_ID {
+                continue;
+            }
+            let space = match bincode::deserialize::<SystemInstruction>(
+                &instruction.data,
+            ) {
+                Ok(SystemInstruction::CreateAccount { space, .. }) => space,
+                Ok(SystemInstruction::CreateAccountWithSeed {
+                    space, ..
+                }) => space,
+                Ok(SystemInstruction::Allocate { space }) => space,
+                Ok(SystemInstruction::AllocateWithSeed { space, .. }) => space,
+                _ => continue,
+            };
+            rent_exempt_deposits +=
+                rent.minimum_balance(usize::try_from(space)?);
+        }
+        Ok(ToolboxEndpointFee {
+            signatures,
+            base_fee,
+            prioritization_fee,
+            total_fee,
+            rent_exempt_deposits,
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::available_parallelism;
+use std::thread::scope;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+use crate::toolbox_endpoint::ToolboxEndpoint;
+
+const GRIND_KEYPAIR_BATCH_ATTEMPTS: u64 = 10_000;
+const GRIND_PDA_BATCH_ATTEMPTS: u64 = 1_000;
+const GRIND_PROGRESS_PERIOD: Duration = Duration::from_secs(1);
+const GRIND_BASE58_ALPHABET: &str =
+    "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+pub type ToolboxEndpointGrindProgressCallback = Arc<dyn Fn(u64) + Send + Sync>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolboxEndpointGrindPattern {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub ignore_case: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct ToolboxEndpointGrindOptions {
+    pub threads: Option<usize>,
+    pub cancel: Arc<AtomicBool>,
+    pub progress: Option<ToolboxEndpointGrindProgressCallback>,
+}
+
+impl ToolboxEndpointGrindPattern {
+    pub fn verify(&self) -> Result<()> {
+        for part in [&self.prefix, &self.suffix].into_iter().flatten() {
+            for character in part.chars() {
+                let valid = if self.ignore_case {
+                    GRIND_BASE58_ALPHABET
+                        .contains(character.to_ascii_lowercase())
+                        || GRIND_BASE58_ALPHABET
+                            .contains(character.to_ascii_uppercase())
+                } else {
+                    GRIND_BASE58_ALPHABET.contains(character)
+                };
+                if !valid {
+                    return Err(anyhow!(
+                        "Grind pattern contains a non-base58 character: {}",
+                        character
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, address: &Pubkey) -> bool {
+        let address = address.to_string();
+        let (address, prefix, suffix) = if self.ignore_case {
+            (
+                address.to_lowercase(),
+                self.prefix.as_ref().map(|prefix| prefix.to_lowercase()),
+                self.suffix.as_ref().map(|suffix| suffix.to_lowercase()),
+            )
+        } else {
+            (address, self.prefix.clone(), self.suffix.clone())
+        };
+        if let Some(prefix) = prefix {
+            if !address.starts_with(&prefix) {
+                return false;
+            }
+        }
+        if let Some(suffix) = suffix {
+            if !address.ends_with(&suffix) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl ToolboxEndpoint {
+    pub fn grind_keypair(
+        pattern: &ToolboxEndpointGrindPattern,
+        options: &ToolboxEndpointGrindOptions,
+    ) -> Result<Option<Keypair>> {
+        pattern.verify()?;
+        let found = Mutex::new(None);
+        ToolboxEndpoint::grind_run(
+            options,
+            GRIND_KEYPAIR_BATCH_ATTEMPTS,
+            || {
+                let keypair = Keypair::new();
+                if pattern.matches(&keypair.pubkey()) {
+                    *found.lock().unwrap() = Some(keypair);
+                    return true;
+                }
+                false
+            },
+        )?;
+        Ok(found.into_inner().unwrap())
+    }
+
+    pub fn grind_pda(
+        program_id: &Pubkey,
+        seeds_prefix: &[&[u8]],
+        seeds_suffix: &[&[u8]],
+        pattern: &ToolboxEndpointGrindPattern,
+        bump: Option<u8>,
+        options: &ToolboxEndpointGrindOptions,
+    ) -> Result<Option<(u64, Pubkey, u8)>> {
+        pattern.verify()?;
+        let counter = AtomicU64::new(0);
+        let found: Mutex<Option<(u64, Pubkey, u8)>> = Mutex::new(None);
+        ToolboxEndpoint::grind_run(options, GRIND_PDA_BATCH_ATTEMPTS, || {
+            let seed_counter = counter.fetch_add(1, Ordering::Relaxed);
+            let seed_counter_bytes = seed_counter.to_le_bytes();
+            let mut seeds = seeds_prefix.to_vec();
+            seeds.push(&seed_counter_bytes);
+            seeds.extend_from_slice(seeds_suffix);
+            let (address, address_bump) =
+                Pubkey::find_program_address(&seeds, program_id);
+            if bump.map(|bump| bump == address_bump).unwrap_or(true)
+                && pattern.matches(&address)
+            {
+                let mut found = found.lock().unwrap();
+                // Prefer the lowest of the counters matched before stopping,
+                // it is not guaranteed to be the lowest possible match
+                if found
+                    .map(|(found_counter, ..)| seed_counter < found_counter)
+                    .unwrap_or(true)
+                {
+                    *found = Some((seed_counter, address, address_bump));
+                }
+                return true;
+            }
+            false
+        })?;
+        Ok(found.into_inner().unwrap())
+    }
+
+    fn grind_run(
+        options: &ToolboxEndpointGrindOptions,
+        batch_attempts: u64,
+        attempt: impl Fn() -> bool + Sync,
+    ) -> Result<()> {
+        let threads_count = match options.threads {
+            Some(threads) => threads,
+            None => available_parallelism()?.get(),
+        };
+        if threads_count == 0 {
+            return Err(anyhow!("Grinding requires at least one thread"));
+        }
+        let attempts = AtomicU64::new(0);
+        let done = AtomicBool::new(false);
+        let progress_last = Mutex::new(Instant::now());
+        let stopped = || {
+            done.load(Ordering::Relaxed)
+                || options.cancel.load(Ordering::Relaxed)
+        };
+        scope(|scope| {
+            for _ in 0..threads_count {
+                let attempt = &attempt;
+                let attempts = &attempts;
+                let done = &done;
+                let progress_last = &progress_last;
+                let stopped = &stopped;
+                scope.spawn(move || loop {
+                    for _ in 0..batch_attempts {
+                        if stopped() {
+                            return;
+                        }
+                        if attempt() {
+                            done.store(true, Ordering::Relaxed);
+                            return;
+                        }
+                    }
+                    let attempts_total = attempts
+                        .fetch_add(batch_attempts, Ordering::Relaxed)
+                        + batch_attempts;
+                    // Only one thread reports the total, at most once per period
+                    if let Some(progress) = &options.progress {
+                        if let Ok(mut progress_last) = progress_last.try_lock()
+                        {
+                            if progress_last.elapsed() >= GRIND_PROGRESS_PERIOD
+                            {
+                                *progress_last = Instant::now();
+                                progress(attempts_total);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        Ok(())
+    }
+}
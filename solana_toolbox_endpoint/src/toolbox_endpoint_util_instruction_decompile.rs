@@ -8,6 +8,7 @@ use solana_sdk::message::CompileError;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecutionInnerInstruction;
 
 impl ToolboxEndpoint {
     pub fn decompile_transaction_payer(
@@ -78,6 +79,51 @@ impl ToolboxEndpoint {
         Ok(instructions)
     }
 
+    pub fn decompile_transaction_inner_instructions(
+        header_num_required_signatures: u8,
+        header_num_readonly_signed_accounts: u8,
+        header_num_readonly_unsigned_accounts: u8,
+        static_addresses: &[Pubkey],
+        loaded_writable_addresses: &[Pubkey],
+        loaded_readonly_addresses: &[Pubkey],
+        compiled_inner_instructions: &[Vec<(
+            CompiledInstruction,
+            Option<u32>,
+        )>],
+    ) -> Result<Vec<Vec<ToolboxEndpointExecutionInnerInstruction>>> {
+        let mut inner_instructions = vec![];
+        for compiled_instructions in compiled_inner_instructions {
+            let instructions =
+                ToolboxEndpoint::decompile_transaction_instructions(
+                    header_num_required_signatures,
+                    header_num_readonly_signed_accounts,
+                    header_num_readonly_unsigned_accounts,
+                    static_addresses,
+                    loaded_writable_addresses,
+                    loaded_readonly_addresses,
+                    &compiled_instructions
+                        .iter()
+                        .map(|(compiled_instruction, _)| {
+                            compiled_instruction.clone()
+                        })
+                        .collect::<Vec<_>>(),
+                )?;
+            inner_instructions.push(
+                instructions
+                    .into_iter()
+                    .zip(compiled_instructions)
+                    .map(|(instruction, (_, stack_height))| {
+                        ToolboxEndpointExecutionInnerInstruction {
+                            instruction,
+                            stack_height: *stack_height,
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        Ok(inner_instructions)
+    }
+
     fn decompile_transaction_signer_addresses(
         header_num_required_signatures: u8,
         static_addresses: &[Pubkey],
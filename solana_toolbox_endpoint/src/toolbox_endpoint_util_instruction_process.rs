@@ -1,8 +1,10 @@
+use std::slice::from_ref;
+
 use anyhow::Result;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
@@ -10,7 +12,7 @@ use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
 impl ToolboxEndpoint {
     pub async fn process_instruction(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instruction: Instruction,
     ) -> Result<(Signature, ToolboxEndpointExecution)> {
         self.process_instructions_with_options(
@@ -25,9 +27,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_instruction_with_signers(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instruction: Instruction,
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
     ) -> Result<(Signature, ToolboxEndpointExecution)> {
         self.process_instructions_with_options(
             payer,
@@ -41,9 +43,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_instructions_with_signers(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
     ) -> Result<(Signature, ToolboxEndpointExecution)> {
         self.process_instructions_with_options(
             payer,
@@ -57,9 +59,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_instructions_with_options(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
         resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
         verify_prelight: bool,
     ) -> Result<(Signature, ToolboxEndpointExecution)> {
@@ -77,4 +79,37 @@ impl ToolboxEndpoint {
         )
         .await
     }
+
+    pub(crate) async fn process_instructions_separately_with_signers(
+        &mut self,
+        payer: &dyn Signer,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        parallelism: usize,
+    ) -> Result<Vec<Result<(Signature, ToolboxEndpointExecution)>>> {
+        let mut results = vec![];
+        for instructions_batch in instructions.chunks(parallelism.max(1)) {
+            let latest_blockhash = self.get_latest_blockhash().await?;
+            let mut versioned_transactions = vec![];
+            for instruction in instructions_batch {
+                versioned_transactions.push(
+                    ToolboxEndpoint::compile_versioned_transaction(
+                        payer,
+                        from_ref(instruction),
+                        signers,
+                        &[],
+                        latest_blockhash,
+                    )?,
+                );
+            }
+            results.extend(
+                self.process_versioned_transactions(
+                    versioned_transactions,
+                    true,
+                )
+                .await?,
+            );
+        }
+        Ok(results)
+    }
 }
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
@@ -9,7 +9,7 @@ use crate::toolbox_endpoint_execution::ToolboxEndpointExecution;
 impl ToolboxEndpoint {
     pub async fn simulate_instruction(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instruction: Instruction,
     ) -> Result<ToolboxEndpointExecution> {
         self.simulate_instructions_with_options(
@@ -24,9 +24,9 @@ impl ToolboxEndpoint {
 
     pub async fn simulate_instruction_with_signers(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instruction: Instruction,
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
     ) -> Result<ToolboxEndpointExecution> {
         self.simulate_instructions_with_options(
             payer,
@@ -40,9 +40,9 @@ impl ToolboxEndpoint {
 
     pub async fn simulate_instructions_with_signers(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
     ) -> Result<ToolboxEndpointExecution> {
         self.simulate_instructions_with_options(
             payer,
@@ -56,9 +56,9 @@ impl ToolboxEndpoint {
 
     pub async fn simulate_instructions_with_options(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
         resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
         verify_signatures: bool,
     ) -> Result<ToolboxEndpointExecution> {
@@ -1,7 +1,6 @@
 use anyhow::anyhow;
 use anyhow::Context;
 use anyhow::Result;
-use solana_sdk::account::Account;
 use solana_sdk::bpf_loader;
 use solana_sdk::bpf_loader_upgradeable;
 use solana_sdk::bpf_loader_upgradeable::close_any;
@@ -12,58 +11,61 @@ use solana_sdk::bpf_loader_upgradeable::set_buffer_authority;
 use solana_sdk::bpf_loader_upgradeable::upgrade;
 use solana_sdk::bpf_loader_upgradeable::write;
 use solana_sdk::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 
+pub(crate) const PROGRAM_BUFFER_WRITE_PACKING: usize = 914;
+pub(crate) const PROGRAM_BUFFER_WRITE_PARALLELISM: usize = 8;
+const PROGRAM_BUFFER_WRITE_ROUNDS_MAX: usize = 3;
+
+// Loader-v4 program accounts store the slot, authority and status first
+pub(crate) const PROGRAM_LOADER_V4_BYTECODE_OFFSET: usize = 48;
+pub(crate) const PROGRAM_LOADER_V4_STATUS_FINALIZED: u64 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolboxEndpointProgramBufferWriteReport {
+    pub program_buffer: Pubkey,
+    pub chunks_total: usize,
+    pub chunks_skipped: usize,
+    pub chunks_written: usize,
+    pub chunks_failed: usize,
+    pub rounds: usize,
+}
+
 impl ToolboxEndpoint {
     pub const BPF_LOADER_2_PROGRAM_ID: Pubkey = bpf_loader::ID;
     pub const BPF_LOADER_UPGRADEABLE_PROGRAM_ID: Pubkey =
         bpf_loader_upgradeable::ID;
+    pub const LOADER_V4_PROGRAM_ID: Pubkey =
+        pubkey!("LoaderV411111111111111111111111111111111111");
 
     pub async fn get_program_meta(
         &mut self,
         program_id: &Pubkey,
     ) -> Result<Option<(u64, Option<Pubkey>)>> {
-        self.get_program_data_account(program_id)
+        Ok(self
+            .get_program_meta_and_bytecode(program_id)
             .await?
-            .map(|program_data| {
-                match bincode::deserialize::<UpgradeableLoaderState>(
-                    &program_data.data,
-                )? {
-                    UpgradeableLoaderState::ProgramData {
-                        slot,
-                        upgrade_authority_address,
-                    } => Ok((slot, upgrade_authority_address)),
-                    _ => Err(anyhow!("Program data is malformed")),
-                }
-            })
-            .transpose()
+            .map(|(slot, upgrade_authority, _)| (slot, upgrade_authority)))
     }
 
     pub async fn get_program_bytecode(
         &mut self,
         program_id: &Pubkey,
     ) -> Result<Option<Vec<u8>>> {
-        self.get_program_data_account(program_id)
+        Ok(self
+            .get_program_meta_and_bytecode(program_id)
             .await?
-            .map(|program_data| {
-                let program_data_bytecode_offset =
-                    UpgradeableLoaderState::size_of_programdata_metadata();
-                if program_data.data.len() < program_data_bytecode_offset {
-                    return Err(anyhow!("Program data is too small"));
-                }
-                Ok(program_data.data[program_data_bytecode_offset..].to_vec())
-            })
-            .transpose()
+            .map(|(_, _, program_bytecode)| program_bytecode))
     }
 
-    async fn get_program_data_account(
+    pub(crate) async fn get_program_meta_and_bytecode(
         &mut self,
         program_id: &Pubkey,
-    ) -> Result<Option<Account>> {
+    ) -> Result<Option<(u64, Option<Pubkey>, Vec<u8>)>> {
         let program_id_account = match self.get_account(program_id).await? {
             Some(account) => account,
             None => {
@@ -73,16 +75,77 @@ impl ToolboxEndpoint {
         if !program_id_account.executable {
             return Err(anyhow!("Program Id is not executable"));
         }
+        if program_id_account.owner == bpf_loader::ID {
+            // Non-upgradeable programs don't record a deployment slot
+            return Ok(Some((0, None, program_id_account.data)));
+        }
+        if program_id_account.owner == ToolboxEndpoint::LOADER_V4_PROGRAM_ID {
+            return ToolboxEndpoint::parse_program_loader_v4_meta_and_bytecode(
+                &program_id_account.data,
+            )
+            .map(Some);
+        }
         if program_id_account.owner != bpf_loader_upgradeable::ID {
             return Err(anyhow!("Unsupported program owner"));
         }
-        self.get_account(&ToolboxEndpoint::find_program_data_from_program_id(
-            program_id,
+        let program_data = match self
+            .get_account(&ToolboxEndpoint::find_program_data_from_program_id(
+                program_id,
+            ))
+            .await?
+        {
+            Some(account) => account,
+            None => {
+                return Ok(None);
+            },
+        };
+        let (slot, upgrade_authority) = match bincode::deserialize::<
+            UpgradeableLoaderState,
+        >(&program_data.data)?
+        {
+            UpgradeableLoaderState::ProgramData {
+                slot,
+                upgrade_authority_address,
+            } => (slot, upgrade_authority_address),
+            _ => return Err(anyhow!("Program data is malformed")),
+        };
+        let program_data_bytecode_offset =
+            UpgradeableLoaderState::size_of_programdata_metadata();
+        if program_data.data.len() < program_data_bytecode_offset {
+            return Err(anyhow!("Program data is too small"));
+        }
+        Ok(Some((
+            slot,
+            upgrade_authority,
+            program_data.data[program_data_bytecode_offset..].to_vec(),
+        )))
+    }
+
+    fn parse_program_loader_v4_meta_and_bytecode(
+        program_data: &[u8],
+    ) -> Result<(u64, Option<Pubkey>, Vec<u8>)> {
+        let program_data_bytecode_offset = PROGRAM_LOADER_V4_BYTECODE_OFFSET;
+        if program_data.len() < program_data_bytecode_offset {
+            return Err(anyhow!("Program data is too small"));
+        }
+        let slot = u64::from_le_bytes(program_data[0..8].try_into()?);
+        let authority = Pubkey::new_from_array(program_data[8..40].try_into()?);
+        let status = u64::from_le_bytes(program_data[40..48].try_into()?);
+        let authority = match status {
+            0 | 1 => Some(authority),
+            2 => None,
+            _ => return Err(anyhow!("Program status is malformed")),
+        };
+        Ok((
+            slot,
+            authority,
+            program_data[program_data_bytecode_offset..].to_vec(),
         ))
-        .await
     }
 
-    fn find_program_data_from_program_id(program_id: &Pubkey) -> Pubkey {
+    pub(crate) fn find_program_data_from_program_id(
+        program_id: &Pubkey,
+    ) -> Pubkey {
         Pubkey::find_program_address(
             &[program_id.as_ref()],
             &bpf_loader_upgradeable::ID,
@@ -92,13 +155,57 @@ impl ToolboxEndpoint {
 
     pub async fn process_program_buffer_new(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
+        program_buffer: &dyn Signer,
+        program_buffer_authority: &dyn Signer,
         program_bytecode: &[u8],
         program_authority: &Pubkey,
-    ) -> Result<Pubkey> {
-        let program_buffer = Keypair::new();
-        let program_buffer_authority = Keypair::new();
-        let program_bytecode_len = program_bytecode.len();
+    ) -> Result<ToolboxEndpointProgramBufferWriteReport> {
+        // An already existing buffer is resumed instead of being re-created
+        if !self
+            .get_account_exists(&program_buffer.pubkey())
+            .await
+            .context("Get Program Buffer Account")?
+        {
+            self.process_program_buffer_init(
+                payer,
+                program_buffer,
+                &program_buffer_authority.pubkey(),
+                program_bytecode.len(),
+            )
+            .await
+            .context("Process Program Buffer Init")?;
+        }
+        let report = self
+            .process_program_buffer_write(
+                payer,
+                &program_buffer.pubkey(),
+                program_buffer_authority,
+                program_bytecode,
+                PROGRAM_BUFFER_WRITE_PARALLELISM,
+            )
+            .await
+            .context("Process Program Buffer Write")?;
+        if program_buffer_authority.pubkey() != *program_authority {
+            self.process_program_buffer_set_authority(
+                payer,
+                &program_buffer.pubkey(),
+                program_buffer_authority,
+                program_authority,
+            )
+            .await
+            .context("Process Program Buffer Set Authority")?;
+        }
+        Ok(report)
+    }
+
+    pub async fn process_program_buffer_init(
+        &mut self,
+        payer: &dyn Signer,
+        program_buffer: &dyn Signer,
+        program_buffer_authority: &Pubkey,
+        program_bytecode_len: usize,
+    ) -> Result<()> {
         let rent_space =
             UpgradeableLoaderState::size_of_buffer(program_bytecode_len);
         let rent_minimum_lamports = self
@@ -109,7 +216,7 @@ impl ToolboxEndpoint {
         let instructions_create = create_buffer(
             &payer.pubkey(),
             &program_buffer.pubkey(),
-            &program_buffer_authority.pubkey(),
+            program_buffer_authority,
             rent_minimum_lamports,
             program_bytecode_len,
         )
@@ -117,51 +224,164 @@ impl ToolboxEndpoint {
         self.process_instructions_with_signers(
             payer,
             &instructions_create,
-            &[&program_buffer],
+            &[program_buffer],
         )
         .await
         .context("Process Create Buffer Instructions")?;
-        let write_packing = 914;
-        let write_count = program_bytecode_len.div_ceil(write_packing);
-        for write_index in 0..write_count {
-            let write_before = write_index * write_packing;
-            let write_after =
-                (write_before + write_packing).min(program_bytecode_len);
-            let instruction_write = write(
-                &program_buffer.pubkey(),
-                &program_buffer_authority.pubkey(),
-                u32::try_from(write_before)?,
-                program_bytecode[write_before..write_after].to_vec(),
-            );
-            self.process_instruction_with_signers(
-                payer,
-                instruction_write,
-                &[&program_buffer_authority],
-            )
-            .await
-            .context("Process Write Buffer Instruction")?;
+        Ok(())
+    }
+
+    pub async fn process_program_buffer_write(
+        &mut self,
+        payer: &dyn Signer,
+        program_buffer: &Pubkey,
+        program_buffer_authority: &dyn Signer,
+        program_bytecode: &[u8],
+        write_parallelism: usize,
+    ) -> Result<ToolboxEndpointProgramBufferWriteReport> {
+        let write_chunks = program_bytecode
+            .chunks(PROGRAM_BUFFER_WRITE_PACKING)
+            .enumerate()
+            .collect::<Vec<_>>();
+        let mut report = ToolboxEndpointProgramBufferWriteReport {
+            program_buffer: *program_buffer,
+            chunks_total: write_chunks.len(),
+            chunks_skipped: 0,
+            chunks_written: 0,
+            chunks_failed: 0,
+            rounds: 0,
+        };
+        loop {
+            let program_buffer_bytecode = self
+                .get_program_buffer_bytecode(
+                    program_buffer,
+                    &program_buffer_authority.pubkey(),
+                    program_bytecode.len(),
+                )
+                .await
+                .context("Get Program Buffer Bytecode")?;
+            let write_chunks_mismatched = write_chunks
+                .iter()
+                .filter(|(write_index, write_chunk)| {
+                    let write_before =
+                        write_index * PROGRAM_BUFFER_WRITE_PACKING;
+                    let write_after = write_before + write_chunk.len();
+                    program_buffer_bytecode[write_before..write_after]
+                        != **write_chunk
+                })
+                .collect::<Vec<_>>();
+            if report.rounds == 0 {
+                report.chunks_skipped =
+                    write_chunks.len() - write_chunks_mismatched.len();
+            }
+            if write_chunks_mismatched.is_empty() {
+                return Ok(report);
+            }
+            if report.rounds >= PROGRAM_BUFFER_WRITE_ROUNDS_MAX {
+                return Err(anyhow!(
+                    "Program buffer verification failed: {} mismatched chunks (after {} rounds)",
+                    write_chunks_mismatched.len(),
+                    report.rounds,
+                ));
+            }
+            report.rounds += 1;
+            let mut instructions_write = vec![];
+            for (write_index, write_chunk) in write_chunks_mismatched {
+                instructions_write.push(write(
+                    program_buffer,
+                    &program_buffer_authority.pubkey(),
+                    u32::try_from(write_index * PROGRAM_BUFFER_WRITE_PACKING)?,
+                    write_chunk.to_vec(),
+                ));
+            }
+            for result in self
+                .process_instructions_separately_with_signers(
+                    payer,
+                    &instructions_write,
+                    &[program_buffer_authority],
+                    write_parallelism,
+                )
+                .await
+                .context("Process Write Buffer Instructions")?
+            {
+                match result {
+                    Ok(_) => report.chunks_written += 1,
+                    Err(_) => report.chunks_failed += 1,
+                }
+            }
         }
+    }
+
+    pub async fn process_program_buffer_set_authority(
+        &mut self,
+        payer: &dyn Signer,
+        program_buffer: &Pubkey,
+        program_buffer_authority: &dyn Signer,
+        program_buffer_authority_new: &Pubkey,
+    ) -> Result<()> {
         let instruction_set_authority = set_buffer_authority(
-            &program_buffer.pubkey(),
+            program_buffer,
             &program_buffer_authority.pubkey(),
-            program_authority,
+            program_buffer_authority_new,
         );
         self.process_instruction_with_signers(
             payer,
             instruction_set_authority,
-            &[&program_buffer_authority],
+            &[program_buffer_authority],
         )
-        .await
-        .context("Process Set Authority Instruction")?;
-        Ok(program_buffer.pubkey())
+        .await?;
+        Ok(())
+    }
+
+    async fn get_program_buffer_bytecode(
+        &mut self,
+        program_buffer: &Pubkey,
+        program_buffer_authority: &Pubkey,
+        program_bytecode_len: usize,
+    ) -> Result<Vec<u8>> {
+        let program_buffer_account =
+            self.get_account(program_buffer).await?.ok_or_else(|| {
+                anyhow!(
+                    "Could not get account: {} (program buffer)",
+                    program_buffer
+                )
+            })?;
+        if program_buffer_account.owner != bpf_loader_upgradeable::ID {
+            return Err(anyhow!("Program buffer has an unexpected owner"));
+        }
+        match bincode::deserialize::<UpgradeableLoaderState>(
+            &program_buffer_account.data,
+        )? {
+            UpgradeableLoaderState::Buffer { authority_address }
+                if authority_address == Some(*program_buffer_authority) => {},
+            UpgradeableLoaderState::Buffer { .. } => {
+                return Err(anyhow!(
+                    "Program buffer authority mismatch: expected: {}",
+                    program_buffer_authority
+                ))
+            },
+            _ => return Err(anyhow!("Program buffer is malformed")),
+        };
+        let program_buffer_bytecode_offset =
+            UpgradeableLoaderState::size_of_buffer_metadata();
+        let program_buffer_bytecode =
+            &program_buffer_account.data[program_buffer_bytecode_offset..];
+        if program_buffer_bytecode.len() < program_bytecode_len {
+            return Err(anyhow!(
+                "Program buffer is too small: {} bytes (expected: {} bytes)",
+                program_buffer_bytecode.len(),
+                program_bytecode_len
+            ));
+        }
+        Ok(program_buffer_bytecode.to_vec())
     }
 
     pub async fn process_program_buffer_deploy(
         &mut self,
-        payer: &Keypair,
-        program_id: &Keypair,
+        payer: &dyn Signer,
+        program_id: &dyn Signer,
         program_buffer: &Pubkey,
-        program_authority: &Keypair,
+        program_authority: &dyn Signer,
         program_bytecode_len: usize,
     ) -> Result<()> {
         let rent_space = UpgradeableLoaderState::size_of_program();
@@ -186,10 +406,10 @@ impl ToolboxEndpoint {
 
     pub async fn process_program_buffer_upgrade(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         program_id: &Pubkey,
         program_buffer: &Pubkey,
-        program_authority: &Keypair,
+        program_authority: &dyn Signer,
         spill: &Pubkey,
     ) -> Result<()> {
         let instruction_upgrade = upgrade(
@@ -209,9 +429,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_program_buffer_close(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         program_buffer: &Pubkey,
-        program_authority: &Keypair,
+        program_authority: &dyn Signer,
         spill: &Pubkey,
     ) -> Result<()> {
         let program_authority_address = &program_authority.pubkey();
@@ -232,11 +452,11 @@ impl ToolboxEndpoint {
 
     pub async fn process_program_deploy(
         &mut self,
-        payer: &Keypair,
-        program_id: &Keypair,
-        program_authority: &Keypair,
+        payer: &dyn Signer,
+        program_id: &dyn Signer,
+        program_authority: &dyn Signer,
         program_bytecode: &[u8],
-    ) -> Result<()> {
+    ) -> Result<ToolboxEndpointProgramBufferWriteReport> {
         if self
             .get_account_exists(&program_id.pubkey())
             .await
@@ -246,29 +466,35 @@ impl ToolboxEndpoint {
                 "Cannot deploy on a program that already exist (need to upgrade)",
             ));
         }
-        let program_buffer = self
+        // The buffer is owned by the program authority so it can be closed
+        let program_buffer = self.generate_keypair();
+        let report = self
             .process_program_buffer_new(
                 payer,
+                &program_buffer,
+                program_authority,
                 program_bytecode,
                 &program_authority.pubkey(),
             )
             .await
-            .context("Program Buffer New")?;
+            .with_context(|| {
+                format!("Program Buffer New: {}", program_buffer.pubkey())
+            })?;
         self.process_program_buffer_deploy(
             payer,
             program_id,
-            &program_buffer,
+            &program_buffer.pubkey(),
             program_authority,
             program_bytecode.len(),
         )
         .await
         .context("Process Program Buffer Deploy")?;
-        Ok(())
+        Ok(report)
     }
 
     pub async fn process_program_extend(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         program_id: &Pubkey,
         program_bytecode_len_added: usize,
     ) -> Result<()> {
@@ -283,12 +509,12 @@ impl ToolboxEndpoint {
 
     pub async fn process_program_upgrade(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         program_id: &Pubkey,
-        program_authority: &Keypair,
+        program_authority: &dyn Signer,
         program_bytecode: &[u8],
         spill: &Pubkey,
-    ) -> Result<()> {
+    ) -> Result<ToolboxEndpointProgramBufferWriteReport> {
         let program_bytecode_len_before =
             match self.get_program_bytecode(program_id).await? {
                 Some(program_bytecode) => program_bytecode.len(),
@@ -304,31 +530,40 @@ impl ToolboxEndpoint {
             .await
             .context("Process Program Extend")?;
         }
-        let program_buffer = self
+        // The buffer is owned by the program authority so it can be closed
+        let program_buffer = self.generate_keypair();
+        let report = self
             .process_program_buffer_new(
                 payer,
+                &program_buffer,
+                program_authority,
                 program_bytecode,
                 &program_authority.pubkey(),
             )
             .await
-            .context("Process Program Buffer New")?;
+            .with_context(|| {
+                format!(
+                    "Process Program Buffer New: {}",
+                    program_buffer.pubkey()
+                )
+            })?;
         self.process_program_buffer_upgrade(
             payer,
             program_id,
-            &program_buffer,
+            &program_buffer.pubkey(),
             program_authority,
             spill,
         )
         .await
         .context("Process Program Buffer Upgrade")?;
-        Ok(())
+        Ok(report)
     }
 
     pub async fn process_program_close(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         program_id: &Pubkey,
-        program_authority: &Keypair,
+        program_authority: &dyn Signer,
         spill: &Pubkey,
     ) -> Result<()> {
         let program_data =
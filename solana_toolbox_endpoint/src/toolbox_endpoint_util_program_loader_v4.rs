@@ -0,0 +1,254 @@
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use solana_loader_v4_interface::instruction::create_buffer;
+use solana_loader_v4_interface::instruction::deploy;
+use solana_loader_v4_interface::instruction::finalize;
+use solana_loader_v4_interface::instruction::retract;
+use solana_loader_v4_interface::instruction::set_program_length;
+use solana_loader_v4_interface::instruction::transfer_authority;
+use solana_loader_v4_interface::instruction::write;
+use solana_loader_v4_interface::state::LoaderV4State;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+
+use crate::toolbox_endpoint::ToolboxEndpoint;
+use crate::toolbox_endpoint_util_program::PROGRAM_BUFFER_WRITE_PACKING;
+use crate::toolbox_endpoint_util_program::PROGRAM_BUFFER_WRITE_PARALLELISM;
+
+impl ToolboxEndpoint {
+    pub async fn process_program_loader_v4_deploy(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &dyn Signer,
+        program_authority: &dyn Signer,
+        program_bytecode: &[u8],
+    ) -> Result<()> {
+        if self
+            .get_account_exists(&program_id.pubkey())
+            .await
+            .context("Get ProgramId Account")?
+        {
+            return Err(anyhow!(
+                "Cannot deploy on a program that already exist (need to upgrade)",
+            ));
+        }
+        let rent_space =
+            LoaderV4State::program_data_offset() + program_bytecode.len();
+        let rent_minimum_lamports = self
+            .get_sysvar_rent()
+            .await
+            .context("Get Sysvar Rent")?
+            .minimum_balance(rent_space);
+        let instructions_create = create_buffer(
+            &payer.pubkey(),
+            &program_id.pubkey(),
+            rent_minimum_lamports,
+            &program_authority.pubkey(),
+            u32::try_from(program_bytecode.len())?,
+            &payer.pubkey(),
+        );
+        self.process_instructions_with_signers(
+            payer,
+            &instructions_create,
+            &[program_id, program_authority],
+        )
+        .await
+        .context("Process Create Program Instructions")?;
+        self.process_program_loader_v4_write(
+            payer,
+            &program_id.pubkey(),
+            program_authority,
+            program_bytecode,
+        )
+        .await
+        .context("Process Program Write")?;
+        let instruction_deploy =
+            deploy(&program_id.pubkey(), &program_authority.pubkey());
+        self.process_instruction_with_signers(
+            payer,
+            instruction_deploy,
+            &[program_authority],
+        )
+        .await
+        .context("Process Deploy Program Instruction")?;
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_upgrade(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+        program_bytecode: &[u8],
+    ) -> Result<()> {
+        self.process_program_loader_v4_retract(
+            payer,
+            program_id,
+            program_authority,
+        )
+        .await
+        .context("Process Program Retract")?;
+        self.process_program_loader_v4_set_program_length(
+            payer,
+            program_id,
+            program_authority,
+            program_bytecode.len(),
+            &payer.pubkey(),
+        )
+        .await
+        .context("Process Program Set Length")?;
+        self.process_program_loader_v4_write(
+            payer,
+            program_id,
+            program_authority,
+            program_bytecode,
+        )
+        .await
+        .context("Process Program Write")?;
+        let instruction_deploy =
+            deploy(program_id, &program_authority.pubkey());
+        self.process_instruction_with_signers(
+            payer,
+            instruction_deploy,
+            &[program_authority],
+        )
+        .await
+        .context("Process Deploy Program Instruction")?;
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_write(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+        program_bytecode: &[u8],
+    ) -> Result<()> {
+        let mut instructions_write = vec![];
+        for (write_index, write_chunk) in program_bytecode
+            .chunks(PROGRAM_BUFFER_WRITE_PACKING)
+            .enumerate()
+        {
+            instructions_write.push(write(
+                program_id,
+                &program_authority.pubkey(),
+                u32::try_from(write_index * PROGRAM_BUFFER_WRITE_PACKING)?,
+                write_chunk.to_vec(),
+            ));
+        }
+        for result in self
+            .process_instructions_separately_with_signers(
+                payer,
+                &instructions_write,
+                &[program_authority],
+                PROGRAM_BUFFER_WRITE_PARALLELISM,
+            )
+            .await?
+        {
+            result.context("Process Write Program Instruction")?;
+        }
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_set_program_length(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+        program_bytecode_len: usize,
+        spill: &Pubkey,
+    ) -> Result<()> {
+        let mut instructions = vec![];
+        if program_bytecode_len > 0 {
+            let rent_space =
+                LoaderV4State::program_data_offset() + program_bytecode_len;
+            let rent_minimum_lamports = self
+                .get_sysvar_rent()
+                .await
+                .context("Get Sysvar Rent")?
+                .minimum_balance(rent_space);
+            let program_lamports = self.get_balance(program_id).await?;
+            if rent_minimum_lamports > program_lamports {
+                instructions.push(transfer(
+                    &payer.pubkey(),
+                    program_id,
+                    rent_minimum_lamports - program_lamports,
+                ));
+            }
+        }
+        instructions.push(set_program_length(
+            program_id,
+            &program_authority.pubkey(),
+            u32::try_from(program_bytecode_len)?,
+            spill,
+        ));
+        self.process_instructions_with_signers(
+            payer,
+            &instructions,
+            &[program_authority],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_retract(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+    ) -> Result<()> {
+        let instruction_retract =
+            retract(program_id, &program_authority.pubkey());
+        self.process_instruction_with_signers(
+            payer,
+            instruction_retract,
+            &[program_authority],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_transfer_authority(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+        program_authority_new: &dyn Signer,
+    ) -> Result<()> {
+        let instruction_transfer_authority = transfer_authority(
+            program_id,
+            &program_authority.pubkey(),
+            &program_authority_new.pubkey(),
+        );
+        self.process_instruction_with_signers(
+            payer,
+            instruction_transfer_authority,
+            &[program_authority, program_authority_new],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn process_program_loader_v4_finalize(
+        &mut self,
+        payer: &dyn Signer,
+        program_id: &Pubkey,
+        program_authority: &dyn Signer,
+        program_id_next_version: &Pubkey,
+    ) -> Result<()> {
+        let instruction_finalize = finalize(
+            program_id,
+            &program_authority.pubkey(),
+            program_id_next_version,
+        );
+        self.process_instruction_with_signers(
+            payer,
+            instruction_finalize,
+            &[program_authority],
+        )
+        .await?;
+        Ok(())
+    }
+}
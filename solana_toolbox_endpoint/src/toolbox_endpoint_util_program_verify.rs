@@ -0,0 +1,92 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::toolbox_endpoint::ToolboxEndpoint;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolboxEndpointProgramVerification {
+    pub matches: bool,
+    pub slot: u64,
+    pub upgrade_authority: Option<Pubkey>,
+    pub onchain_hash: Hash,
+    pub onchain_length: usize,
+    pub local_hash: Hash,
+    pub local_length: usize,
+    pub diff: Option<ToolboxEndpointProgramVerificationDiff>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolboxEndpointProgramVerificationDiff {
+    pub first_offset: usize,
+    pub differing_bytes: usize,
+    pub differing_ranges: Vec<(usize, usize)>,
+}
+
+impl ToolboxEndpoint {
+    pub async fn verify_program_bytecode(
+        &mut self,
+        program_id: &Pubkey,
+        local_bytecode: &[u8],
+    ) -> Result<ToolboxEndpointProgramVerification> {
+        let (slot, upgrade_authority, onchain_bytecode) = self
+            .get_program_meta_and_bytecode(program_id)
+            .await?
+            .ok_or_else(|| anyhow!("Could not find program: {}", program_id))?;
+        let onchain_bytecode =
+            ToolboxEndpoint::trim_program_bytecode(&onchain_bytecode);
+        let local_bytecode =
+            ToolboxEndpoint::trim_program_bytecode(local_bytecode);
+        let diff = ToolboxEndpoint::compute_program_bytecode_diff(
+            onchain_bytecode,
+            local_bytecode,
+        );
+        Ok(ToolboxEndpointProgramVerification {
+            matches: diff.is_none(),
+            slot,
+            upgrade_authority,
+            onchain_hash: ToolboxEndpoint::hash_bytes(onchain_bytecode),
+            onchain_length: onchain_bytecode.len(),
+            local_hash: ToolboxEndpoint::hash_bytes(local_bytecode),
+            local_length: local_bytecode.len(),
+            diff,
+        })
+    }
+
+    fn trim_program_bytecode(program_bytecode: &[u8]) -> &[u8] {
+        let program_bytecode_len = program_bytecode
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map(|position| position + 1)
+            .unwrap_or(0);
+        &program_bytecode[..program_bytecode_len]
+    }
+
+    fn compute_program_bytecode_diff(
+        onchain_bytecode: &[u8],
+        local_bytecode: &[u8],
+    ) -> Option<ToolboxEndpointProgramVerificationDiff> {
+        let mut differing_bytes = 0;
+        let mut differing_ranges: Vec<(usize, usize)> = vec![];
+        for offset in 0..onchain_bytecode.len().max(local_bytecode.len()) {
+            let onchain_byte = onchain_bytecode.get(offset).unwrap_or(&0);
+            let local_byte = local_bytecode.get(offset).unwrap_or(&0);
+            if onchain_byte == local_byte {
+                continue;
+            }
+            differing_bytes += 1;
+            match differing_ranges.last_mut() {
+                Some(differing_range) if differing_range.1 == offset => {
+                    differing_range.1 = offset + 1;
+                },
+                _ => differing_ranges.push((offset, offset + 1)),
+            }
+        }
+        Some(ToolboxEndpointProgramVerificationDiff {
+            first_offset: differing_ranges.first()?.0,
+            differing_bytes,
+            differing_ranges,
+        })
+    }
+}
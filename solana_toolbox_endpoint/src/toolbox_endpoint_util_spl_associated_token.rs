@@ -1,6 +1,5 @@
 use anyhow::Result;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use spl_associated_token_account::get_associated_token_address;
 use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
@@ -13,7 +12,7 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_associated_token_account_get_or_init(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         owner: &Pubkey,
         mint: &Pubkey,
     ) -> Result<Pubkey> {
@@ -1,7 +1,6 @@
 use anyhow::Result;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction::create_account;
 use spl_token::instruction::burn;
@@ -25,12 +24,12 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_mint_new(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint_authority: &Pubkey,
         mint_freeze_authority: Option<&Pubkey>,
         mint_decimals: u8,
     ) -> Result<Pubkey> {
-        let mint = Keypair::new();
+        let mint = self.generate_keypair();
         self.process_spl_token_mint_init(
             payer,
             &mint,
@@ -44,8 +43,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_mint_init(
         &mut self,
-        payer: &Keypair,
-        mint: &Keypair,
+        payer: &dyn Signer,
+        mint: &dyn Signer,
         mint_authority: &Pubkey,
         mint_freeze_authority: Option<&Pubkey>,
         mint_decimals: u8,
@@ -78,9 +77,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_mint_set_authority(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        source_mint_authority: &Keypair,
+        source_mint_authority: &dyn Signer,
         destination_mint_authority: Option<&Pubkey>,
     ) -> Result<()> {
         let instruction = set_authority(
@@ -102,9 +101,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_mint_to(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        mint_authority: &Keypair,
+        mint_authority: &dyn Signer,
         destination_token_account: &Pubkey,
         amount: u64,
     ) -> Result<()> {
@@ -127,9 +126,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_mint_set_freeze_authority(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        source_mint_freeze_authority: &Keypair,
+        source_mint_freeze_authority: &dyn Signer,
         destination_mint_freeze_authority: Option<&Pubkey>,
     ) -> Result<()> {
         let instruction = set_authority(
@@ -151,9 +150,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_freeze(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        mint_freeze_authority: &Keypair,
+        mint_freeze_authority: &dyn Signer,
         token_account: &Pubkey,
     ) -> Result<()> {
         let instruction = freeze_account(
@@ -174,9 +173,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_thaw(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        mint_freeze_authority: &Keypair,
+        mint_freeze_authority: &dyn Signer,
         token_account: &Pubkey,
     ) -> Result<()> {
         let instruction = thaw_account(
@@ -197,8 +196,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_transfer(
         &mut self,
-        payer: &Keypair,
-        owner: &Keypair,
+        payer: &dyn Signer,
+        owner: &dyn Signer,
         source_token_account: &Pubkey,
         destination_token_account: &Pubkey,
         amount: u64,
@@ -218,8 +217,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_burn(
         &mut self,
-        payer: &Keypair,
-        owner: &Keypair,
+        payer: &dyn Signer,
+        owner: &dyn Signer,
         source_token_account: &Pubkey,
         mint: &Pubkey,
         amount: u64,
@@ -239,14 +238,14 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_account_new(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         owner: &Pubkey,
         mint: &Pubkey,
     ) -> Result<Pubkey> {
         let rent_space = Account::LEN;
         let rent_minimum_lamports =
             self.get_sysvar_rent().await?.minimum_balance(rent_space);
-        let account = Keypair::new();
+        let account = self.generate_keypair();
         let instruction_create = create_account(
             &payer.pubkey(),
             &account.pubkey(),
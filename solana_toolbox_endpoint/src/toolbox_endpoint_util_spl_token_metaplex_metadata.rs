@@ -1,3 +1,4 @@
+use anyhow::anyhow;
 use anyhow::Result;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -5,18 +6,90 @@ use solana_sdk::instruction::AccountMeta;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-struct TokenMetaplexMetadataAccountSimplified {
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexMetadata {
     pub update_authority: Pubkey,
     pub mint: Pubkey,
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<ToolboxEndpointSplTokenMetaplexMetadataCreator>>,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard:
+        Option<ToolboxEndpointSplTokenMetaplexMetadataTokenStandard>,
+    pub collection: Option<ToolboxEndpointSplTokenMetaplexMetadataCollection>,
+    pub uses: Option<ToolboxEndpointSplTokenMetaplexMetadataUses>,
+    pub collection_details:
+        Option<ToolboxEndpointSplTokenMetaplexMetadataCollectionDetails>,
+    pub programmable_config:
+        Option<ToolboxEndpointSplTokenMetaplexMetadataProgrammableConfig>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexMetadataCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ToolboxEndpointSplTokenMetaplexMetadataTokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+    ProgrammableNonFungible,
+    ProgrammableNonFungibleEdition,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexMetadataCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexMetadataUses {
+    pub use_method: ToolboxEndpointSplTokenMetaplexMetadataUseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ToolboxEndpointSplTokenMetaplexMetadataUseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ToolboxEndpointSplTokenMetaplexMetadataCollectionDetails {
+    V1 { size: u64 },
+    V2 { padding: [u8; 8] },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ToolboxEndpointSplTokenMetaplexMetadataProgrammableConfig {
+    V1 { rule_set: Option<Pubkey> },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexMasterEdition {
+    pub supply: u64,
+    pub max_supply: Option<u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ToolboxEndpointSplTokenMetaplexEdition {
+    pub parent: Pubkey,
+    pub edition: u64,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
@@ -45,6 +118,20 @@ struct TokenMetaplexMetadataUpdateArgsSimplified {
     pub is_mutable: Option<bool>,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+struct TokenMetaplexMetadataCreateMasterEditionArgs {
+    pub max_supply: Option<u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+struct TokenMetaplexMetadataSetCollectionSizeArgs {
+    pub size: u64,
+}
+
+const TOKEN_METAPLEX_METADATA_KEY_EDITION_V1: u8 = 1;
+const TOKEN_METAPLEX_METADATA_KEY_METADATA_V1: u8 = 4;
+const TOKEN_METAPLEX_METADATA_KEY_MASTER_EDITION_V2: u8 = 6;
+
 impl ToolboxEndpoint {
     pub const SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID: Pubkey =
         pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -62,34 +149,179 @@ impl ToolboxEndpoint {
         .0
     }
 
+    pub fn find_spl_token_metaplex_edition(mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"metadata",
+                ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID
+                    .as_ref(),
+                mint.as_ref(),
+                b"edition",
+            ],
+            &ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+        )
+        .0
+    }
+
     pub async fn get_spl_token_metaplex_metadata(
         &mut self,
         mint: &Pubkey,
     ) -> Result<Option<(Pubkey, String, String, String)>> {
+        Ok(self
+            .get_spl_token_metaplex_metadata_account(mint)
+            .await?
+            .map(|metadata| {
+                (
+                    metadata.update_authority,
+                    metadata.symbol,
+                    metadata.name,
+                    metadata.uri,
+                )
+            }))
+    }
+
+    pub async fn get_spl_token_metaplex_metadata_account(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<Option<ToolboxEndpointSplTokenMetaplexMetadata>> {
         self.get_account_data(
             &ToolboxEndpoint::find_spl_token_metaplex_metadata(mint),
         )
         .await?
         .map(|data| {
-            let content =
-                TokenMetaplexMetadataAccountSimplified::deserialize_reader(
-                    &mut &data[1..],
-                )?;
-            Ok((
-                content.update_authority,
-                content.symbol.trim_end_matches("\0").to_string(),
-                content.name.trim_end_matches("\0").to_string(),
-                content.uri.trim_end_matches("\0").to_string(),
-            ))
+            ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(&data)
+        })
+        .transpose()
+    }
+
+    pub fn parse_spl_token_metaplex_metadata_account(
+        metadata_data: &[u8],
+    ) -> Result<ToolboxEndpointSplTokenMetaplexMetadata> {
+        let reader = &mut ToolboxEndpoint::check_spl_token_metaplex_key(
+            metadata_data,
+            TOKEN_METAPLEX_METADATA_KEY_METADATA_V1,
+        )?;
+        let update_authority = Pubkey::deserialize_reader(reader)?;
+        let mint = Pubkey::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let symbol = String::deserialize_reader(reader)?;
+        let uri = String::deserialize_reader(reader)?;
+        let seller_fee_basis_points = u16::deserialize_reader(reader)?;
+        let creators = Option::deserialize_reader(reader)?;
+        let primary_sale_happened = bool::deserialize_reader(reader)?;
+        let is_mutable = bool::deserialize_reader(reader)?;
+        let edition_nonce =
+            ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        let token_standard =
+            ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        let collection =
+            ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        let uses = ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        let collection_details =
+            ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        let programmable_config =
+            ToolboxEndpoint::parse_spl_token_metaplex_optional(reader)?;
+        Ok(ToolboxEndpointSplTokenMetaplexMetadata {
+            update_authority,
+            mint,
+            name: name.trim_end_matches("\0").to_string(),
+            symbol: symbol.trim_end_matches("\0").to_string(),
+            uri: uri.trim_end_matches("\0").to_string(),
+            seller_fee_basis_points,
+            creators,
+            primary_sale_happened,
+            is_mutable,
+            edition_nonce,
+            token_standard,
+            collection,
+            uses,
+            collection_details,
+            programmable_config,
+        })
+    }
+
+    pub async fn get_spl_token_metaplex_master_edition(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<Option<ToolboxEndpointSplTokenMetaplexMasterEdition>> {
+        self.get_account_data(
+            &ToolboxEndpoint::find_spl_token_metaplex_edition(mint),
+        )
+        .await?
+        .map(|data| {
+            ToolboxEndpoint::parse_spl_token_metaplex_master_edition(&data)
         })
         .transpose()
     }
 
+    pub fn parse_spl_token_metaplex_master_edition(
+        master_edition_data: &[u8],
+    ) -> Result<ToolboxEndpointSplTokenMetaplexMasterEdition> {
+        Ok(
+            ToolboxEndpointSplTokenMetaplexMasterEdition::deserialize_reader(
+                &mut ToolboxEndpoint::check_spl_token_metaplex_key(
+                    master_edition_data,
+                    TOKEN_METAPLEX_METADATA_KEY_MASTER_EDITION_V2,
+                )?,
+            )?,
+        )
+    }
+
+    pub async fn get_spl_token_metaplex_edition(
+        &mut self,
+        mint: &Pubkey,
+    ) -> Result<Option<ToolboxEndpointSplTokenMetaplexEdition>> {
+        self.get_account_data(
+            &ToolboxEndpoint::find_spl_token_metaplex_edition(mint),
+        )
+        .await?
+        .map(|data| ToolboxEndpoint::parse_spl_token_metaplex_edition(&data))
+        .transpose()
+    }
+
+    pub fn parse_spl_token_metaplex_edition(
+        edition_data: &[u8],
+    ) -> Result<ToolboxEndpointSplTokenMetaplexEdition> {
+        Ok(ToolboxEndpointSplTokenMetaplexEdition::deserialize_reader(
+            &mut ToolboxEndpoint::check_spl_token_metaplex_key(
+                edition_data,
+                TOKEN_METAPLEX_METADATA_KEY_EDITION_V1,
+            )?,
+        )?)
+    }
+
+    // Fields added in later versions of the program may be missing entirely
+    fn parse_spl_token_metaplex_optional<T: BorshDeserialize>(
+        reader: &mut &[u8],
+    ) -> Result<Option<T>> {
+        if reader.is_empty() {
+            return Ok(None);
+        }
+        Ok(Option::deserialize_reader(reader)?)
+    }
+
+    fn check_spl_token_metaplex_key(
+        data: &[u8],
+        expected_key: u8,
+    ) -> Result<&[u8]> {
+        let key = *data
+            .first()
+            .ok_or_else(|| anyhow!("Metaplex account is empty"))?;
+        if key != expected_key {
+            return Err(anyhow!(
+                "Metaplex account key mismatch: found: {}, expected: {}",
+                key,
+                expected_key
+            ));
+        }
+        Ok(&data[1..])
+    }
+
     pub async fn process_spl_token_metaplex_metadata_create(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        mint_authority: &Keypair,
+        mint_authority: &dyn Signer,
         metadata: (Pubkey, String, String, String),
     ) -> Result<()> {
         let accounts = vec![
@@ -139,9 +371,9 @@ impl ToolboxEndpoint {
 
     pub async fn process_spl_token_metaplex_metadata_update(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         mint: &Pubkey,
-        metadata_authority: &Keypair,
+        metadata_authority: &dyn Signer,
         metadata: (Pubkey, String, String, String),
     ) -> Result<()> {
         let accounts = vec![
@@ -181,4 +413,155 @@ impl ToolboxEndpoint {
         .await?;
         Ok(())
     }
+
+    pub async fn process_spl_token_metaplex_master_edition_create(
+        &mut self,
+        payer: &dyn Signer,
+        mint: &Pubkey,
+        mint_authority: &dyn Signer,
+        metadata_authority: &dyn Signer,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        let accounts = vec![
+            AccountMeta::new(
+                ToolboxEndpoint::find_spl_token_metaplex_edition(mint),
+                false,
+            ),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(metadata_authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_authority.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(
+                ToolboxEndpoint::find_spl_token_metaplex_metadata(mint),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID,
+                false,
+            ),
+            AccountMeta::new_readonly(
+                ToolboxEndpoint::SYSTEM_PROGRAM_ID,
+                false,
+            ),
+            AccountMeta::new_readonly(ToolboxEndpoint::SYSVAR_RENT_ID, false),
+        ];
+        let mut data = vec![];
+        data.push(17);
+        TokenMetaplexMetadataCreateMasterEditionArgs { max_supply }
+            .serialize(&mut data)?;
+        let instruction = Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        self.process_instruction_with_signers(
+            payer,
+            instruction,
+            &[mint_authority, metadata_authority],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn process_spl_token_metaplex_metadata_collection_set_and_verify(
+        &mut self,
+        payer: &dyn Signer,
+        mint: &Pubkey,
+        collection_mint: &Pubkey,
+        collection_authority: &dyn Signer,
+    ) -> Result<()> {
+        let metadata = self
+            .get_spl_token_metaplex_metadata_account(mint)
+            .await?
+            .ok_or_else(|| {
+                anyhow!("Could not find metadata for mint: {}", mint)
+            })?;
+        let collection_metadata = self
+            .get_spl_token_metaplex_metadata_account(collection_mint)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Could not find metadata for collection mint: {}",
+                    collection_mint
+                )
+            })?;
+        let collection_sized = collection_metadata.collection_details.is_some();
+        let collection_metadata_address =
+            ToolboxEndpoint::find_spl_token_metaplex_metadata(collection_mint);
+        let collection_master_edition_address =
+            ToolboxEndpoint::find_spl_token_metaplex_edition(collection_mint);
+        let accounts = vec![
+            AccountMeta::new(
+                ToolboxEndpoint::find_spl_token_metaplex_metadata(mint),
+                false,
+            ),
+            AccountMeta::new(collection_authority.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(metadata.update_authority, false),
+            AccountMeta::new_readonly(*collection_mint, false),
+            if collection_sized {
+                AccountMeta::new(collection_metadata_address, false)
+            } else {
+                AccountMeta::new_readonly(collection_metadata_address, false)
+            },
+            if collection_sized {
+                AccountMeta::new(collection_master_edition_address, false)
+            } else {
+                AccountMeta::new_readonly(
+                    collection_master_edition_address,
+                    false,
+                )
+            },
+        ];
+        let data = vec![if collection_sized { 32 } else { 25 }];
+        let instruction = Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        self.process_instruction_with_signers(
+            payer,
+            instruction,
+            &[collection_authority],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn process_spl_token_metaplex_metadata_collection_set_size(
+        &mut self,
+        payer: &dyn Signer,
+        collection_mint: &Pubkey,
+        collection_authority: &dyn Signer,
+        collection_size: u64,
+    ) -> Result<()> {
+        let accounts = vec![
+            AccountMeta::new(
+                ToolboxEndpoint::find_spl_token_metaplex_metadata(
+                    collection_mint,
+                ),
+                false,
+            ),
+            AccountMeta::new(collection_authority.pubkey(), true),
+            AccountMeta::new_readonly(*collection_mint, false),
+        ];
+        let mut data = vec![];
+        data.push(34);
+        TokenMetaplexMetadataSetCollectionSizeArgs {
+            size: collection_size,
+        }
+        .serialize(&mut data)?;
+        let instruction = Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        self.process_instruction_with_signers(
+            payer,
+            instruction,
+            &[collection_authority],
+        )
+        .await?;
+        Ok(())
+    }
 }
@@ -1,6 +1,5 @@
 use anyhow::Result;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction::allocate;
 use solana_sdk::system_instruction::assign;
@@ -15,12 +14,12 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_new(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         lamports: u64,
         space: usize,
         owner: &Pubkey,
     ) -> Result<Pubkey> {
-        let account = Keypair::new();
+        let account = self.generate_keypair();
         self.process_system_create(payer, &account, lamports, space, owner)
             .await?;
         Ok(account.pubkey())
@@ -28,11 +27,11 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_new_exempt(
         &mut self,
-        payer: &Keypair,
+        payer: &dyn Signer,
         space: usize,
         owner: &Pubkey,
     ) -> Result<Pubkey> {
-        let account = Keypair::new();
+        let account = self.generate_keypair();
         self.process_system_create_exempt(payer, &account, space, owner)
             .await?;
         Ok(account.pubkey())
@@ -40,8 +39,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_create(
         &mut self,
-        payer: &Keypair,
-        account: &Keypair,
+        payer: &dyn Signer,
+        account: &dyn Signer,
         lamports: u64,
         space: usize,
         owner: &Pubkey,
@@ -60,8 +59,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_create_exempt(
         &mut self,
-        payer: &Keypair,
-        account: &Keypair,
+        payer: &dyn Signer,
+        account: &dyn Signer,
         space: usize,
         owner: &Pubkey,
     ) -> Result<()> {
@@ -80,8 +79,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_transfer(
         &mut self,
-        payer: &Keypair,
-        source: &Keypair,
+        payer: &dyn Signer,
+        source: &dyn Signer,
         destination: &Pubkey,
         lamports: u64,
     ) -> Result<()> {
@@ -93,8 +92,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_allocate(
         &mut self,
-        payer: &Keypair,
-        account: &Keypair,
+        payer: &dyn Signer,
+        account: &dyn Signer,
         space: usize,
     ) -> Result<()> {
         let instruction = allocate(&account.pubkey(), u64::try_from(space)?);
@@ -105,8 +104,8 @@ impl ToolboxEndpoint {
 
     pub async fn process_system_assign(
         &mut self,
-        payer: &Keypair,
-        account: &Keypair,
+        payer: &dyn Signer,
+        account: &dyn Signer,
         owner: &Pubkey,
     ) -> Result<()> {
         let instruction = assign(&account.pubkey(), owner);
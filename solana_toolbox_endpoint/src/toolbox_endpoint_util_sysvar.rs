@@ -1,20 +1,41 @@
 use anyhow::anyhow;
 use anyhow::Result;
 use solana_sdk::hash::Hash;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::slot_hashes::SlotHashes;
+use solana_sdk::stake_history::StakeHistory;
+use solana_sdk::stake_history::StakeHistoryEntry;
 use solana_sdk::sysvar::clock;
 use solana_sdk::sysvar::clock::Clock;
+use solana_sdk::sysvar::epoch_rewards;
+use solana_sdk::sysvar::epoch_rewards::EpochRewards;
+use solana_sdk::sysvar::epoch_schedule;
+use solana_sdk::sysvar::epoch_schedule::EpochSchedule;
+use solana_sdk::sysvar::fees;
+use solana_sdk::sysvar::instructions;
+use solana_sdk::sysvar::last_restart_slot;
+use solana_sdk::sysvar::last_restart_slot::LastRestartSlot;
+use solana_sdk::sysvar::recent_blockhashes;
 use solana_sdk::sysvar::rent;
 use solana_sdk::sysvar::rent::Rent;
 use solana_sdk::sysvar::slot_hashes;
+use solana_sdk::sysvar::stake_history;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
 
 impl ToolboxEndpoint {
     pub const SYSVAR_CLOCK_ID: Pubkey = clock::ID;
+    pub const SYSVAR_EPOCH_REWARDS_ID: Pubkey = epoch_rewards::ID;
+    pub const SYSVAR_EPOCH_SCHEDULE_ID: Pubkey = epoch_schedule::ID;
+    pub const SYSVAR_FEES_ID: Pubkey = fees::ID;
+    pub const SYSVAR_INSTRUCTIONS_ID: Pubkey = instructions::ID;
+    pub const SYSVAR_LAST_RESTART_SLOT_ID: Pubkey = last_restart_slot::ID;
+    pub const SYSVAR_RECENT_BLOCKHASHES_ID: Pubkey = recent_blockhashes::ID;
     pub const SYSVAR_RENT_ID: Pubkey = rent::ID;
     pub const SYSVAR_SLOT_HASHES: Pubkey = slot_hashes::ID;
+    pub const SYSVAR_STAKE_HISTORY_ID: Pubkey = stake_history::ID;
 
     pub async fn get_sysvar_clock(&mut self) -> Result<Clock> {
         self.get_account_data_bincode_deserialized(
@@ -56,4 +77,227 @@ impl ToolboxEndpoint {
             })?;
         Ok(slot_hashes.slot_hashes().to_vec())
     }
+
+    pub async fn get_sysvar_epoch_schedule(&mut self) -> Result<EpochSchedule> {
+        self.get_account_data_bincode_deserialized(
+            &ToolboxEndpoint::SYSVAR_EPOCH_SCHEDULE_ID,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not get account: {} (sysvar epoch_schedule)",
+                ToolboxEndpoint::SYSVAR_EPOCH_SCHEDULE_ID,
+            )
+        })
+    }
+
+    pub async fn get_sysvar_epoch_rewards(&mut self) -> Result<EpochRewards> {
+        self.get_account_data_bincode_deserialized(
+            &ToolboxEndpoint::SYSVAR_EPOCH_REWARDS_ID,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not get account: {} (sysvar epoch_rewards)",
+                ToolboxEndpoint::SYSVAR_EPOCH_REWARDS_ID,
+            )
+        })
+    }
+
+    pub async fn get_sysvar_stake_history(
+        &mut self,
+    ) -> Result<Vec<(u64, StakeHistoryEntry)>> {
+        let stake_history: StakeHistory = self
+            .get_account_data_bincode_deserialized(
+                &ToolboxEndpoint::SYSVAR_STAKE_HISTORY_ID,
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Could not get account: {} (sysvar stake_history)",
+                    ToolboxEndpoint::SYSVAR_STAKE_HISTORY_ID,
+                )
+            })?;
+        Ok(stake_history.to_vec())
+    }
+
+    pub async fn get_sysvar_last_restart_slot(&mut self) -> Result<u64> {
+        let last_restart_slot: LastRestartSlot = self
+            .get_account_data_bincode_deserialized(
+                &ToolboxEndpoint::SYSVAR_LAST_RESTART_SLOT_ID,
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Could not get account: {} (sysvar last_restart_slot)",
+                    ToolboxEndpoint::SYSVAR_LAST_RESTART_SLOT_ID,
+                )
+            })?;
+        Ok(last_restart_slot.last_restart_slot)
+    }
+
+    pub async fn get_sysvar_fees_lamports_per_signature(
+        &mut self,
+    ) -> Result<u64> {
+        self.get_account_data_bincode_deserialized(
+            &ToolboxEndpoint::SYSVAR_FEES_ID,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not get account: {} (sysvar fees)",
+                ToolboxEndpoint::SYSVAR_FEES_ID,
+            )
+        })
+    }
+
+    pub async fn get_sysvar_recent_blockhashes(
+        &mut self,
+    ) -> Result<Vec<(Hash, u64)>> {
+        self.get_account_data_bincode_deserialized(
+            &ToolboxEndpoint::SYSVAR_RECENT_BLOCKHASHES_ID,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not get account: {} (sysvar recent_blockhashes)",
+                ToolboxEndpoint::SYSVAR_RECENT_BLOCKHASHES_ID,
+            )
+        })
+    }
+
+    // The instructions sysvar only exists while a transaction executes, it
+    // has no account to fetch, so only its raw data can be parsed
+    pub fn parse_sysvar_instructions(
+        data: &[u8],
+    ) -> Result<(u16, Vec<Instruction>)> {
+        let read_u16 = |offset: usize| -> Result<u16> {
+            Ok(u16::from_le_bytes(
+                data.get(offset..offset + 2)
+                    .ok_or_else(|| {
+                        anyhow!("Sysvar instructions too small: {}", offset)
+                    })?
+                    .try_into()?,
+            ))
+        };
+        let read_pubkey = |offset: usize| -> Result<Pubkey> {
+            Ok(Pubkey::try_from(
+                data.get(offset..offset + 32).ok_or_else(|| {
+                    anyhow!("Sysvar instructions too small: {}", offset)
+                })?,
+            )?)
+        };
+        let instructions_count = read_u16(0)?;
+        let mut instructions = vec![];
+        for instruction_index in 0..usize::from(instructions_count) {
+            let mut offset = usize::from(read_u16(2 + instruction_index * 2)?);
+            let accounts_count = read_u16(offset)?;
+            offset += 2;
+            let mut accounts = vec![];
+            for _ in 0..accounts_count {
+                let flags = *data.get(offset).ok_or_else(|| {
+                    anyhow!("Sysvar instructions too small: {}", offset)
+                })?;
+                let pubkey = read_pubkey(offset + 1)?;
+                offset += 33;
+                accounts.push(AccountMeta {
+                    pubkey,
+                    is_signer: flags & 1 != 0,
+                    is_writable: flags & 2 != 0,
+                });
+            }
+            let program_id = read_pubkey(offset)?;
+            offset += 32;
+            let data_len = usize::from(read_u16(offset)?);
+            offset += 2;
+            let instruction_data =
+                data.get(offset..offset + data_len).ok_or_else(|| {
+                    anyhow!("Sysvar instructions too small: {}", offset)
+                })?;
+            instructions.push(Instruction {
+                program_id,
+                accounts,
+                data: instruction_data.to_vec(),
+            });
+        }
+        let current_index = read_u16(data.len().saturating_sub(2))?;
+        Ok((current_index, instructions))
+    }
+
+    // The fees and recent_blockhashes sysvars have no setter: the bank
+    // recomputes them on every new blockhash, overwriting any change
+    pub async fn set_sysvar_clock(&mut self, clock: &Clock) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_CLOCK_ID,
+            &bincode::serialize(clock)?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_rent(&mut self, rent: &Rent) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_RENT_ID,
+            &bincode::serialize(rent)?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_slot_hashes(
+        &mut self,
+        slot_hashes: &[(u64, Hash)],
+    ) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_SLOT_HASHES,
+            &bincode::serialize(&SlotHashes::new(slot_hashes))?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_epoch_schedule(
+        &mut self,
+        epoch_schedule: &EpochSchedule,
+    ) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_EPOCH_SCHEDULE_ID,
+            &bincode::serialize(epoch_schedule)?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_epoch_rewards(
+        &mut self,
+        epoch_rewards: &EpochRewards,
+    ) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_EPOCH_REWARDS_ID,
+            &bincode::serialize(epoch_rewards)?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_stake_history(
+        &mut self,
+        stake_history: &[(u64, StakeHistoryEntry)],
+    ) -> Result<()> {
+        let mut stake_history_sorted = StakeHistory::default();
+        for (epoch, entry) in stake_history {
+            stake_history_sorted.add(*epoch, entry.clone());
+        }
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_STAKE_HISTORY_ID,
+            &bincode::serialize(&stake_history_sorted)?,
+        )
+        .await
+    }
+
+    pub async fn set_sysvar_last_restart_slot(
+        &mut self,
+        last_restart_slot: u64,
+    ) -> Result<()> {
+        self.set_sysvar_account(
+            &ToolboxEndpoint::SYSVAR_LAST_RESTART_SLOT_ID,
+            &bincode::serialize(&LastRestartSlot { last_restart_slot })?,
+        )
+        .await
+    }
 }
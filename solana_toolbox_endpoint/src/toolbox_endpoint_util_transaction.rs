@@ -5,7 +5,6 @@ use anyhow::Result;
 use solana_sdk::hash::Hash;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
 
@@ -13,9 +12,9 @@ use crate::toolbox_endpoint::ToolboxEndpoint;
 
 impl ToolboxEndpoint {
     pub fn compile_transaction(
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
         recent_blockhash: Hash,
     ) -> Result<Transaction> {
         let mut transaction =
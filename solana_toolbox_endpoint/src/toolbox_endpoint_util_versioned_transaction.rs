@@ -5,22 +5,24 @@ use anyhow::anyhow;
 use anyhow::Result;
 use solana_sdk::address_lookup_table::AddressLookupTableAccount;
 use solana_sdk::hash::Hash;
+use solana_sdk::instruction::CompiledInstruction;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::message::v0::Message;
 use solana_sdk::message::CompileError;
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signature;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::VersionedTransaction;
 
 use crate::toolbox_endpoint::ToolboxEndpoint;
+use crate::toolbox_endpoint_execution::ToolboxEndpointExecutionInnerInstruction;
 
 impl ToolboxEndpoint {
     pub fn compile_versioned_transaction(
-        payer: &Keypair,
+        payer: &dyn Signer,
         instructions: &[Instruction],
-        signers: &[&Keypair],
+        signers: &[&dyn Signer],
         resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
         recent_blockhash: Hash,
     ) -> Result<VersionedTransaction> {
@@ -58,6 +60,144 @@ impl ToolboxEndpoint {
         Ok(versioned_transaction)
     }
 
+    pub fn compile_versioned_transaction_unsigned(
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction> {
+        let message = ToolboxEndpoint::compile_versioned_transaction_message(
+            payer,
+            instructions,
+            resolved_address_lookup_tables,
+            recent_blockhash,
+        )?;
+        Ok(VersionedTransaction {
+            signatures: vec![
+                Signature::default();
+                usize::from(
+                    message.header().num_required_signatures
+                )
+            ],
+            message,
+        })
+    }
+
+    pub fn sign_versioned_transaction_partial(
+        versioned_transaction: &mut VersionedTransaction,
+        signers: &[&dyn Signer],
+    ) -> Result<()> {
+        let message_data = versioned_transaction.message.serialize();
+        let signers_pubkeys =
+            ToolboxEndpoint::get_versioned_transaction_signers(
+                versioned_transaction,
+            )?;
+        for signer in signers {
+            let signer_pubkey = signer.pubkey();
+            let signer_index = signers_pubkeys
+                .iter()
+                .position(|signer_pubkey_needed| {
+                    *signer_pubkey_needed == signer_pubkey
+                })
+                .ok_or_else(|| {
+                    anyhow!(
+                        "VersionedTransaction does not require a signature from: {}",
+                        signer_pubkey
+                    )
+                })?;
+            versioned_transaction.signatures[signer_index] =
+                signer.try_sign_message(&message_data)?;
+        }
+        Ok(())
+    }
+
+    pub fn merge_versioned_transaction_signatures(
+        versioned_transactions: &[VersionedTransaction],
+    ) -> Result<VersionedTransaction> {
+        let mut merged_versioned_transaction = versioned_transactions
+            .first()
+            .ok_or_else(|| anyhow!("No VersionedTransaction to merge"))?
+            .clone();
+        let merged_message_data =
+            merged_versioned_transaction.message.serialize();
+        for versioned_transaction in &versioned_transactions[1..] {
+            if versioned_transaction.message.serialize() != merged_message_data
+            {
+                return Err(anyhow!(
+                    "Cannot merge signatures of different messages"
+                ));
+            }
+            if versioned_transaction.signatures.len()
+                != merged_versioned_transaction.signatures.len()
+            {
+                return Err(anyhow!(
+                    "Cannot merge signatures of different lengths"
+                ));
+            }
+            for (merged_signature, signature) in merged_versioned_transaction
+                .signatures
+                .iter_mut()
+                .zip(&versioned_transaction.signatures)
+            {
+                if *signature == Signature::default() {
+                    continue;
+                }
+                if *merged_signature != Signature::default()
+                    && merged_signature != signature
+                {
+                    return Err(anyhow!(
+                        "Cannot merge conflicting signatures: {} and {}",
+                        merged_signature,
+                        signature
+                    ));
+                }
+                *merged_signature = *signature;
+            }
+        }
+        Ok(merged_versioned_transaction)
+    }
+
+    pub fn get_versioned_transaction_signers(
+        versioned_transaction: &VersionedTransaction,
+    ) -> Result<Vec<Pubkey>> {
+        let signers_count = usize::from(
+            versioned_transaction
+                .message
+                .header()
+                .num_required_signatures,
+        );
+        let static_addresses =
+            versioned_transaction.message.static_account_keys();
+        if static_addresses.len() < signers_count {
+            return Err(anyhow!(
+                "VersionedTransaction is malformed: missing signers addresses"
+            ));
+        }
+        Ok(static_addresses[..signers_count].to_vec())
+    }
+
+    pub fn get_versioned_transaction_signers_missing(
+        versioned_transaction: &VersionedTransaction,
+    ) -> Result<Vec<Pubkey>> {
+        let signers_pubkeys =
+            ToolboxEndpoint::get_versioned_transaction_signers(
+                versioned_transaction,
+            )?;
+        let mut signers_pubkeys_missing = vec![];
+        for (signer_index, signer_pubkey) in signers_pubkeys.iter().enumerate()
+        {
+            if versioned_transaction
+                .signatures
+                .get(signer_index)
+                .map(|signature| *signature == Signature::default())
+                .unwrap_or(true)
+            {
+                signers_pubkeys_missing.push(*signer_pubkey);
+            }
+        }
+        Ok(signers_pubkeys_missing)
+    }
+
     pub fn compile_versioned_transaction_message(
         payer: &Pubkey,
         instructions: &[Instruction],
@@ -83,6 +223,22 @@ impl ToolboxEndpoint {
         versioned_transaction: &VersionedTransaction,
         resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
     ) -> Result<(Pubkey, Vec<Instruction>)> {
+        let (loaded_writable_addresses, loaded_readonly_addresses) =
+            ToolboxEndpoint::decompile_versioned_transaction_loaded_addresses(
+                versioned_transaction,
+                resolved_address_lookup_tables,
+            )?;
+        ToolboxEndpoint::decompile_versioned_transaction_with_loaded_addresses(
+            versioned_transaction,
+            &loaded_writable_addresses,
+            &loaded_readonly_addresses,
+        )
+    }
+
+    pub fn decompile_versioned_transaction_loaded_addresses(
+        versioned_transaction: &VersionedTransaction,
+        resolved_address_lookup_tables: &[(Pubkey, Vec<Pubkey>)],
+    ) -> Result<(Vec<Pubkey>, Vec<Pubkey>)> {
         let mut resolved_address_lookup_tables_addresses = HashMap::new();
         for resolved_address_lookup_table in resolved_address_lookup_tables {
             resolved_address_lookup_tables_addresses.insert(
@@ -128,11 +284,7 @@ impl ToolboxEndpoint {
                 }
             }
         }
-        ToolboxEndpoint::decompile_versioned_transaction_with_loaded_addresses(
-            versioned_transaction,
-            &loaded_writable_addresses,
-            &loaded_readonly_addresses,
-        )
+        Ok((loaded_writable_addresses, loaded_readonly_addresses))
     }
 
     pub fn decompile_versioned_transaction_with_loaded_addresses(
@@ -157,6 +309,27 @@ impl ToolboxEndpoint {
         Ok((payer, instructions))
     }
 
+    pub fn decompile_versioned_transaction_inner_instructions(
+        versioned_transaction: &VersionedTransaction,
+        loaded_writable_addresses: &[Pubkey],
+        loaded_readonly_addresses: &[Pubkey],
+        compiled_inner_instructions: &[Vec<(
+            CompiledInstruction,
+            Option<u32>,
+        )>],
+    ) -> Result<Vec<Vec<ToolboxEndpointExecutionInnerInstruction>>> {
+        let header = versioned_transaction.message.header();
+        ToolboxEndpoint::decompile_transaction_inner_instructions(
+            header.num_required_signatures,
+            header.num_readonly_signed_accounts,
+            header.num_readonly_unsigned_accounts,
+            versioned_transaction.message.static_account_keys(),
+            loaded_writable_addresses,
+            loaded_readonly_addresses,
+            compiled_inner_instructions,
+        )
+    }
+
     pub fn verify_versioned_transaction_length(
         versioned_transaction: &VersionedTransaction,
     ) -> Result<()> {
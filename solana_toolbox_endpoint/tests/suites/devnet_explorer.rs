@@ -0,0 +1,24 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointCluster;
+use solana_toolbox_endpoint::ToolboxEndpointExplorer;
+
+#[tokio::test]
+pub async fn run() {
+    // Create the endpoint pointing to devnet
+    let mut endpoint = ToolboxEndpoint::new_devnet().await;
+    // The cluster should be recognized from its genesis hash
+    let cluster = endpoint.get_cluster_from_genesis_hash().await.unwrap();
+    assert_eq!(ToolboxEndpointCluster::Devnet, cluster);
+    // Even behind a private RPC url, links should point to devnet
+    let address = Pubkey::default();
+    assert_eq!(
+        format!("https://solscan.io/account/{}?cluster=devnet", address),
+        ToolboxEndpoint::compute_explorer_address_url_with_explorer(
+            &ToolboxEndpointExplorer::Solscan,
+            cluster,
+            "https://my-private-rpc.com",
+            &address,
+        )
+    );
+}
@@ -91,6 +91,7 @@ pub async fn run() {
                     }
                 )
             ]),
+            inner_instructions: Some(vec![vec![]]),
             logs: Some(vec![
                 "Program CW5VzSk7WC4NPyuNt19VFev9FUHhyk5xxHTj2DUWBexu invoke [1]".to_string(),
                 "Program log: Instruction: EditCredixLpDepository".to_string(),
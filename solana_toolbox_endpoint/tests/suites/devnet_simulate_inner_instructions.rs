@@ -0,0 +1,41 @@
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+#[tokio::test]
+pub async fn run() {
+    // Create the endpoint
+    let mut endpoint = ToolboxEndpoint::new_devnet().await;
+    // Make a payer
+    let payer = ToolboxEndpoint::keypair_from_seed_string_hash(
+        "Dummy devnet payer used to test simulations outcomes",
+    )
+    .unwrap();
+    // Simulate an associated token account creation (which CPIs into other programs)
+    let instruction = create_associated_token_account(
+        &payer.pubkey(),
+        &Pubkey::new_unique(),
+        &pubkey!("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"),
+        &ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID,
+    );
+    let simulation = endpoint
+        .simulate_instruction(&payer, instruction)
+        .await
+        .unwrap();
+    assert_eq!(simulation.error, None);
+    // Check that the parsed inner instructions were mapped back to programs
+    let inner_instructions = simulation.inner_instructions.unwrap();
+    assert_eq!(inner_instructions.len(), 1);
+    assert!(!inner_instructions[0].is_empty());
+    for inner_instruction in &inner_instructions[0] {
+        assert_eq!(inner_instruction.stack_height, Some(2));
+    }
+    let inner_programs_ids = inner_instructions[0]
+        .iter()
+        .map(|inner_instruction| inner_instruction.instruction.program_id)
+        .collect::<Vec<_>>();
+    assert!(inner_programs_ids.contains(&ToolboxEndpoint::SYSTEM_PROGRAM_ID));
+    assert!(inner_programs_ids.contains(&ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID));
+}
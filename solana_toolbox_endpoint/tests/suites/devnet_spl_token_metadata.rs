@@ -5,6 +5,9 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 use solana_toolbox_endpoint::ToolboxEndpointLoggerPrinter;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMasterEdition;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataCollection;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataCollectionDetails;
 
 #[tokio::test]
 pub async fn run() {
@@ -104,4 +107,120 @@ pub async fn run() {
             dummy_uri.to_string()
         )
     );
+    // Make a collection mint with a single token
+    let collection_mint = endpoint
+        .process_spl_token_mint_new(&payer, &mint_authority.pubkey(), None, 0)
+        .await
+        .unwrap();
+    let collection_token = endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &payer.pubkey(),
+            &collection_mint,
+        )
+        .await
+        .unwrap();
+    endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &collection_mint,
+            &mint_authority,
+            &collection_token,
+            1,
+        )
+        .await
+        .unwrap();
+    // Create the collection metadata and master edition
+    endpoint
+        .process_spl_token_metaplex_metadata_create(
+            &payer,
+            &collection_mint,
+            &mint_authority,
+            (
+                metadata_authority.pubkey(),
+                "COLL".to_string(),
+                "COLLECTION".to_string(),
+                dummy_uri.to_string(),
+            ),
+        )
+        .await
+        .unwrap();
+    endpoint
+        .process_spl_token_metaplex_master_edition_create(
+            &payer,
+            &collection_mint,
+            &mint_authority,
+            &metadata_authority,
+            Some(0),
+        )
+        .await
+        .unwrap();
+    // Check that the master edition has been created
+    assert_eq!(
+        endpoint
+            .get_spl_token_metaplex_master_edition(&collection_mint)
+            .await
+            .unwrap()
+            .unwrap(),
+        ToolboxEndpointSplTokenMetaplexMasterEdition {
+            supply: 0,
+            max_supply: Some(0),
+        }
+    );
+    // Make the collection sized
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_size(
+            &payer,
+            &collection_mint,
+            &metadata_authority,
+            0,
+        )
+        .await
+        .unwrap();
+    // Attach the first mint to the collection
+    let metadata = endpoint
+        .get_spl_token_metaplex_metadata_account(&mint)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.collection, None);
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_and_verify(
+            &payer,
+            &mint,
+            &collection_mint,
+            &metadata_authority,
+        )
+        .await
+        .unwrap();
+    // Check that the mint is now a verified collection item
+    assert_eq!(
+        endpoint
+            .get_spl_token_metaplex_metadata_account(&mint)
+            .await
+            .unwrap()
+            .unwrap()
+            .collection,
+        Some(ToolboxEndpointSplTokenMetaplexMetadataCollection {
+            verified: true,
+            key: collection_mint,
+        })
+    );
+    // Check the full decoded content of the collection metadata
+    let collection_metadata = endpoint
+        .get_spl_token_metaplex_metadata_account(&collection_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(collection_metadata.mint, collection_mint);
+    assert_eq!(collection_metadata.name, "COLLECTION");
+    assert_eq!(collection_metadata.symbol, "COLL");
+    assert_eq!(
+        collection_metadata.collection_details,
+        Some(
+            ToolboxEndpointSplTokenMetaplexMetadataCollectionDetails::V1 {
+                size: 1
+            }
+        )
+    );
 }
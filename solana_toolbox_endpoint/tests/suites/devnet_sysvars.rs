@@ -29,4 +29,12 @@ pub async fn run() {
         .map(|slot_hash| slot_hash.0)
         .collect::<Vec<_>>()
         .contains(&clock.slot));
+    // Fetch and check the other sysvars
+    let epoch_schedule = endpoint.get_sysvar_epoch_schedule().await.unwrap();
+    assert_eq!(epoch_schedule.slots_per_epoch, 432000);
+    assert_eq!(epoch_schedule.get_epoch(clock.slot), clock.epoch,);
+    let stake_history = endpoint.get_sysvar_stake_history().await.unwrap();
+    assert!(!stake_history.is_empty());
+    endpoint.get_sysvar_epoch_rewards().await.unwrap();
+    endpoint.get_sysvar_last_restart_slot().await.unwrap();
 }
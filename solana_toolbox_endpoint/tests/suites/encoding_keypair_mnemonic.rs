@@ -0,0 +1,100 @@
+use solana_sdk::pubkey;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+
+#[tokio::test]
+pub async fn run() {
+    // Standard BIP39 test mnemonic
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    // Equivalent to the solana CLI's "ASK" (legacy, no derivation)
+    assert_eq!(
+        pubkey!("EHqmfkN89RJ7Y33CXM6uCzhVeuywHoJXZZLszBHHZy7o"),
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic_legacy(
+            mnemonic, ""
+        )
+        .unwrap()
+        .pubkey()
+    );
+    assert_eq!(
+        pubkey!("6UChi37U4BGomEQR665JZNjTM2PiHcUmBCD3e7TD366v"),
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic_legacy(
+            mnemonic, "TREZOR"
+        )
+        .unwrap()
+        .pubkey()
+    );
+    // Equivalent to the solana CLI's "prompt:" (default derivation m/44'/501')
+    assert_eq!(
+        pubkey!("D2PPQSYFe83nDzk96FqGumVU8JA7J8vj2Rhjc2oXzEi5"),
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+            mnemonic, "", None
+        )
+        .unwrap()
+        .pubkey()
+    );
+    // Equivalent to the solana CLI's "prompt://?key=0/0" (and wallets' default)
+    let expected = pubkey!("HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+    for derivation_path in ["0/0", "m/44'/501'/0'/0'", "m/44/501/0/0"] {
+        assert_eq!(
+            expected,
+            ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+                mnemonic,
+                "",
+                Some(derivation_path)
+            )
+            .unwrap()
+            .pubkey()
+        );
+    }
+    // Whitespace and case should not matter
+    assert_eq!(
+        expected,
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+            &format!("  {}\n", mnemonic.to_uppercase().replace(' ', "   ")),
+            "",
+            Some("0/0")
+        )
+        .unwrap()
+        .pubkey()
+    );
+    // Passphrase and account index should be applied
+    assert_eq!(
+        pubkey!("8CW93AFYFKj2rKZRq3YjLesb4XbgtAiq8k2FgfruVZL5"),
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+            mnemonic,
+            "TREZOR",
+            Some("m/44'/501'/1'/0'")
+        )
+        .unwrap()
+        .pubkey()
+    );
+    // Invalid derivation paths should fail
+    assert!(ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+        mnemonic,
+        "",
+        Some("0/0/0")
+    )
+    .is_err());
+    // Mnemonics must use the BIP39 english wordlist and have a valid checksum
+    let mnemonic_bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+    assert!(ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+        mnemonic_bad_checksum,
+        "",
+        None
+    )
+    .is_err());
+    assert!(
+        ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic_legacy(
+            mnemonic_bad_checksum,
+            ""
+        )
+        .is_err()
+    );
+    let mnemonic_bad_word = mnemonic.replace("about", "abouts");
+    assert!(ToolboxEndpoint::sanitize_and_decode_keypair_mnemonic(
+        &mnemonic_bad_word,
+        "",
+        None
+    )
+    .is_err());
+}
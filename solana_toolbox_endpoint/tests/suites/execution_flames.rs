@@ -0,0 +1,70 @@
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionFlame;
+
+#[tokio::test]
+pub async fn run() {
+    // Dummy programs that we'll pretend to invoke
+    let program_a = Pubkey::new_unique();
+    let program_b = Pubkey::new_unique();
+    let program_c = Pubkey::new_unique();
+    // Parse a nested execution's logs
+    let logs = vec![
+        format!("Program {} invoke [1]", program_a),
+        "Program log: Hello".to_string(),
+        format!("Program {} invoke [2]", program_b),
+        format!("Program {} invoke [3]", program_c),
+        format!("Program {} consumed 300 of 100000 compute units", program_c),
+        format!("Program {} success", program_c),
+        format!(
+            "Program {} consumed 1000 of 100500 compute units",
+            program_b
+        ),
+        format!("Program {} success", program_b),
+        format!("Program {} invoke [2]", program_c),
+        format!("Program {} consumed 200 of 99000 compute units", program_c),
+        format!("Program {} success", program_c),
+        format!(
+            "Program {} consumed 5000 of 200000 compute units",
+            program_a
+        ),
+        format!("Program {} success", program_a),
+        format!("Program {} invoke [1]", program_c),
+        format!("Program {} consumed 400 of 195000 compute units", program_c),
+        format!("Program {} success", program_c),
+    ];
+    let steps = ToolboxEndpointExecution::try_parse_steps(&logs).unwrap();
+    let flames = ToolboxEndpointExecutionFlame::compute_from_steps(&steps);
+    // Check the computed self and total units
+    assert_eq!(flames.len(), 2);
+    assert_eq!(flames[0].program_id, program_a);
+    assert_eq!(flames[0].units_total, 5000);
+    assert_eq!(flames[0].units_self, 3800);
+    assert_eq!(flames[0].children[0].units_total, 1000);
+    assert_eq!(flames[0].children[0].units_self, 700);
+    assert_eq!(flames[0].children[0].children[0].units_self, 300);
+    assert_eq!(flames[0].children[1].units_self, 200);
+    assert_eq!(flames[1].units_self, 400);
+    // Check the folded stacks export, identical stacks are merged
+    let mut flames_labeled = flames.clone();
+    flames_labeled[0].label = "a.my_ix".to_string();
+    flames_labeled[0].children[0].label = "b".to_string();
+    flames_labeled[0].children[0].children[0].label = "c".to_string();
+    flames_labeled[0].children[1].label = "c".to_string();
+    flames_labeled[1].label = "a.my_ix".to_string();
+    assert_eq!(
+        ToolboxEndpointExecutionFlame::export_folded(&flames_labeled),
+        "a.my_ix 4200\na.my_ix;b 700\na.my_ix;b;c 300\na.my_ix;c 200\n"
+    );
+    // Check the JSON tree export
+    assert_eq!(
+        ToolboxEndpointExecutionFlame::export_json(&flames[1..]),
+        json!([{
+            "label": program_c.to_string(),
+            "program_id": program_c.to_string(),
+            "units": { "total": 400, "self": 400 },
+            "children": [],
+        }])
+    );
+}
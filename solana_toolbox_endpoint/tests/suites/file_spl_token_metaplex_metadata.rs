@@ -0,0 +1,187 @@
+use std::fs::read_to_string;
+use std::str::FromStr;
+
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexEdition;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMasterEdition;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadata;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataCollection;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataCreator;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataProgrammableConfig;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataTokenStandard;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataUseMethod;
+use solana_toolbox_endpoint::ToolboxEndpointSplTokenMetaplexMetadataUses;
+
+#[tokio::test]
+pub async fn run() {
+    // Known addresses used in the fixtures
+    let mint = Pubkey::from_str("7mFX7J8zFwmf7yLKuzJ2cZGLNv3Mkt4TWtvjvH1dQYn5")
+        .unwrap();
+    let collection_mint =
+        Pubkey::from_str("4Zs2Gd8Z6sTw7HXsH1KYEK2cDMx2JbLEqTbmjJX2GYXS")
+            .unwrap();
+    let edition_mint =
+        Pubkey::from_str("Gx9Cq2wpN6Z7TpF5Nbm5bGg6nzkRwzLsEPo9Qn5yZsTj")
+            .unwrap();
+    // Decode a full programmable NFT metadata account
+    let (metadata_address, metadata_data) =
+        read_account_fixture("spl_token_metaplex_metadata_account");
+    assert_eq!(
+        metadata_address,
+        ToolboxEndpoint::find_spl_token_metaplex_metadata(&mint)
+    );
+    assert_eq!(
+        ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(
+            &metadata_data
+        )
+        .unwrap(),
+        ToolboxEndpointSplTokenMetaplexMetadata {
+            update_authority: Pubkey::from_str(
+                "9Tq4kxjYyVAd6TNSWT1Ujwpt6bhgh4Dmh8NTPPqgDddS"
+            )
+            .unwrap(),
+            mint,
+            name: "Toolbox Dummy #42".to_string(),
+            symbol: "TBX".to_string(),
+            uri: "https://example.com/dummy/42.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![
+                ToolboxEndpointSplTokenMetaplexMetadataCreator {
+                    address: Pubkey::from_str(
+                        "HYSaCk2K6vB1B1m6cYTzDgkhZbqBFFtEJBVd1hzD8PbL"
+                    )
+                    .unwrap(),
+                    verified: true,
+                    share: 30,
+                },
+                ToolboxEndpointSplTokenMetaplexMetadataCreator {
+                    address: Pubkey::from_str(
+                        "8Vw9pHSmGnmFSHqGN45vEVoRHGRLxJbq4VsynBSDh2Dp"
+                    )
+                    .unwrap(),
+                    verified: false,
+                    share: 70,
+                },
+            ]),
+            primary_sale_happened: true,
+            is_mutable: true,
+            edition_nonce: Some(254),
+            token_standard: Some(
+                ToolboxEndpointSplTokenMetaplexMetadataTokenStandard::ProgrammableNonFungible
+            ),
+            collection: Some(
+                ToolboxEndpointSplTokenMetaplexMetadataCollection {
+                    verified: true,
+                    key: collection_mint,
+                }
+            ),
+            uses: Some(ToolboxEndpointSplTokenMetaplexMetadataUses {
+                use_method:
+                    ToolboxEndpointSplTokenMetaplexMetadataUseMethod::Multiple,
+                remaining: 3,
+                total: 10,
+            }),
+            collection_details: None,
+            programmable_config: Some(
+                ToolboxEndpointSplTokenMetaplexMetadataProgrammableConfig::V1 {
+                    rule_set: Some(
+                        Pubkey::from_str(
+                            "eBJLFYPxJmMGKuFwpDWkzxZeUrad92kZRC5BJLpzyT9"
+                        )
+                        .unwrap()
+                    ),
+                }
+            ),
+        }
+    );
+    // Decode a legacy metadata account missing the optional trailing fields
+    let (metadata_legacy_address, metadata_legacy_data) =
+        read_account_fixture("spl_token_metaplex_metadata_legacy_account");
+    assert_eq!(
+        metadata_legacy_address,
+        ToolboxEndpoint::find_spl_token_metaplex_metadata(&collection_mint)
+    );
+    let metadata_legacy =
+        ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(
+            &metadata_legacy_data,
+        )
+        .unwrap();
+    assert_eq!(metadata_legacy.mint, collection_mint);
+    assert_eq!(metadata_legacy.name, "Legacy");
+    assert_eq!(metadata_legacy.creators, None);
+    assert!(!metadata_legacy.primary_sale_happened);
+    assert!(metadata_legacy.is_mutable);
+    assert_eq!(metadata_legacy.edition_nonce, None);
+    assert_eq!(metadata_legacy.token_standard, None);
+    assert_eq!(metadata_legacy.collection, None);
+    assert_eq!(metadata_legacy.uses, None);
+    assert_eq!(metadata_legacy.collection_details, None);
+    assert_eq!(metadata_legacy.programmable_config, None);
+    // Corrupted or truncated optional trailing fields must be rejected
+    let mut metadata_invalid_tag_data = metadata_legacy_data.clone();
+    metadata_invalid_tag_data.push(2);
+    ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(
+        &metadata_invalid_tag_data,
+    )
+    .unwrap_err();
+    let mut metadata_truncated_data = metadata_legacy_data.clone();
+    metadata_truncated_data.extend_from_slice(&[0, 0, 1, 1]);
+    ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(
+        &metadata_truncated_data,
+    )
+    .unwrap_err();
+    // Decode a master edition account
+    let (master_edition_address, master_edition_data) =
+        read_account_fixture("spl_token_metaplex_master_edition_account");
+    assert_eq!(
+        master_edition_address,
+        ToolboxEndpoint::find_spl_token_metaplex_edition(&collection_mint)
+    );
+    assert_eq!(
+        ToolboxEndpoint::parse_spl_token_metaplex_master_edition(
+            &master_edition_data
+        )
+        .unwrap(),
+        ToolboxEndpointSplTokenMetaplexMasterEdition {
+            supply: 0,
+            max_supply: Some(0),
+        }
+    );
+    // Decode a printed edition account
+    let (edition_address, edition_data) =
+        read_account_fixture("spl_token_metaplex_edition_account");
+    assert_eq!(
+        edition_address,
+        ToolboxEndpoint::find_spl_token_metaplex_edition(&edition_mint)
+    );
+    assert_eq!(
+        ToolboxEndpoint::parse_spl_token_metaplex_edition(&edition_data)
+            .unwrap(),
+        ToolboxEndpointSplTokenMetaplexEdition {
+            parent: ToolboxEndpoint::find_spl_token_metaplex_edition(&mint),
+            edition: 7,
+        }
+    );
+    // Mismatched account kinds must be rejected
+    ToolboxEndpoint::parse_spl_token_metaplex_metadata_account(&edition_data)
+        .unwrap_err();
+    ToolboxEndpoint::parse_spl_token_metaplex_master_edition(&metadata_data)
+        .unwrap_err();
+    ToolboxEndpoint::parse_spl_token_metaplex_edition(&master_edition_data)
+        .unwrap_err();
+}
+
+fn read_account_fixture(name: &str) -> (Pubkey, Vec<u8>) {
+    let content =
+        read_to_string(format!("./tests/fixtures/{}.json", name)).unwrap();
+    let value = serde_json::from_str::<Value>(&content).unwrap();
+    (
+        Pubkey::from_str(value["pubkey"].as_str().unwrap()).unwrap(),
+        ToolboxEndpoint::sanitize_and_decode_base64(
+            value["account"]["data"][0].as_str().unwrap(),
+        )
+        .unwrap(),
+    )
+}
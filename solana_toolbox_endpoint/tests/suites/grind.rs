@@ -0,0 +1,106 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointGrindOptions;
+use solana_toolbox_endpoint::ToolboxEndpointGrindPattern;
+
+#[tokio::test]
+pub async fn run() {
+    // Grind a keypair with a short case-insensitive prefix
+    let pattern = ToolboxEndpointGrindPattern {
+        prefix: Some("ab".to_string()),
+        suffix: None,
+        ignore_case: true,
+    };
+    let keypair = ToolboxEndpoint::grind_keypair(
+        &pattern,
+        &ToolboxEndpointGrindOptions::default(),
+    )
+    .unwrap()
+    .unwrap();
+    assert!(keypair
+        .pubkey()
+        .to_string()
+        .to_lowercase()
+        .starts_with("ab"));
+    // Grind a PDA with a specific bump and suffix
+    let program_id = Pubkey::new_unique();
+    let pattern = ToolboxEndpointGrindPattern {
+        prefix: None,
+        suffix: Some("z".to_string()),
+        ignore_case: false,
+    };
+    let (seed_counter, address, bump) = ToolboxEndpoint::grind_pda(
+        &program_id,
+        &[b"prefix"],
+        &[b"suffix"],
+        &pattern,
+        Some(255),
+        &ToolboxEndpointGrindOptions {
+            threads: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(255, bump);
+    assert!(address.to_string().ends_with('z'));
+    assert_eq!(
+        (address, bump),
+        Pubkey::find_program_address(
+            &[b"prefix", &seed_counter.to_le_bytes(), b"suffix"],
+            &program_id
+        )
+    );
+    // Cancelled grinds should stop without a result
+    let cancel = Arc::new(AtomicBool::new(true));
+    let pattern_unlikely = ToolboxEndpointGrindPattern {
+        prefix: Some("zzzzzzzzzz".to_string()),
+        suffix: None,
+        ignore_case: false,
+    };
+    assert!(ToolboxEndpoint::grind_keypair(
+        &pattern_unlikely,
+        &ToolboxEndpointGrindOptions {
+            cancel,
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .is_none());
+    // Invalid base58 patterns should be rejected
+    let pattern = ToolboxEndpointGrindPattern {
+        prefix: Some("0O".to_string()),
+        suffix: None,
+        ignore_case: false,
+    };
+    assert!(ToolboxEndpoint::grind_keypair(
+        &pattern,
+        &ToolboxEndpointGrindOptions::default()
+    )
+    .is_err());
+    // Progress should be reported and can be used to cancel the grind
+    let cancel = Arc::new(AtomicBool::new(false));
+    let cancel_clone = cancel.clone();
+    let progress_attempts = Arc::new(AtomicU64::new(0));
+    let progress_attempts_clone = progress_attempts.clone();
+    assert!(ToolboxEndpoint::grind_keypair(
+        &pattern_unlikely,
+        &ToolboxEndpointGrindOptions {
+            threads: Some(1),
+            cancel,
+            progress: Some(Arc::new(move |attempts| {
+                progress_attempts_clone.store(attempts, Ordering::Relaxed);
+                cancel_clone.store(true, Ordering::Relaxed);
+            })),
+        },
+    )
+    .unwrap()
+    .is_none());
+    assert!(progress_attempts.load(Ordering::Relaxed) > 0);
+}
@@ -1,4 +1,5 @@
 pub mod devnet_address_lookup_table;
+pub mod devnet_explorer;
 pub mod devnet_get_execution;
 pub mod devnet_get_slot_unix_timestamp;
 pub mod devnet_preflight;
@@ -7,21 +8,43 @@ pub mod devnet_search_addresses;
 pub mod devnet_search_signatures;
 pub mod devnet_signature_verify;
 pub mod devnet_simulate;
+pub mod devnet_simulate_inner_instructions;
 pub mod devnet_spl_token_metadata;
 pub mod devnet_sysvars;
+pub mod encoding_keypair_mnemonic;
+pub mod execution_flames;
+pub mod file_spl_token_metaplex_metadata;
+pub mod grind;
 pub mod mainnet_spl_token_metadata;
+pub mod program_test_accounts_dump;
 pub mod program_test_address_lookup_table;
 pub mod program_test_builtin_programs;
+pub mod program_test_cloned_programs;
 pub mod program_test_compute_budget;
+pub mod program_test_compute_max_units;
+pub mod program_test_duplicate_transactions;
+pub mod program_test_explorer;
+pub mod program_test_fee;
 pub mod program_test_forward_clock;
 pub mod program_test_get_execution;
 pub mod program_test_get_slot_unix_timestamp;
+pub mod program_test_inner_instructions;
 pub mod program_test_loggers;
+pub mod program_test_offline_signing;
+pub mod program_test_options;
 pub mod program_test_preflight;
 pub mod program_test_program;
+pub mod program_test_program_buffer;
+#[cfg(feature = "has_loader_v4")]
+pub mod program_test_program_loader_v4;
+pub mod program_test_program_verify;
+pub mod program_test_remote_signer;
 pub mod program_test_search_addresses;
 pub mod program_test_search_signatures;
+pub mod program_test_seeded;
 pub mod program_test_signature_verify;
 pub mod program_test_simulate;
 pub mod program_test_spl_token;
+pub mod program_test_spl_token_metaplex_metadata;
 pub mod program_test_sysvars;
+pub mod program_test_warp_clock;
@@ -0,0 +1,69 @@
+use std::fs::read_to_string;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+use tempfile::TempDir;
+
+#[tokio::test]
+pub async fn run() {
+    // Encoding and decoding a fixture should be lossless
+    let fixture = read_to_string(
+        "./tests/fixtures/spl_token_metaplex_edition_account.json",
+    )
+    .unwrap();
+    let (fixture_address, fixture_account) =
+        ToolboxEndpoint::sanitize_and_decode_account_json(&fixture).unwrap();
+    assert_eq!(
+        (fixture_address, fixture_account.clone()),
+        ToolboxEndpoint::sanitize_and_decode_account_json(
+            &ToolboxEndpoint::encode_account_json(
+                &fixture_address,
+                &fixture_account
+            )
+        )
+        .unwrap()
+    );
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Create a few accounts
+    let account = endpoint
+        .process_system_new_exempt(&payer, 42, &Pubkey::new_unique())
+        .await
+        .unwrap();
+    let mint = endpoint
+        .process_spl_token_mint_new(&payer, &payer.pubkey(), None, 6)
+        .await
+        .unwrap();
+    let addresses = vec![payer.pubkey(), account, mint];
+    // Dump the accounts to a directory
+    let temp_dir = TempDir::new().unwrap();
+    let directory = temp_dir.path().to_str().unwrap();
+    endpoint.dump_accounts(&addresses, directory).await.unwrap();
+    // Dumping a missing account should fail
+    endpoint
+        .dump_accounts(&[Pubkey::new_unique()], directory)
+        .await
+        .unwrap_err();
+    // Load the dumped accounts into a fresh endpoint
+    let mut endpoint_loaded = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_genesis_accounts_json_directory(directory),
+    )
+    .await;
+    // The accounts should be identical
+    for address in &addresses {
+        assert_eq!(
+            endpoint.get_account(address).await.unwrap(),
+            endpoint_loaded.get_account(address).await.unwrap()
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::fs::read;
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestPreloadedProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize a fixture-backed source endpoint
+    let program_id_preloaded = Keypair::new();
+    let mut source =
+        ToolboxEndpoint::new_program_test_with_preloaded_programs(&[
+            ToolboxEndpointProgramTestPreloadedProgram::new(
+                program_id_preloaded.pubkey(),
+                "bpf_loader_program_minimal",
+            )
+            .with_loader_id(ToolboxEndpoint::LOADER_V4_PROGRAM_ID),
+        ])
+        .await;
+    // Make a payer
+    let payer = Keypair::new();
+    source
+        .request_airdrop(&payer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    // Deploy an upgradeable program on the source
+    let program_id_deployed = Keypair::new();
+    let program_authority = Keypair::new();
+    let program_bytecode =
+        read("./tests/fixtures/bpf_loader_program_minimal.so").unwrap();
+    source
+        .process_program_deploy(
+            &payer,
+            &program_id_deployed,
+            &program_authority,
+            &program_bytecode,
+        )
+        .await
+        .unwrap();
+    // Clone both programs into a new memnet, preserving the authority
+    let program_ids =
+        [program_id_preloaded.pubkey(), program_id_deployed.pubkey()];
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_cloned_programs(
+        &mut source,
+        &program_ids,
+        true,
+    )
+    .await
+    .unwrap();
+    // Check that the programs were cloned as upgradeable programs
+    for program_id in &program_ids {
+        assert_eq!(
+            Some(ToolboxEndpoint::BPF_LOADER_UPGRADEABLE_PROGRAM_ID),
+            endpoint.get_account_owner(program_id).await.unwrap()
+        );
+        assert_eq!(
+            program_bytecode,
+            endpoint
+                .get_program_bytecode(program_id)
+                .await
+                .unwrap()
+                .unwrap()
+        );
+    }
+    assert_eq!(
+        (0, Some(program_authority.pubkey())),
+        endpoint
+            .get_program_meta(&program_id_deployed.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+    );
+    // The cloned programs should be executable
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    for program_id in &program_ids {
+        endpoint
+            .process_instruction(
+                &payer,
+                Instruction {
+                    program_id: *program_id,
+                    accounts: vec![],
+                    data: vec![],
+                },
+            )
+            .await
+            .unwrap();
+    }
+    // The preserved authority should be able to upgrade the program
+    endpoint
+        .process_program_upgrade(
+            &payer,
+            &program_id_deployed.pubkey(),
+            &program_authority,
+            &program_bytecode,
+            &payer.pubkey(),
+        )
+        .await
+        .unwrap();
+    // Cloning without preserving the authority makes programs immutable
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_cloned_programs(
+        &mut source,
+        &program_ids,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        (0, None),
+        endpoint
+            .get_program_meta(&program_id_deployed.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+    );
+    // Cloning a missing program should fail
+    assert!(ToolboxEndpoint::new_program_test_with_cloned_programs(
+        &mut source,
+        &[Keypair::new().pubkey()],
+        false,
+    )
+    .await
+    .is_err());
+}
@@ -1,7 +1,12 @@
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction::transfer;
+use solana_sdk::transaction::TransactionError;
 use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointComputeBudget;
 
 #[tokio::test]
 pub async fn run() {
@@ -59,4 +64,120 @@ pub async fn run() {
             .unwrap()
             .unwrap()
     );
+    // Check that the compute budget can be parsed back from the execution
+    assert_eq!(
+        ToolboxEndpointComputeBudget {
+            unit_limit: Some(paid_compute_units),
+            unit_price_micro_lamports: Some(micro_lamport_price_per_unit),
+            ..Default::default()
+        },
+        ToolboxEndpoint::parse_compute_budget(&execution.instructions).unwrap()
+    );
+    assert_eq!(
+        u64::from(paid_compute_units) * micro_lamport_price_per_unit
+            / 1_000_000,
+        ToolboxEndpoint::compute_execution_prioritization_fee(&execution)
+            .unwrap()
+    );
+    // Send a transaction with all compute budget settings and default unit limit
+    let compute_budget = ToolboxEndpointComputeBudget {
+        unit_limit: None,
+        unit_price_micro_lamports: Some(1_000_001),
+        heap_frame_bytes: Some(64 * 1024),
+        loaded_accounts_data_size_limit: Some(1_000_000),
+    };
+    endpoint
+        .request_airdrop(&payer.pubkey(), airdrop_lamports)
+        .await
+        .unwrap();
+    let payer_lamports_before = endpoint
+        .get_account_lamports(&payer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let (_, execution) = endpoint
+        .process_instructions_with_options(
+            &payer,
+            &ToolboxEndpoint::generate_instructions_with_compute_budget_settings(
+                &[transfer(&payer.pubkey(), &destination, transfer_lamports)],
+                &compute_budget,
+            ),
+            &[&payer],
+            &[],
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(None, execution.error);
+    assert_eq!(
+        compute_budget,
+        ToolboxEndpoint::parse_compute_budget(&execution.instructions).unwrap()
+    );
+    // The default unit limit reserves 3_000 units per builtin instruction
+    assert_eq!(
+        4 * 3_000,
+        ToolboxEndpoint::compute_effective_compute_unit_limit(
+            &execution.instructions
+        )
+        .unwrap()
+    );
+    // Stake is still a builtin while secp256r1 is not
+    assert_eq!(
+        3_000,
+        ToolboxEndpoint::compute_effective_compute_unit_limit(&[Instruction {
+            program_id: pubkey!("Stake11111111111111111111111111111111111111"),
+            accounts: vec![],
+            data: vec![],
+        }])
+        .unwrap()
+    );
+    assert_eq!(
+        200_000,
+        ToolboxEndpoint::compute_effective_compute_unit_limit(&[Instruction {
+            program_id: pubkey!("Secp256r1SigVerify1111111111111111111111111"),
+            accounts: vec![],
+            data: vec![],
+        }])
+        .unwrap()
+    );
+    // The prioritization fee rounds up
+    let prioritization_fee =
+        ToolboxEndpoint::compute_execution_prioritization_fee(&execution)
+            .unwrap();
+    assert_eq!(12_001, prioritization_fee);
+    assert_eq!(
+        payer_lamports_before
+            - transfer_lamports
+            - ToolboxEndpoint::LAMPORTS_PER_SIGNATURE
+            - prioritization_fee,
+        endpoint
+            .get_account_lamports(&payer.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+    );
+    // Repeating a compute budget setting is rejected, like the runtime does
+    let instructions_duplicated = [
+        ComputeBudgetInstruction::set_compute_unit_limit(10_000),
+        ComputeBudgetInstruction::set_compute_unit_limit(20_000),
+        transfer(&payer.pubkey(), &destination, transfer_lamports),
+    ];
+    ToolboxEndpoint::parse_compute_budget(&instructions_duplicated)
+        .unwrap_err();
+    ToolboxEndpoint::compute_prioritization_fee(&instructions_duplicated)
+        .unwrap_err();
+    let (_, execution) = endpoint
+        .process_instructions_with_options(
+            &payer,
+            &instructions_duplicated,
+            &[&payer],
+            &[],
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        Some(TransactionError::DuplicateInstruction(1)),
+        execution.error
+    );
 }
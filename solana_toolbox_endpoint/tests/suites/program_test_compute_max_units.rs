@@ -0,0 +1,74 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+
+#[tokio::test]
+pub async fn run() {
+    // Measure the compute units used by a simple transfer
+    let payer = Keypair::new();
+    let instruction =
+        transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000_000);
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let units_consumed = endpoint
+        .simulate_instruction(&payer, instruction.clone())
+        .await
+        .unwrap()
+        .units_consumed
+        .unwrap();
+    // A compute max units with some margin should let the transfer through
+    let units_margin = 10;
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_compute_max_units(units_consumed + units_margin),
+    )
+    .await;
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let execution = endpoint
+        .simulate_instruction(&payer, instruction.clone())
+        .await
+        .unwrap();
+    assert_eq!(None, execution.error);
+    assert_eq!(Some(units_consumed), execution.units_consumed);
+    endpoint
+        .process_instruction(&payer, instruction.clone())
+        .await
+        .unwrap();
+    // A compute max units below what is needed should fail the transfer
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_compute_max_units(units_consumed - 1),
+    )
+    .await;
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    assert!(endpoint
+        .simulate_instruction(&payer, instruction.clone())
+        .await
+        .unwrap()
+        .error
+        .is_some());
+    endpoint
+        .process_instruction(&payer, instruction)
+        .await
+        .unwrap_err();
+    // An invalid compute max units must be rejected
+    ToolboxEndpoint::try_new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_compute_max_units(u64::MAX),
+    )
+    .await
+    .err()
+    .unwrap();
+}
@@ -0,0 +1,79 @@
+use std::slice::from_ref;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Identical airdrops should not collide
+    let payer = Keypair::new();
+    let (signature_airdrop1, _) = endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let (signature_airdrop2, _) = endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    assert_ne!(signature_airdrop1, signature_airdrop2);
+    assert_eq!(
+        2_000_000_000,
+        endpoint.get_balance(&payer.pubkey()).await.unwrap()
+    );
+    // Process a locally compiled transaction
+    let destination = Pubkey::new_unique();
+    let instruction = transfer(&payer.pubkey(), &destination, 1_000_000);
+    let versioned_transaction = ToolboxEndpoint::compile_versioned_transaction(
+        &payer,
+        from_ref(&instruction),
+        &[],
+        &[],
+        endpoint.get_latest_blockhash().await.unwrap(),
+    )
+    .unwrap();
+    let (signature, execution) = endpoint
+        .process_versioned_transaction(versioned_transaction.clone(), true)
+        .await
+        .unwrap();
+    // The signature must be the real one from the transaction
+    assert_eq!(versioned_transaction.signatures[0], signature);
+    assert_eq!(execution, endpoint.get_execution(&signature).await.unwrap());
+    assert_eq!(
+        vec![signature],
+        endpoint
+            .search_signatures(&destination, usize::MAX, None, None)
+            .await
+            .unwrap()
+    );
+    // Processing the exact same transaction again must be refused
+    endpoint
+        .process_versioned_transaction(versioned_transaction.clone(), false)
+        .await
+        .unwrap_err();
+    assert_eq!(execution, endpoint.get_execution(&signature).await.unwrap());
+    assert_eq!(1_000_000, endpoint.get_balance(&destination).await.unwrap());
+    // Many identical instructions in a row should all succeed
+    for _ in 0..150 {
+        endpoint
+            .process_instruction(&payer, instruction.clone())
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        151_000_000,
+        endpoint.get_balance(&destination).await.unwrap()
+    );
+    assert_eq!(
+        151,
+        endpoint
+            .search_signatures(&destination, usize::MAX, None, None)
+            .await
+            .unwrap()
+            .len()
+    );
+}
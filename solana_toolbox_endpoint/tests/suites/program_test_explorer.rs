@@ -0,0 +1,106 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointCluster;
+use solana_toolbox_endpoint::ToolboxEndpointExplorer;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // A local genesis should not be recognized as a public cluster
+    let cluster = endpoint.get_cluster_from_genesis_hash().await.unwrap();
+    assert_eq!(ToolboxEndpointCluster::Custom, cluster);
+    // Dummy values
+    let address = Pubkey::default();
+    let signature = Signature::default();
+    let rpc_url = "http://localhost:8899";
+    // Unknown clusters should fall back to a custom URL
+    assert_eq!(
+        format!(
+            "https://explorer.solana.com/address/{}?cluster=custom&customUrl=http%3A%2F%2Flocalhost%3A8899",
+            address
+        ),
+        ToolboxEndpoint::compute_explorer_address_url(rpc_url, &address)
+    );
+    // Solscan does not support custom URLs and falls back to the solana explorer
+    assert_eq!(
+        format!(
+            "https://explorer.solana.com/tx/{}?cluster=custom&customUrl=http%3A%2F%2Flocalhost%3A8899",
+            signature
+        ),
+        ToolboxEndpoint::compute_explorer_signature_url_with_explorer(
+            &ToolboxEndpointExplorer::Solscan,
+            cluster,
+            rpc_url,
+            &signature,
+        )
+    );
+    // Known clusters should use each explorer's cluster naming
+    assert_eq!(
+        format!(
+            "https://solana.fm/address/{}?cluster=devnet-solana",
+            address
+        ),
+        ToolboxEndpoint::compute_explorer_address_url_with_explorer(
+            &ToolboxEndpointExplorer::SolanaFm,
+            ToolboxEndpointCluster::Devnet,
+            rpc_url,
+            &address,
+        )
+    );
+    assert_eq!(
+        format!("https://solscan.io/account/{}", address),
+        ToolboxEndpoint::compute_explorer_address_url_with_explorer(
+            &ToolboxEndpointExplorer::Solscan,
+            ToolboxEndpointCluster::MainnetBeta,
+            rpc_url,
+            &address,
+        )
+    );
+    assert_eq!(
+        format!("https://explorer.solana.com/tx/{}", signature),
+        ToolboxEndpoint::compute_explorer_signature_url(
+            "mainnet-beta",
+            &signature
+        )
+    );
+    // Custom templates should be filled
+    let explorer = ToolboxEndpointExplorer::Custom {
+        address_url_template: "https://my.explorer/{cluster}/a/{address}"
+            .to_string(),
+        signature_url_template: "https://my.explorer/{cluster}/s/{signature}"
+            .to_string(),
+        simulation_url_template:
+            "https://my.explorer/sim?rpc={rpc_url}&message={message}"
+                .to_string(),
+    };
+    assert_eq!(
+        format!("https://my.explorer/testnet/a/{}", address),
+        ToolboxEndpoint::compute_explorer_address_url_with_explorer(
+            &explorer,
+            ToolboxEndpointCluster::Testnet,
+            rpc_url,
+            &address,
+        )
+    );
+    assert_eq!(
+        format!("https://my.explorer/custom/s/{}", signature),
+        ToolboxEndpoint::compute_explorer_signature_url_with_explorer(
+            &explorer,
+            ToolboxEndpointCluster::Custom,
+            rpc_url,
+            &signature,
+        )
+    );
+    assert_eq!(
+        "https://my.explorer/sim?rpc=http%3A%2F%2Flocalhost%3A8899&message=AQID",
+        ToolboxEndpoint::compute_explorer_simulation_url_with_explorer(
+            &explorer,
+            ToolboxEndpointCluster::Custom,
+            rpc_url,
+            &[],
+            &[1, 2, 3],
+        )
+    );
+}
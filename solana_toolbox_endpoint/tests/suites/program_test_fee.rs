@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+
+use solana_sdk::account::Account;
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::address_lookup_table::state::LookupTableMeta;
+use solana_sdk::message::Message;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::allocate;
+use solana_sdk::system_instruction::create_account;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointFee;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Prepare a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Prepare a batch of instructions creating accounts
+    let account_created = Keypair::new();
+    let account_allocated = Keypair::new();
+    let rent_lamports = Rent::default().minimum_balance(100);
+    let instructions =
+        ToolboxEndpoint::generate_instructions_with_compute_budget(
+            &[
+                create_account(
+                    &payer.pubkey(),
+                    &account_created.pubkey(),
+                    rent_lamports,
+                    100,
+                    &Pubkey::new_unique(),
+                ),
+                transfer(
+                    &payer.pubkey(),
+                    &account_allocated.pubkey(),
+                    Rent::default().minimum_balance(200),
+                ),
+                allocate(&account_allocated.pubkey(), 200),
+            ],
+            Some(100_000),
+            Some(3_000_000),
+        );
+    // Compute the expected fee
+    let expected_fee = ToolboxEndpointFee {
+        signatures: 3,
+        base_fee: 15_000,
+        prioritization_fee: 300_000,
+        total_fee: 315_000,
+        rent_exempt_deposits: Rent::default().minimum_balance(100)
+            + Rent::default().minimum_balance(200),
+    };
+    // Check the fee preview of a v0 message
+    assert_eq!(
+        expected_fee,
+        endpoint
+            .compute_fee_for_instructions(&payer.pubkey(), &instructions, &[])
+            .await
+            .unwrap()
+    );
+    // Check the fee preview of a legacy message (cross-checked by the endpoint)
+    let mut legacy_message = Message::new(&instructions, Some(&payer.pubkey()));
+    legacy_message.recent_blockhash =
+        endpoint.get_latest_blockhash().await.unwrap();
+    let legacy_message = VersionedMessage::Legacy(legacy_message);
+    assert_eq!(
+        Some(expected_fee.total_fee),
+        endpoint.get_fee_for_message(&legacy_message).await.unwrap()
+    );
+    assert_eq!(
+        expected_fee,
+        endpoint
+            .compute_fee_for_message(&legacy_message)
+            .await
+            .unwrap()
+    );
+    // Process the instructions and check the fee actually paid
+    endpoint
+        .process_instructions_with_signers(
+            &payer,
+            &instructions,
+            &[&account_created, &account_allocated],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        2_000_000_000
+            - expected_fee.total_fee
+            - Rent::default().minimum_balance(100)
+            - Rent::default().minimum_balance(200),
+        endpoint.get_balance(&payer.pubkey()).await.unwrap()
+    );
+    // Prepare an endpoint with an address lookup table at genesis
+    let users = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    let address_lookup_table = Pubkey::new_unique();
+    let address_lookup_table_data = AddressLookupTable {
+        meta: LookupTableMeta::default(),
+        addresses: Cow::Owned(users.clone()),
+    }
+    .serialize_for_tests()
+    .unwrap();
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new().with_genesis_account(
+            address_lookup_table,
+            Account {
+                lamports: Rent::default()
+                    .minimum_balance(address_lookup_table_data.len()),
+                data: address_lookup_table_data,
+                owner: ToolboxEndpoint::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
+    )
+    .await;
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    let resolved_address_lookup_tables = endpoint
+        .resolve_address_lookup_tables(&[address_lookup_table])
+        .await
+        .unwrap();
+    let transfer_lamports = Rent::default().minimum_balance(0);
+    let instructions =
+        ToolboxEndpoint::generate_instructions_with_compute_budget(
+            &[
+                transfer(&payer.pubkey(), &users[0], transfer_lamports),
+                transfer(&payer.pubkey(), &users[1], transfer_lamports),
+            ],
+            Some(50_000),
+            Some(2_000_000),
+        );
+    let expected_fee = ToolboxEndpointFee {
+        signatures: 1,
+        base_fee: 5_000,
+        prioritization_fee: 100_000,
+        total_fee: 105_000,
+        rent_exempt_deposits: 0,
+    };
+    // The memnet cannot cross-check the fee of a v0 message
+    let versioned_transaction = ToolboxEndpoint::compile_versioned_transaction(
+        &payer,
+        &instructions,
+        &[],
+        &resolved_address_lookup_tables,
+        endpoint.get_latest_blockhash().await.unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        1,
+        versioned_transaction
+            .message
+            .address_table_lookups()
+            .unwrap()
+            .len()
+    );
+    // The message sent to the RPC for a fee keeps its version prefix
+    let encoded_message = ToolboxEndpoint::sanitize_and_decode_base64(
+        &ToolboxEndpoint::encode_versioned_message_base64(
+            &versioned_transaction.message,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(0x80, encoded_message[0]);
+    assert_eq!(versioned_transaction.message.serialize(), encoded_message);
+    let encoded_legacy_message = ToolboxEndpoint::sanitize_and_decode_base64(
+        &ToolboxEndpoint::encode_versioned_message_base64(&legacy_message)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(legacy_message.serialize(), encoded_legacy_message);
+    assert_eq!(
+        None,
+        endpoint
+            .get_fee_for_message(&versioned_transaction.message)
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        expected_fee,
+        endpoint
+            .compute_fee_for_message(&versioned_transaction.message)
+            .await
+            .unwrap()
+    );
+    // So we check the computed fee against the fee actually paid
+    let payer_lamports_before =
+        endpoint.get_balance(&payer.pubkey()).await.unwrap();
+    endpoint
+        .process_versioned_transaction(versioned_transaction, false)
+        .await
+        .unwrap();
+    assert_eq!(
+        payer_lamports_before - expected_fee.total_fee - 2 * transfer_lamports,
+        endpoint.get_balance(&payer.pubkey()).await.unwrap()
+    );
+}
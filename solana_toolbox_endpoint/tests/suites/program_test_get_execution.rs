@@ -64,6 +64,7 @@ pub async fn run() {
                     failure: None,
                 }
             )]),
+            inner_instructions: None,
             logs: Some(vec![
                 "Program 11111111111111111111111111111111 invoke [1]"
                     .to_string(),
@@ -115,6 +116,7 @@ pub async fn run() {
                 failure: Some("custom program error: 0x1".to_string()),
             }
         )]),
+        inner_instructions: None,
         logs: Some(vec![
             "Program 11111111111111111111111111111111 invoke [1]".to_string(),
             "Transfer: insufficient lamports 1899980000, need 10000000000".to_string(),
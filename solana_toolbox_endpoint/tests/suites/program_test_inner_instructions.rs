@@ -0,0 +1,73 @@
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint, inner instructions are simulated on memnet
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_simulated_inner_instructions(),
+    )
+    .await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Create a mint
+    let mint = endpoint
+        .process_spl_token_mint_new(&payer, &payer.pubkey(), None, 6)
+        .await
+        .unwrap();
+    // Create an associated token account (which CPIs into other programs)
+    let owner = Keypair::new();
+    let instruction = create_associated_token_account(
+        &payer.pubkey(),
+        &owner.pubkey(),
+        &mint,
+        &ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID,
+    );
+    let simulation = endpoint
+        .simulate_instruction(&payer, instruction.clone())
+        .await
+        .unwrap();
+    let (signature, execution) = endpoint
+        .process_instruction(&payer, instruction)
+        .await
+        .unwrap();
+    // Check that the inner instructions were captured
+    let inner_instructions = execution.inner_instructions.clone().unwrap();
+    assert_eq!(inner_instructions.len(), 1);
+    assert!(!inner_instructions[0].is_empty());
+    for inner_instruction in &inner_instructions[0] {
+        assert_eq!(inner_instruction.stack_height, Some(2));
+    }
+    let inner_programs_ids = inner_instructions[0]
+        .iter()
+        .map(|inner_instruction| inner_instruction.instruction.program_id)
+        .collect::<Vec<_>>();
+    assert!(inner_programs_ids.contains(&ToolboxEndpoint::SYSTEM_PROGRAM_ID));
+    assert!(inner_programs_ids.contains(&ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID));
+    // Check that the simulation and the lookup match the processing
+    assert_eq!(simulation.inner_instructions, execution.inner_instructions);
+    assert_eq!(endpoint.get_execution(&signature).await.unwrap(), execution);
+    // Without the option, processing does not simulate the inner instructions
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    let (_, execution) = endpoint
+        .process_instruction(
+            &payer,
+            transfer(&payer.pubkey(), &mint, 1_000_000),
+        )
+        .await
+        .unwrap();
+    assert_eq!(execution.inner_instructions, None);
+}
@@ -0,0 +1,135 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::create_account;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Generate an instruction that requires two signatures
+    let account = Keypair::new();
+    let owner = Pubkey::new_unique();
+    let instruction = create_account(
+        &payer.pubkey(),
+        &account.pubkey(),
+        100_000_000,
+        42,
+        &owner,
+    );
+    // Compile the unsigned transaction knowing only the payer's address
+    let unsigned = ToolboxEndpoint::compile_versioned_transaction_unsigned(
+        &payer.pubkey(),
+        &[instruction],
+        &[],
+        endpoint.get_latest_blockhash().await.unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        vec![payer.pubkey(), account.pubkey()],
+        ToolboxEndpoint::get_versioned_transaction_signers_missing(&unsigned)
+            .unwrap()
+    );
+    ToolboxEndpoint::verify_versioned_transaction_signatures(&unsigned)
+        .unwrap_err();
+    // Export the unsigned transaction in both encodings
+    let exported_base58 =
+        ToolboxEndpoint::encode_versioned_transaction_base58(&unsigned)
+            .unwrap();
+    let exported_base64 =
+        ToolboxEndpoint::encode_versioned_transaction_base64(&unsigned)
+            .unwrap();
+    // Each signer imports its own copy and signs it separately
+    let mut signed_by_payer =
+        ToolboxEndpoint::sanitize_and_decode_versioned_transaction_base58(
+            &exported_base58,
+        )
+        .unwrap();
+    ToolboxEndpoint::sign_versioned_transaction_partial(
+        &mut signed_by_payer,
+        &[&payer],
+    )
+    .unwrap();
+    let mut signed_by_account =
+        ToolboxEndpoint::sanitize_and_decode_versioned_transaction_base64(
+            &exported_base64,
+        )
+        .unwrap();
+    ToolboxEndpoint::sign_versioned_transaction_partial(
+        &mut signed_by_account,
+        &[&account],
+    )
+    .unwrap();
+    assert_eq!(
+        vec![account.pubkey()],
+        ToolboxEndpoint::get_versioned_transaction_signers_missing(
+            &signed_by_payer
+        )
+        .unwrap()
+    );
+    ToolboxEndpoint::verify_versioned_transaction_signatures(&signed_by_payer)
+        .unwrap_err();
+    // Signing with an unrelated keypair must fail
+    ToolboxEndpoint::sign_versioned_transaction_partial(
+        &mut signed_by_account.clone(),
+        &[&Keypair::new()],
+    )
+    .unwrap_err();
+    // Merge the signatures from the serialized copies
+    let merged = ToolboxEndpoint::merge_versioned_transaction_signatures(&[
+        ToolboxEndpoint::sanitize_and_decode_versioned_transaction_base64(
+            &ToolboxEndpoint::encode_versioned_transaction_base64(
+                &signed_by_payer,
+            )
+            .unwrap(),
+        )
+        .unwrap(),
+        ToolboxEndpoint::sanitize_and_decode_versioned_transaction_base58(
+            &ToolboxEndpoint::encode_versioned_transaction_base58(
+                &signed_by_account,
+            )
+            .unwrap(),
+        )
+        .unwrap(),
+    ])
+    .unwrap();
+    assert!(ToolboxEndpoint::get_versioned_transaction_signers_missing(
+        &merged
+    )
+    .unwrap()
+    .is_empty());
+    ToolboxEndpoint::verify_versioned_transaction_signatures(&merged).unwrap();
+    // Merging copies of different messages must fail
+    let other = ToolboxEndpoint::compile_versioned_transaction_unsigned(
+        &payer.pubkey(),
+        &[],
+        &[],
+        endpoint.get_latest_blockhash().await.unwrap(),
+    )
+    .unwrap();
+    ToolboxEndpoint::merge_versioned_transaction_signatures(&[
+        merged.clone(),
+        other,
+    ])
+    .unwrap_err();
+    // Submit the merged transaction and check its effect
+    endpoint
+        .process_versioned_transaction(merged, true)
+        .await
+        .unwrap();
+    let created = endpoint
+        .get_account(&account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(100_000_000, created.lamports);
+    assert_eq!(42, created.data.len());
+    assert_eq!(owner, created.owner);
+}
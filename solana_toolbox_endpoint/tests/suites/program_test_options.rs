@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+
+#[tokio::test]
+pub async fn run() {
+    // Prepare the custom genesis state
+    let payer = Keypair::new();
+    let genesis_address = Pubkey::new_unique();
+    let genesis_owner = Pubkey::new_unique();
+    let feature_disable_fees_sysvar =
+        Pubkey::from_str("JAN1trEUEtZjgXYzNBYHU9DYd7GnThhXfFP7SzPXkPsG")
+            .unwrap();
+    // Initialize the endpoint with custom options
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_deactivated_feature(feature_disable_fees_sysvar)
+            .with_compute_max_units(500_000)
+            .with_genesis_account(
+                genesis_address,
+                Account {
+                    lamports: 42_000_000,
+                    data: vec![1, 2, 3, 4],
+                    owner: genesis_owner,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .with_genesis_account_json_path(
+                "./tests/fixtures/spl_token_metaplex_edition_account.json",
+            )
+            .with_payer(payer.insecure_clone())
+            .with_payer_lamports(5_000_000_000),
+    )
+    .await;
+    // The deactivated feature should not be active
+    assert_eq!(
+        None,
+        endpoint
+            .get_account(&feature_disable_fees_sysvar)
+            .await
+            .unwrap()
+    );
+    // The genesis accounts should be present
+    let genesis_account = endpoint
+        .get_account(&genesis_address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(42_000_000, genesis_account.lamports);
+    assert_eq!(vec![1, 2, 3, 4], genesis_account.data);
+    assert_eq!(genesis_owner, genesis_account.owner);
+    let edition = endpoint
+        .get_spl_token_metaplex_edition(
+            &Pubkey::from_str("Gx9Cq2wpN6Z7TpF5Nbm5bGg6nzkRwzLsEPo9Qn5yZsTj")
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(7, edition.edition);
+    // Airdrops are funded by the custom payer (and fail when it runs out)
+    let receiver = Pubkey::new_unique();
+    endpoint
+        .request_airdrop(&receiver, 1_000_000_000)
+        .await
+        .unwrap();
+    assert_eq!(
+        1_000_000_000,
+        endpoint.get_balance(&receiver).await.unwrap()
+    );
+    assert_eq!(
+        4_000_000_000 - 5_000,
+        endpoint.get_balance(&payer.pubkey()).await.unwrap()
+    );
+    assert!(endpoint
+        .request_airdrop(&receiver, 5_000_000_000)
+        .await
+        .unwrap()
+        .1
+        .error
+        .is_some());
+    // Only setting the payer lamports should limit the default payer
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_payer_lamports(2_000_000_000),
+    )
+    .await;
+    endpoint
+        .request_airdrop(&receiver, 1_000_000_000)
+        .await
+        .unwrap();
+    assert!(endpoint
+        .request_airdrop(&receiver, 1_000_000_000)
+        .await
+        .unwrap()
+        .1
+        .error
+        .is_some());
+}
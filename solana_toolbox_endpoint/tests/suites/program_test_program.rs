@@ -20,19 +20,42 @@ pub async fn run() {
     let program_bytecode =
         read("./tests/fixtures/bpf_loader_program_minimal.so").unwrap();
     // Create a buffer
-    let program_buffer = endpoint
+    let program_buffer = Keypair::new();
+    let report = endpoint
         .process_program_buffer_new(
             &payer,
+            &program_buffer,
+            &program_authority,
+            &program_bytecode,
+            &program_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(report.program_buffer, program_buffer.pubkey());
+    assert_eq!(
+        report.chunks_skipped + report.chunks_written,
+        report.chunks_total
+    );
+    assert_eq!(report.chunks_failed, 0);
+    // Resuming the same buffer has nothing left to write
+    let report = endpoint
+        .process_program_buffer_new(
+            &payer,
+            &program_buffer,
+            &program_authority,
             &program_bytecode,
             &program_authority.pubkey(),
         )
         .await
         .unwrap();
+    assert_eq!(report.chunks_skipped, report.chunks_total);
+    assert_eq!(report.chunks_written, 0);
+    assert_eq!(report.rounds, 0);
     // Close the buffer
     endpoint
         .process_program_buffer_close(
             &payer,
-            &program_buffer,
+            &program_buffer.pubkey(),
             &program_authority,
             &payer.pubkey(),
         )
@@ -0,0 +1,143 @@
+use std::fs::read;
+
+use solana_sdk::bpf_loader_upgradeable::write;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramBufferWriteReport;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    // Program details
+    let program_id = Keypair::new();
+    let program_authority = Keypair::new();
+    let program_bytecode =
+        read("./tests/fixtures/bpf_loader_program_minimal.so").unwrap();
+    let program_chunks = program_bytecode.chunks(914).collect::<Vec<_>>();
+    // Create an empty buffer
+    let program_buffer = Keypair::new();
+    endpoint
+        .process_program_buffer_init(
+            &payer,
+            &program_buffer,
+            &program_authority.pubkey(),
+            program_bytecode.len(),
+        )
+        .await
+        .unwrap();
+    // Only write the first half of the bytecode (simulate an interruption)
+    let program_bytecode_half_len = 914 * (program_chunks.len() / 2);
+    let mut program_bytecode_half = program_bytecode.clone();
+    program_bytecode_half[program_bytecode_half_len..].fill(0);
+    endpoint
+        .process_program_buffer_write(
+            &payer,
+            &program_buffer.pubkey(),
+            &program_authority,
+            &program_bytecode_half,
+            4,
+        )
+        .await
+        .unwrap();
+    // Corrupt one of the already written chunks
+    endpoint
+        .process_instruction_with_signers(
+            &payer,
+            write(
+                &program_buffer.pubkey(),
+                &program_authority.pubkey(),
+                914,
+                vec![42; 10],
+            ),
+            &[&program_authority],
+        )
+        .await
+        .unwrap();
+    // Some time passes before we resume the upload
+    endpoint.forward_clock_slot(1).await.unwrap();
+    // Resume the upload, only the missing and corrupted chunks are rewritten
+    let program_chunks_missing = program_chunks[program_chunks.len() / 2..]
+        .iter()
+        .filter(|chunk| chunk.iter().any(|byte| *byte != 0))
+        .count();
+    assert_eq!(
+        endpoint
+            .process_program_buffer_write(
+                &payer,
+                &program_buffer.pubkey(),
+                &program_authority,
+                &program_bytecode,
+                4,
+            )
+            .await
+            .unwrap(),
+        ToolboxEndpointProgramBufferWriteReport {
+            program_buffer: program_buffer.pubkey(),
+            chunks_total: program_chunks.len(),
+            chunks_skipped: program_chunks.len() - program_chunks_missing - 1,
+            chunks_written: program_chunks_missing + 1,
+            chunks_failed: 0,
+            rounds: 1,
+        }
+    );
+    // Resuming a complete buffer does nothing
+    assert_eq!(
+        endpoint
+            .process_program_buffer_write(
+                &payer,
+                &program_buffer.pubkey(),
+                &program_authority,
+                &program_bytecode,
+                4,
+            )
+            .await
+            .unwrap(),
+        ToolboxEndpointProgramBufferWriteReport {
+            program_buffer: program_buffer.pubkey(),
+            chunks_total: program_chunks.len(),
+            chunks_skipped: program_chunks.len(),
+            chunks_written: 0,
+            chunks_failed: 0,
+            rounds: 0,
+        }
+    );
+    // Writing with the wrong authority must fail
+    endpoint
+        .process_program_buffer_write(
+            &payer,
+            &program_buffer.pubkey(),
+            &Keypair::new(),
+            &program_bytecode,
+            4,
+        )
+        .await
+        .unwrap_err();
+    // Deploy the program from the resumed buffer
+    endpoint
+        .process_program_buffer_deploy(
+            &payer,
+            &program_id,
+            &program_buffer.pubkey(),
+            &program_authority,
+            program_bytecode.len(),
+        )
+        .await
+        .unwrap();
+    // Check that the deployed bytecode is the one we uploaded
+    assert_eq!(
+        program_bytecode,
+        endpoint
+            .get_program_bytecode(&program_id.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+    );
+}
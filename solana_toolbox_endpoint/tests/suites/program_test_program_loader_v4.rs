@@ -0,0 +1,188 @@
+use std::fs::read;
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestPreloadedProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint with a preloaded loader-v4 program
+    let program_preloaded_id = Pubkey::new_unique();
+    let mut endpoint =
+        ToolboxEndpoint::new_program_test_with_preloaded_programs(&[
+            ToolboxEndpointProgramTestPreloadedProgram::new(
+                program_preloaded_id,
+                "bpf_loader_program_minimal",
+            )
+            .with_loader_id(ToolboxEndpoint::LOADER_V4_PROGRAM_ID),
+        ])
+        .await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    // Program details
+    let program_bytecode =
+        read("./tests/fixtures/bpf_loader_program_minimal.so").unwrap();
+    // Check that the preloaded program is available and immutable
+    assert_eq!(
+        endpoint
+            .get_program_meta(&program_preloaded_id)
+            .await
+            .unwrap(),
+        Some((0, None))
+    );
+    assert_eq!(
+        endpoint
+            .get_program_bytecode(&program_preloaded_id)
+            .await
+            .unwrap(),
+        Some(program_bytecode.clone())
+    );
+    endpoint
+        .process_instruction(
+            &payer,
+            Instruction {
+                program_id: program_preloaded_id,
+                accounts: vec![],
+                data: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    // Deploy a new loader-v4 program
+    let program_id = Keypair::new();
+    let program_authority = Keypair::new();
+    endpoint
+        .process_program_loader_v4_deploy(
+            &payer,
+            &program_id,
+            &program_authority,
+            &program_bytecode,
+        )
+        .await
+        .unwrap();
+    let program_slot = endpoint.get_sysvar_clock().await.unwrap().slot;
+    // Check the deployed program
+    assert_eq!(
+        endpoint
+            .get_program_meta(&program_id.pubkey())
+            .await
+            .unwrap(),
+        Some((program_slot, Some(program_authority.pubkey())))
+    );
+    assert_eq!(
+        endpoint
+            .get_program_bytecode(&program_id.pubkey())
+            .await
+            .unwrap(),
+        Some(program_bytecode.clone())
+    );
+    // Wait a slot to be able to interact with the program again
+    endpoint.forward_clock_slot(1).await.unwrap();
+    // Upgrade the program with a bigger bytecode
+    let mut program_bytecode_upgraded = program_bytecode.clone();
+    program_bytecode_upgraded.extend_from_slice(&[0; 1000]);
+    endpoint
+        .process_program_loader_v4_upgrade(
+            &payer,
+            &program_id.pubkey(),
+            &program_authority,
+            &program_bytecode_upgraded,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        endpoint
+            .get_program_bytecode(&program_id.pubkey())
+            .await
+            .unwrap(),
+        Some(program_bytecode_upgraded.clone())
+    );
+    // Transfer the authority to someone else
+    let program_authority_new = Keypair::new();
+    endpoint
+        .process_program_loader_v4_transfer_authority(
+            &payer,
+            &program_id.pubkey(),
+            &program_authority,
+            &program_authority_new,
+        )
+        .await
+        .unwrap();
+    let program_slot = endpoint.get_sysvar_clock().await.unwrap().slot;
+    assert_eq!(
+        endpoint
+            .get_program_meta(&program_id.pubkey())
+            .await
+            .unwrap(),
+        Some((program_slot, Some(program_authority_new.pubkey())))
+    );
+    // Downgrade the program back to its original bytecode
+    endpoint.forward_clock_slot(1).await.unwrap();
+    endpoint
+        .process_program_loader_v4_upgrade(
+            &payer,
+            &program_id.pubkey(),
+            &program_authority_new,
+            &program_bytecode,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        endpoint
+            .get_program_bytecode(&program_id.pubkey())
+            .await
+            .unwrap(),
+        Some(program_bytecode.clone())
+    );
+    // Finalize the program, it then has no authority anymore
+    endpoint.forward_clock_slot(1).await.unwrap();
+    endpoint
+        .process_program_loader_v4_finalize(
+            &payer,
+            &program_id.pubkey(),
+            &program_authority_new,
+            &program_id.pubkey(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        endpoint
+            .get_program_meta(&program_id.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .1,
+        None
+    );
+    // The program cannot be modified anymore
+    endpoint.forward_clock_slot(1).await.unwrap();
+    endpoint
+        .process_program_loader_v4_retract(
+            &payer,
+            &program_id.pubkey(),
+            &program_authority_new,
+        )
+        .await
+        .unwrap_err();
+    // Preloading a program with an unsupported loader must fail
+    ToolboxEndpoint::try_new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new().with_preloaded_program(
+            ToolboxEndpointProgramTestPreloadedProgram::new(
+                Pubkey::new_unique(),
+                "bpf_loader_program_minimal",
+            )
+            .with_loader_id(ToolboxEndpoint::BPF_LOADER_UPGRADEABLE_PROGRAM_ID),
+        ),
+    )
+    .await
+    .err()
+    .unwrap();
+}
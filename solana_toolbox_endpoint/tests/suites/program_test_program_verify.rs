@@ -0,0 +1,124 @@
+use std::fs::read;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestPreloadedProgram;
+use solana_toolbox_endpoint::ToolboxEndpointProgramVerificationDiff;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint with a non-upgradeable program
+    let program_preloaded_id = Pubkey::new_unique();
+    let mut endpoint =
+        ToolboxEndpoint::new_program_test_with_preloaded_programs(&[
+            ToolboxEndpointProgramTestPreloadedProgram::new(
+                program_preloaded_id,
+                "bpf_loader_program_minimal",
+            ),
+        ])
+        .await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    // Program details
+    let program_id = Keypair::new();
+    let program_authority = Keypair::new();
+    let program_bytecode =
+        read("./tests/fixtures/bpf_loader_program_minimal.so").unwrap();
+    // Deploy the upgradeable program
+    endpoint
+        .process_program_deploy(
+            &payer,
+            &program_id,
+            &program_authority,
+            &program_bytecode,
+        )
+        .await
+        .unwrap();
+    let program_slot = endpoint.get_sysvar_clock().await.unwrap().slot;
+    // Verify against the same bytecode with some extra padding
+    let mut program_bytecode_padded = program_bytecode.clone();
+    program_bytecode_padded.extend_from_slice(&[0; 42]);
+    let program_verification = endpoint
+        .verify_program_bytecode(&program_id.pubkey(), &program_bytecode_padded)
+        .await
+        .unwrap();
+    assert!(program_verification.matches);
+    assert_eq!(program_verification.slot, program_slot);
+    assert_eq!(
+        program_verification.upgrade_authority,
+        Some(program_authority.pubkey())
+    );
+    assert_eq!(
+        program_verification.onchain_hash,
+        program_verification.local_hash
+    );
+    assert_eq!(
+        program_verification.onchain_length,
+        program_bytecode
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .unwrap()
+            + 1
+    );
+    assert_eq!(program_verification.diff, None);
+    // Verify against a modified bytecode
+    let mut program_bytecode_modified = program_bytecode.clone();
+    program_bytecode_modified[100] ^= 0xFF;
+    program_bytecode_modified[101] ^= 0xFF;
+    program_bytecode_modified[200] ^= 0xFF;
+    program_bytecode_modified.push(0x42);
+    let program_verification = endpoint
+        .verify_program_bytecode(
+            &program_id.pubkey(),
+            &program_bytecode_modified,
+        )
+        .await
+        .unwrap();
+    assert!(!program_verification.matches);
+    assert_ne!(
+        program_verification.onchain_hash,
+        program_verification.local_hash
+    );
+    assert_eq!(
+        program_verification.local_length,
+        program_bytecode.len() + 1
+    );
+    assert_eq!(
+        program_verification.diff,
+        Some(ToolboxEndpointProgramVerificationDiff {
+            first_offset: 100,
+            differing_bytes: 4,
+            differing_ranges: vec![
+                (100, 102),
+                (200, 201),
+                (program_bytecode.len(), program_bytecode.len() + 1)
+            ],
+        })
+    );
+    // Verify the non-upgradeable program
+    let program_verification = endpoint
+        .verify_program_bytecode(&program_preloaded_id, &program_bytecode)
+        .await
+        .unwrap();
+    assert!(program_verification.matches);
+    assert_eq!(program_verification.slot, 0);
+    assert_eq!(program_verification.upgrade_authority, None);
+    assert_eq!(
+        endpoint
+            .get_program_meta(&program_preloaded_id)
+            .await
+            .unwrap(),
+        Some((0, None))
+    );
+    // Verifying a missing program must fail
+    endpoint
+        .verify_program_bytecode(&Pubkey::new_unique(), &program_bytecode)
+        .await
+        .unwrap_err();
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use anyhow::anyhow;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointRemoteSigner;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a remote payer that records everything it signs
+    let payer_keypair = Keypair::new();
+    let payer_pubkey = payer_keypair.pubkey();
+    let payer_signed = Arc::new(RwLock::new(vec![]));
+    let payer_signed_recorder = payer_signed.clone();
+    let payer = ToolboxEndpointRemoteSigner::new(
+        payer_pubkey,
+        Arc::new(move |pubkey, message| {
+            assert_eq!(*pubkey, payer_keypair.pubkey());
+            payer_signed_recorder
+                .write()
+                .unwrap()
+                .push(message.to_vec());
+            Ok(payer_keypair.sign_message(message))
+        }),
+    );
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Create an account using the remote payer and a local signer
+    let account = Keypair::new();
+    let owner = Pubkey::new_unique();
+    endpoint
+        .process_system_create(&payer, &account, 100_000_000, 42, &owner)
+        .await
+        .unwrap();
+    assert_eq!(1, payer_signed.read().unwrap().len());
+    // Check that the account was created
+    let created = endpoint
+        .get_account(&account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(100_000_000, created.lamports);
+    assert_eq!(owner, created.owner);
+    // Use the remote signer as a non-payer signer
+    let destination = Pubkey::new_unique();
+    let local_payer = Keypair::new();
+    endpoint
+        .request_airdrop(&local_payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    endpoint
+        .process_system_transfer(&local_payer, &payer, &destination, 1_000_000)
+        .await
+        .unwrap();
+    assert_eq!(1_000_000, endpoint.get_balance(&destination).await.unwrap());
+    assert_eq!(2, payer_signed.read().unwrap().len());
+    // A remote signer that refuses to sign must make processing fail
+    let refusing = ToolboxEndpointRemoteSigner::new(
+        payer_pubkey,
+        Arc::new(|_, _| Err(anyhow!("Signing refused"))),
+    );
+    endpoint
+        .process_system_transfer(&refusing, &refusing, &destination, 1)
+        .await
+        .unwrap_err();
+    // A remote signer returning the wrong signature must make processing fail
+    let impostor = Keypair::new();
+    let mismatched = ToolboxEndpointRemoteSigner::new(
+        payer_pubkey,
+        Arc::new(move |_, message| Ok(impostor.sign_message(message))),
+    );
+    endpoint
+        .process_system_transfer(&mismatched, &mismatched, &destination, 1)
+        .await
+        .unwrap_err();
+    assert_eq!(1_000_000, endpoint.get_balance(&destination).await.unwrap());
+}
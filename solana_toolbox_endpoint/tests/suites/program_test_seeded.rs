@@ -0,0 +1,62 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointLoggerHistory;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+
+async fn run_seeded(
+    seed: &str,
+) -> (Vec<Pubkey>, Vec<ToolboxEndpointExecution>) {
+    // Initialize the endpoint with a seed
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new().with_seed(seed),
+    )
+    .await;
+    let logger_history = ToolboxEndpointLoggerHistory::new();
+    endpoint.add_logger(Box::new(logger_history.clone()));
+    // Generate a payer from the endpoint
+    let payer = endpoint.generate_keypair();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Create a few accounts using helpers generating internal keypairs
+    let account = endpoint
+        .process_system_new_exempt(&payer, 42, &Pubkey::default())
+        .await
+        .unwrap();
+    let mint = endpoint
+        .process_spl_token_mint_new(&payer, &payer.pubkey(), None, 6)
+        .await
+        .unwrap();
+    let token_account = endpoint
+        .process_spl_token_account_new(&payer, &payer.pubkey(), &mint)
+        .await
+        .unwrap();
+    // Collect everything that was generated
+    let executions = logger_history
+        .get_processed()
+        .iter()
+        .map(|(_signature, execution)| execution.clone())
+        .collect::<Vec<_>>();
+    (
+        vec![payer.pubkey(), account, mint, token_account],
+        executions,
+    )
+}
+
+#[tokio::test]
+pub async fn run() {
+    // Two runs with the same seed should be identical
+    let (addresses1, executions1) = run_seeded("hello").await;
+    let (addresses2, executions2) = run_seeded("hello").await;
+    assert_eq!(addresses1, addresses2);
+    assert_eq!(executions1, executions2);
+    assert_eq!(4, executions1.len());
+    // A different seed should generate different addresses
+    let (addresses3, _executions3) = run_seeded("world").await;
+    for address in &addresses3 {
+        assert!(!addresses1.contains(address));
+    }
+}
@@ -54,6 +54,7 @@ pub async fn run() {
                     failure: None
                 }
             )]),
+            inner_instructions: Some(vec![vec![]]),
             logs: Some(vec![
                 "Program 11111111111111111111111111111111 invoke [1]"
                     .to_string(),
@@ -105,6 +106,7 @@ pub async fn run() {
                     }
                 )
             ]),
+            inner_instructions: Some(vec![vec![]]),
             logs: Some(vec![
                 "Program 11111111111111111111111111111111 invoke [1]".to_string(),
                 "Transfer: insufficient lamports 1999990000, need 10000000000".to_string(),
@@ -151,6 +153,7 @@ pub async fn run() {
                     }
                 )
             ]),
+            inner_instructions: Some(vec![vec![]]),
             logs: Some(vec![
                 "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]".to_string(),
                 "Program log: Instruction: UiAmountToAmount".to_string(),
@@ -0,0 +1,212 @@
+use std::fs::read_to_string;
+use std::str::FromStr;
+
+use solana_sdk::account::Account;
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::toolbox_endpoint_program_test_builtin_program;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointLoggerHistory;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
+
+#[tokio::test]
+pub async fn run() {
+    // Stand-in for the metaplex program, we only check the instructions sent
+    fn metaplex_program_entry(
+        _program_id: &Pubkey,
+        _accounts: &[AccountInfo],
+        _data: &[u8],
+    ) -> Result<(), ProgramError> {
+        Ok(())
+    }
+    // Known addresses used in the fixtures
+    let mint = Pubkey::from_str("7mFX7J8zFwmf7yLKuzJ2cZGLNv3Mkt4TWtvjvH1dQYn5")
+        .unwrap();
+    let collection_mint =
+        Pubkey::from_str("4Zs2Gd8Z6sTw7HXsH1KYEK2cDMx2JbLEqTbmjJX2GYXS")
+            .unwrap();
+    let update_authority =
+        Pubkey::from_str("9Tq4kxjYyVAd6TNSWT1Ujwpt6bhgh4Dmh8NTPPqgDddS")
+            .unwrap();
+    // Make a sized collection from the legacy collection metadata
+    let (_, collection_legacy_account) =
+        ToolboxEndpoint::sanitize_and_decode_account_json(
+            &read_to_string(
+                "./tests/fixtures/spl_token_metaplex_metadata_legacy_account.json",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    let collection_sized_mint = Pubkey::new_unique();
+    let mut collection_sized_data = collection_legacy_account.data.clone();
+    collection_sized_data.extend_from_slice(&[0, 0, 0, 0, 1, 0]);
+    collection_sized_data.extend_from_slice(&0u64.to_le_bytes());
+    // Initialize the endpoint with the metadata accounts
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new()
+            .with_builtin_program(toolbox_endpoint_program_test_builtin_program!(
+                "metaplex",
+                ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+                metaplex_program_entry
+            ))
+            .with_genesis_account_json_path(
+                "./tests/fixtures/spl_token_metaplex_metadata_account.json",
+            )
+            .with_genesis_account_json_path(
+                "./tests/fixtures/spl_token_metaplex_metadata_legacy_account.json",
+            )
+            .with_genesis_account(
+                ToolboxEndpoint::find_spl_token_metaplex_metadata(
+                    &collection_sized_mint,
+                ),
+                Account {
+                    data: collection_sized_data,
+                    ..collection_legacy_account
+                },
+            ),
+    )
+    .await;
+    let logger_history = ToolboxEndpointLoggerHistory::new();
+    endpoint.add_logger(Box::new(logger_history.clone()));
+    // Prepare the actors
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let collection_authority = Keypair::new();
+    // Verifying into an unsized collection uses the legacy instruction
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_and_verify(
+            &payer,
+            &mint,
+            &collection_mint,
+            &collection_authority,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        last_processed_instruction(&logger_history),
+        Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(
+                    ToolboxEndpoint::find_spl_token_metaplex_metadata(&mint),
+                    false
+                ),
+                AccountMeta::new(collection_authority.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(update_authority, false),
+                AccountMeta::new_readonly(collection_mint, false),
+                AccountMeta::new_readonly(
+                    ToolboxEndpoint::find_spl_token_metaplex_metadata(
+                        &collection_mint
+                    ),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    ToolboxEndpoint::find_spl_token_metaplex_edition(
+                        &collection_mint
+                    ),
+                    false
+                ),
+            ],
+            data: vec![25],
+        }
+    );
+    // Verifying into a sized collection updates the collection accounts
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_and_verify(
+            &payer,
+            &mint,
+            &collection_sized_mint,
+            &collection_authority,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        last_processed_instruction(&logger_history),
+        Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(
+                    ToolboxEndpoint::find_spl_token_metaplex_metadata(&mint),
+                    false
+                ),
+                AccountMeta::new(collection_authority.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(update_authority, false),
+                AccountMeta::new_readonly(collection_sized_mint, false),
+                AccountMeta::new(
+                    ToolboxEndpoint::find_spl_token_metaplex_metadata(
+                        &collection_sized_mint
+                    ),
+                    false
+                ),
+                AccountMeta::new(
+                    ToolboxEndpoint::find_spl_token_metaplex_edition(
+                        &collection_sized_mint
+                    ),
+                    false
+                ),
+            ],
+            data: vec![32],
+        }
+    );
+    // Setting the collection size
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_size(
+            &payer,
+            &collection_mint,
+            &collection_authority,
+            42,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        last_processed_instruction(&logger_history),
+        Instruction {
+            program_id: ToolboxEndpoint::SPL_TOKEN_METAPLEX_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(
+                    ToolboxEndpoint::find_spl_token_metaplex_metadata(
+                        &collection_mint
+                    ),
+                    false
+                ),
+                AccountMeta::new(collection_authority.pubkey(), true),
+                AccountMeta::new_readonly(collection_mint, false),
+            ],
+            data: vec![34, 42, 0, 0, 0, 0, 0, 0, 0],
+        }
+    );
+    // Verifying requires the collection metadata to exist
+    endpoint
+        .process_spl_token_metaplex_metadata_collection_set_and_verify(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            &collection_authority,
+        )
+        .await
+        .unwrap_err();
+}
+
+fn last_processed_instruction(
+    logger_history: &ToolboxEndpointLoggerHistory,
+) -> Instruction {
+    logger_history
+        .get_processed()
+        .last()
+        .unwrap()
+        .1
+        .instructions
+        .last()
+        .unwrap()
+        .clone()
+}
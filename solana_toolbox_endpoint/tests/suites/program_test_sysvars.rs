@@ -1,4 +1,16 @@
+use solana_sdk::account::Account;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::stake_history::StakeHistoryEntry;
+use solana_sdk::sysvar::epoch_rewards::EpochRewards;
+use solana_sdk::sysvar::epoch_schedule::EpochSchedule;
+use solana_sdk::sysvar::instructions::construct_instructions_data;
+use solana_sdk::sysvar::instructions::BorrowedAccountMeta;
+use solana_sdk::sysvar::instructions::BorrowedInstruction;
 use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointProgramTestOptions;
 
 #[tokio::test]
 pub async fn run() {
@@ -23,4 +35,152 @@ pub async fn run() {
     let slot_hashes = endpoint.get_sysvar_slot_hashes().await.unwrap();
     assert_eq!(1, slot_hashes.len());
     assert_eq!(0, slot_hashes.first().unwrap().0);
+    // Read the other sysvars and check the default values
+    let epoch_schedule = endpoint.get_sysvar_epoch_schedule().await.unwrap();
+    assert_eq!(EpochSchedule::default(), epoch_schedule);
+    let epoch_rewards = endpoint.get_sysvar_epoch_rewards().await.unwrap();
+    assert!(!epoch_rewards.active);
+    let stake_history = endpoint.get_sysvar_stake_history().await.unwrap();
+    assert!(stake_history.is_empty());
+    let last_restart_slot =
+        endpoint.get_sysvar_last_restart_slot().await.unwrap();
+    assert_eq!(0, last_restart_slot);
+    let recent_blockhashes =
+        endpoint.get_sysvar_recent_blockhashes().await.unwrap();
+    assert_eq!(
+        endpoint.get_latest_blockhash().await.unwrap(),
+        recent_blockhashes.first().unwrap().0
+    );
+    // The fees sysvar is deprecated and not created on recent clusters
+    endpoint
+        .get_sysvar_fees_lamports_per_signature()
+        .await
+        .unwrap_err();
+    // Set custom sysvars values and read them back
+    let custom_epoch_schedule = EpochSchedule::custom(64, 64, false);
+    endpoint
+        .set_sysvar_epoch_schedule(&custom_epoch_schedule)
+        .await
+        .unwrap();
+    assert_eq!(
+        custom_epoch_schedule,
+        endpoint.get_sysvar_epoch_schedule().await.unwrap()
+    );
+    let custom_epoch_rewards = EpochRewards {
+        distribution_starting_block_height: 42,
+        num_partitions: 2,
+        parent_blockhash: Hash::new_unique(),
+        total_points: 1_000,
+        total_rewards: 2_000,
+        distributed_rewards: 500,
+        active: true,
+    };
+    endpoint
+        .set_sysvar_epoch_rewards(&custom_epoch_rewards)
+        .await
+        .unwrap();
+    assert_eq!(
+        custom_epoch_rewards,
+        endpoint.get_sysvar_epoch_rewards().await.unwrap()
+    );
+    let custom_stake_history = vec![
+        (2, StakeHistoryEntry::with_effective_and_activating(300, 30)),
+        (1, StakeHistoryEntry::with_effective(200)),
+        (0, StakeHistoryEntry::with_deactivating(100)),
+    ];
+    endpoint
+        .set_sysvar_stake_history(&[
+            custom_stake_history[1].clone(),
+            custom_stake_history[2].clone(),
+            custom_stake_history[0].clone(),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(
+        custom_stake_history,
+        endpoint.get_sysvar_stake_history().await.unwrap()
+    );
+    endpoint.set_sysvar_last_restart_slot(77).await.unwrap();
+    assert_eq!(77, endpoint.get_sysvar_last_restart_slot().await.unwrap());
+    let mut custom_clock = endpoint.get_sysvar_clock().await.unwrap();
+    custom_clock.unix_timestamp = 1_234_567;
+    endpoint.set_sysvar_clock(&custom_clock).await.unwrap();
+    assert_eq!(custom_clock, endpoint.get_sysvar_clock().await.unwrap());
+    // Unsupported sysvars cannot be set
+    endpoint
+        .set_sysvar_account(&ToolboxEndpoint::SYSVAR_INSTRUCTIONS_ID, &[])
+        .await
+        .unwrap_err();
+    // Parse an instructions sysvar data as it would be seen by a program
+    let program_id = Pubkey::new_unique();
+    let account1 = Pubkey::new_unique();
+    let account2 = Pubkey::new_unique();
+    let mut instructions_data = construct_instructions_data(&[
+        BorrowedInstruction {
+            program_id: &program_id,
+            accounts: vec![
+                BorrowedAccountMeta {
+                    pubkey: &account1,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                BorrowedAccountMeta {
+                    pubkey: &account2,
+                    is_signer: false,
+                    is_writable: true,
+                },
+            ],
+            data: &[1, 2, 3],
+        },
+        BorrowedInstruction {
+            program_id: &program_id,
+            accounts: vec![],
+            data: &[],
+        },
+    ]);
+    let instructions_data_len = instructions_data.len();
+    instructions_data[instructions_data_len - 2..]
+        .copy_from_slice(&1u16.to_le_bytes());
+    assert_eq!(
+        (
+            1,
+            vec![
+                Instruction {
+                    program_id,
+                    accounts: vec![
+                        AccountMeta::new_readonly(account1, true),
+                        AccountMeta::new(account2, false),
+                    ],
+                    data: vec![1, 2, 3],
+                },
+                Instruction {
+                    program_id,
+                    accounts: vec![],
+                    data: vec![],
+                },
+            ]
+        ),
+        ToolboxEndpoint::parse_sysvar_instructions(&instructions_data).unwrap()
+    );
+    // Read a fees sysvar that was created at genesis
+    let mut endpoint = ToolboxEndpoint::new_program_test_with_options(
+        ToolboxEndpointProgramTestOptions::new().with_genesis_account(
+            ToolboxEndpoint::SYSVAR_FEES_ID,
+            Account {
+                lamports: 1_000_000,
+                data: bincode::serialize(&4_242u64).unwrap(),
+                owner: ToolboxEndpoint::SYSVAR_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
+    )
+    .await;
+    assert_eq!(
+        4_242,
+        endpoint
+            .get_sysvar_fees_lamports_per_signature()
+            .await
+            .unwrap()
+    );
 }
@@ -0,0 +1,81 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    let epoch_schedule = endpoint.get_sysvar_epoch_schedule().await.unwrap();
+    // Set an absolute unix timestamp without moving the slot
+    let clock_01 = endpoint.get_sysvar_clock().await.unwrap();
+    endpoint
+        .set_clock_unix_timestamp(clock_01.unix_timestamp + 1_000)
+        .await
+        .unwrap();
+    let clock_02 = endpoint.get_sysvar_clock().await.unwrap();
+    assert_eq!(clock_01.slot, clock_02.slot);
+    assert_eq!(clock_01.unix_timestamp + 1_000, clock_02.unix_timestamp);
+    assert_eq!(
+        clock_02.unix_timestamp,
+        endpoint
+            .get_slot_unix_timestamp(clock_02.slot)
+            .await
+            .unwrap()
+    );
+    // Warp to a specific slot
+    let warp_slot = 1_000;
+    endpoint.warp_to_slot(warp_slot).await.unwrap();
+    let clock_03 = endpoint.get_sysvar_clock().await.unwrap();
+    assert_eq!(warp_slot, clock_03.slot);
+    assert_eq!(epoch_schedule.get_epoch(warp_slot), clock_03.epoch);
+    assert_eq!(
+        epoch_schedule.get_leader_schedule_epoch(warp_slot),
+        clock_03.leader_schedule_epoch
+    );
+    // The memnet clock follows the genesis ticks per slot (400ms per slot)
+    let ms_per_slot = 400;
+    let warp_elapsed_seconds =
+        (warp_slot - clock_02.slot) * ms_per_slot / 1_000;
+    assert_eq!(
+        clock_02.unix_timestamp + i64::try_from(warp_elapsed_seconds).unwrap(),
+        clock_03.unix_timestamp
+    );
+    let epoch_elapsed_seconds = (warp_slot
+        - epoch_schedule.get_first_slot_in_epoch(clock_03.epoch))
+        * ms_per_slot
+        / 1_000;
+    assert_eq!(
+        clock_03.unix_timestamp - i64::try_from(epoch_elapsed_seconds).unwrap(),
+        clock_03.epoch_start_timestamp
+    );
+    assert_eq!(
+        clock_03.unix_timestamp,
+        endpoint.get_slot_unix_timestamp(warp_slot).await.unwrap()
+    );
+    let slot_hashes = endpoint.get_sysvar_slot_hashes().await.unwrap();
+    assert_eq!(warp_slot - 1, slot_hashes[0].0);
+    // Warping backward must fail
+    endpoint.warp_to_slot(warp_slot).await.unwrap_err();
+    endpoint.warp_to_slot(warp_slot / 2).await.unwrap_err();
+    // Warp to a specific epoch
+    endpoint.warp_to_epoch(20).await.unwrap();
+    let clock_04 = endpoint.get_sysvar_clock().await.unwrap();
+    assert_eq!(epoch_schedule.get_first_slot_in_epoch(20), clock_04.slot);
+    assert_eq!(20, clock_04.epoch);
+    assert_eq!(21, clock_04.leader_schedule_epoch);
+    assert_eq!(clock_04.unix_timestamp, clock_04.epoch_start_timestamp);
+    // Transactions must still be processed properly after warping
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let destination = Pubkey::new_unique();
+    endpoint
+        .process_system_transfer(&payer, &payer, &destination, 1_000_000)
+        .await
+        .unwrap();
+    assert_eq!(1_000_000, endpoint.get_balance(&destination).await.unwrap());
+}
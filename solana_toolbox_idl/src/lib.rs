@@ -24,12 +24,15 @@ mod toolbox_idl_instruction_payload;
 mod toolbox_idl_path;
 mod toolbox_idl_path_json;
 mod toolbox_idl_path_type;
+mod toolbox_idl_printer;
 mod toolbox_idl_program;
 mod toolbox_idl_program_export;
 mod toolbox_idl_program_lookup;
 mod toolbox_idl_program_parse;
+mod toolbox_idl_program_parse_codama;
 mod toolbox_idl_service;
 mod toolbox_idl_service_account;
+mod toolbox_idl_service_execution;
 mod toolbox_idl_service_instruction;
 mod toolbox_idl_type_flat;
 mod toolbox_idl_type_flat_export;
@@ -49,20 +52,23 @@ mod toolbox_idl_typedef_export;
 mod toolbox_idl_typedef_parse;
 mod toolbox_idl_utils;
 
-// TODO (FAR) - handle events from logs ?
 // TODO (FAR) - support for uploading IDLs ?
 
 pub use toolbox_idl_account::ToolboxIdlAccount;
 pub use toolbox_idl_error::ToolboxIdlError;
+pub use toolbox_idl_event::ToolboxIdlEvent;
 pub use toolbox_idl_format::ToolboxIdlFormat;
 pub use toolbox_idl_instruction::ToolboxIdlInstruction;
 pub use toolbox_idl_instruction_account::ToolboxIdlInstructionAccount;
 pub use toolbox_idl_instruction_account::ToolboxIdlInstructionAccountPda;
 pub use toolbox_idl_instruction_account::ToolboxIdlInstructionAccountPdaBlob;
 pub use toolbox_idl_path::ToolboxIdlPath;
+pub use toolbox_idl_printer::ToolboxIdlPrinter;
 pub use toolbox_idl_program::ToolboxIdlProgram;
 pub use toolbox_idl_service::ToolboxIdlService;
 pub use toolbox_idl_service_account::ToolboxIdlServiceAccountInfo;
+pub use toolbox_idl_service_execution::ToolboxIdlServiceErrorInfo;
+pub use toolbox_idl_service_execution::ToolboxIdlServiceEventInfo;
 pub use toolbox_idl_service_instruction::ToolboxIdlServiceInstructionInfo;
 pub use toolbox_idl_type_flat::ToolboxIdlTypeFlat;
 pub use toolbox_idl_type_flat::ToolboxIdlTypeFlatEnumVariant;
@@ -27,6 +27,10 @@ impl Default for ToolboxIdlEvent {
 }
 
 impl ToolboxIdlEvent {
+    // Prefix of the self-CPI instruction data used by anchor's emit_cpi!
+    pub const CPI_TAG: &[u8] =
+        &[0xE4, 0x45, 0xA5, 0x2E, 0x51, 0xCB, 0x9A, 0x1D];
+
     pub fn encode(&self, event_message: &Value) -> Result<Vec<u8>> {
         let mut event_data = vec![];
         event_data.extend_from_slice(&self.discriminator);
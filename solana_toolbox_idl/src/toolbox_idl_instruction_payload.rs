@@ -37,4 +37,12 @@ impl ToolboxIdlInstruction {
             .context("Deserialize Payload")?;
         Ok(instruction_payload)
     }
+
+    pub fn decode_return(&self, return_data: &[u8]) -> Result<Value> {
+        let (_, return_value) = self
+            .return_type_full
+            .try_deserialize(return_data, 0)
+            .context("Deserialize Return")?;
+        Ok(return_value)
+    }
 }
@@ -54,7 +54,8 @@ impl ToolboxIdlPath {
                         .with_context(|| format!("Generic: {}", symbol))?;
                 self.try_get_type_flat(generic, generics_by_symbol, typedefs)
             },
-            ToolboxIdlTypeFlat::Option { content, .. } => {
+            ToolboxIdlTypeFlat::Option { content, .. }
+            | ToolboxIdlTypeFlat::OptionZeroable { content } => {
                 self.try_get_type_flat(content, generics_by_symbol, typedefs)
             },
             ToolboxIdlTypeFlat::Vec { items, .. } => {
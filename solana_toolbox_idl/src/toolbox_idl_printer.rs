@@ -0,0 +1,603 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use solana_sdk::account::Account;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStep;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStepCall;
+use solana_toolbox_endpoint::ToolboxEndpointLogger;
+
+use crate::toolbox_idl_program::ToolboxIdlProgram;
+use crate::toolbox_idl_service::ToolboxIdlService;
+
+#[derive(Default)]
+pub struct ToolboxIdlPrinter {
+    idl_service: ToolboxIdlService,
+}
+
+struct ToolboxIdlPrinterPrograms(
+    HashMap<Pubkey, Option<Arc<ToolboxIdlProgram>>>,
+);
+
+struct ToolboxIdlPrinterNode {
+    label: String,
+    children: Vec<ToolboxIdlPrinterNode>,
+}
+
+impl ToolboxIdlPrinterNode {
+    fn leaf(label: String) -> ToolboxIdlPrinterNode {
+        ToolboxIdlPrinterNode {
+            label,
+            children: vec![],
+        }
+    }
+
+    fn render(&self, prefix_label: &str, prefix_children: &str) -> Vec<String> {
+        let mut lines = vec![format!("{}{}", prefix_label, self.label)];
+        for (index, child) in self.children.iter().enumerate() {
+            let (child_prefix_label, child_prefix_children) =
+                if index + 1 == self.children.len() {
+                    ("└─ ", "   ")
+                } else {
+                    ("├─ ", "│  ")
+                };
+            lines.extend(child.render(
+                &format!("{}{}", prefix_children, child_prefix_label),
+                &format!("{}{}", prefix_children, child_prefix_children),
+            ));
+        }
+        lines
+    }
+}
+
+#[async_trait::async_trait]
+impl ToolboxEndpointLogger for ToolboxIdlPrinter {
+    async fn on_processed(
+        &self,
+        processed: &(Signature, ToolboxEndpointExecution),
+    ) {
+        // Loggers have no endpoint, only known and builtin programs are used
+        let mut idl_programs = HashMap::new();
+        for program_id in ToolboxIdlPrinter::collect_execution_programs_ids(
+            &processed.1,
+            &HashMap::new(),
+        ) {
+            idl_programs.insert(
+                program_id,
+                self.idl_service.get_cached_or_lib_program(&program_id),
+            );
+        }
+        println!("signature: {}", processed.0);
+        for line in ToolboxIdlPrinterPrograms(idl_programs)
+            .render_execution(&processed.1, &HashMap::new())
+        {
+            println!("{}", line);
+        }
+        println!();
+    }
+}
+
+impl ToolboxIdlPrinter {
+    pub fn new() -> ToolboxIdlPrinter {
+        ToolboxIdlPrinter::default()
+    }
+
+    pub fn set_program(
+        &mut self,
+        program_id: &Pubkey,
+        idl_program: Option<Arc<ToolboxIdlProgram>>,
+    ) {
+        self.idl_service.set_program(program_id, idl_program);
+    }
+
+    pub async fn print_execution(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<()> {
+        for line in self
+            .render_execution(endpoint, execution, &HashMap::new())
+            .await?
+        {
+            println!("{}", line);
+        }
+        Ok(())
+    }
+
+    // Accounts states are fetched after the fact, so they are the latest known
+    pub async fn print_execution_with_accounts_states(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<()> {
+        let mut addresses = vec![];
+        let mut addresses_known = HashSet::new();
+        for instruction in &execution.instructions {
+            for account_meta in &instruction.accounts {
+                if addresses_known.insert(account_meta.pubkey) {
+                    addresses.push(account_meta.pubkey);
+                }
+            }
+        }
+        let mut accounts_states = HashMap::new();
+        for (address, account) in addresses
+            .iter()
+            .zip(endpoint.get_accounts(&addresses).await?)
+        {
+            if let Some(account) = account {
+                accounts_states.insert(*address, account);
+            }
+        }
+        for line in self
+            .render_execution(endpoint, execution, &accounts_states)
+            .await?
+        {
+            println!("{}", line);
+        }
+        Ok(())
+    }
+
+    pub async fn print_account(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        address: &Pubkey,
+        account: &Option<Account>,
+    ) -> Result<()> {
+        for line in self.render_account(endpoint, address, account).await? {
+            println!("{}", line);
+        }
+        Ok(())
+    }
+
+    pub async fn render_execution(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+        accounts_states: &HashMap<Pubkey, Account>,
+    ) -> Result<Vec<String>> {
+        let idl_programs = self
+            .resolve_programs(
+                endpoint,
+                ToolboxIdlPrinter::collect_execution_programs_ids(
+                    execution,
+                    accounts_states,
+                ),
+            )
+            .await?;
+        Ok(idl_programs.render_execution(execution, accounts_states))
+    }
+
+    pub async fn render_account(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        address: &Pubkey,
+        account: &Option<Account>,
+    ) -> Result<Vec<String>> {
+        let account = account.clone().unwrap_or_default();
+        let idl_programs =
+            self.resolve_programs(endpoint, vec![account.owner]).await?;
+        Ok(idl_programs.render_account(address, &account))
+    }
+
+    async fn resolve_programs(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        programs_ids: Vec<Pubkey>,
+    ) -> Result<ToolboxIdlPrinterPrograms> {
+        let mut idl_programs = HashMap::new();
+        for program_id in programs_ids {
+            if idl_programs.contains_key(&program_id) {
+                continue;
+            }
+            let idl_program = self
+                .idl_service
+                .get_or_resolve_program(endpoint, &program_id)
+                .await
+                .context("Resolve Program")?;
+            idl_programs.insert(program_id, idl_program);
+        }
+        Ok(ToolboxIdlPrinterPrograms(idl_programs))
+    }
+
+    fn collect_execution_programs_ids(
+        execution: &ToolboxEndpointExecution,
+        accounts_states: &HashMap<Pubkey, Account>,
+    ) -> Vec<Pubkey> {
+        let mut programs_ids = vec![];
+        for instruction in &execution.instructions {
+            programs_ids.push(instruction.program_id);
+        }
+        ToolboxIdlPrinter::collect_steps_programs_ids(
+            execution.steps.iter().flatten(),
+            &mut programs_ids,
+        );
+        for account in accounts_states.values() {
+            programs_ids.push(account.owner);
+        }
+        programs_ids
+    }
+
+    fn collect_steps_programs_ids<'a>(
+        steps: impl Iterator<Item = &'a ToolboxEndpointExecutionStep>,
+        programs_ids: &mut Vec<Pubkey>,
+    ) {
+        for step in steps {
+            if let ToolboxEndpointExecutionStep::Call(step_call) = step {
+                programs_ids.push(step_call.program_id);
+                ToolboxIdlPrinter::collect_steps_programs_ids(
+                    step_call.steps.iter(),
+                    programs_ids,
+                );
+            }
+        }
+    }
+}
+
+impl ToolboxIdlPrinterPrograms {
+    fn get_program(
+        &self,
+        program_id: &Pubkey,
+    ) -> Option<Arc<ToolboxIdlProgram>> {
+        self.0.get(program_id).cloned().flatten()
+    }
+
+    fn render_execution(
+        &self,
+        execution: &ToolboxEndpointExecution,
+        accounts_states: &HashMap<Pubkey, Account>,
+    ) -> Vec<String> {
+        let mut cpis_events_datas = HashMap::new();
+        for (instruction_index, inner_instructions) in
+            execution.inner_instructions.iter().flatten().enumerate()
+        {
+            ToolboxIdlService::collect_inner_instructions_events_datas(
+                instruction_index,
+                execution.instructions.get(instruction_index),
+                inner_instructions,
+                &mut cpis_events_datas,
+            );
+        }
+        let steps_calls =
+            ToolboxIdlService::find_instructions_steps_calls(execution);
+        let mut children = vec![];
+        for (instruction_index, (instruction, step_call)) in
+            execution.instructions.iter().zip(steps_calls).enumerate()
+        {
+            children.push(self.compute_instruction_node(
+                instruction_index,
+                instruction,
+                step_call,
+                accounts_states,
+                &mut cpis_events_datas,
+            ));
+        }
+        children.push(match &execution.error {
+            None => ToolboxIdlPrinterNode::leaf("result: Ok".to_string()),
+            Some(error) => ToolboxIdlPrinterNode {
+                label: format!("result: Err({:?})", error),
+                children: ToolboxIdlService::find_execution_error_call(
+                    execution,
+                )
+                .and_then(|(_, error_code, program_id, step_call)| {
+                    self.compute_error_name(&program_id, step_call, error_code)
+                })
+                .map(|error_name| {
+                    ToolboxIdlPrinterNode::leaf(format!(
+                        "error: {}",
+                        error_name
+                    ))
+                })
+                .into_iter()
+                .collect(),
+            },
+        });
+        if let Some(units_consumed) = execution.units_consumed {
+            children.push(ToolboxIdlPrinterNode::leaf(format!(
+                "units_consumed: {}",
+                units_consumed
+            )));
+        }
+        ToolboxIdlPrinterNode {
+            label: format!(
+                "execution: slot: {}, payer: {}",
+                execution.slot, execution.payer
+            ),
+            children,
+        }
+        .render("", "")
+    }
+
+    fn render_account(
+        &self,
+        address: &Pubkey,
+        account: &Account,
+    ) -> Vec<String> {
+        ToolboxIdlPrinterNode {
+            label: format!("account: {}", address),
+            children: vec![
+                ToolboxIdlPrinterNode::leaf(format!(
+                    "lamports: {}",
+                    account.lamports
+                )),
+                ToolboxIdlPrinterNode::leaf(format!(
+                    "owner: {}",
+                    self.compute_program_name(&account.owner)
+                )),
+                ToolboxIdlPrinterNode::leaf(format!(
+                    "executable: {}",
+                    account.executable
+                )),
+                ToolboxIdlPrinterNode::leaf(
+                    self.compute_account_state(account),
+                ),
+            ],
+        }
+        .render("", "")
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn compute_instruction_node(
+        &self,
+        instruction_index: usize,
+        instruction: &Instruction,
+        step_call: Option<&ToolboxEndpointExecutionStepCall>,
+        accounts_states: &HashMap<Pubkey, Account>,
+        cpis_events_datas: &mut HashMap<(usize, usize), (Vec<Pubkey>, &[u8])>,
+    ) -> ToolboxIdlPrinterNode {
+        let program_name = self.compute_program_name(&instruction.program_id);
+        let idl_instruction = self
+            .get_program(&instruction.program_id)
+            .and_then(|idl_program| {
+                idl_program.guess_instruction(&instruction.data)
+            });
+        let decoded = idl_instruction.as_ref().and_then(|idl_instruction| {
+            idl_instruction.decode(instruction).ok()
+        });
+        let mut accounts_names = HashMap::<Pubkey, Vec<String>>::new();
+        if let (Some(idl_instruction), Some((_, _, instruction_addresses))) =
+            (&idl_instruction, &decoded)
+        {
+            for idl_instruction_account in &idl_instruction.accounts {
+                if let Some(address) =
+                    instruction_addresses.get(&idl_instruction_account.name)
+                {
+                    accounts_names
+                        .entry(*address)
+                        .or_default()
+                        .push(idl_instruction_account.name.to_string());
+                }
+            }
+        }
+        let mut children = vec![];
+        let mut accounts_children = vec![];
+        for (account_index, account_meta) in
+            instruction.accounts.iter().enumerate()
+        {
+            let account_names = accounts_names
+                .get(&account_meta.pubkey)
+                .map(|account_names| account_names.join("/"))
+                .unwrap_or_else(|| "?".to_string());
+            accounts_children.push(ToolboxIdlPrinterNode {
+                label: format!(
+                    "#{:03}: {}: {} ({}{})",
+                    account_index + 1,
+                    account_names,
+                    account_meta.pubkey,
+                    if account_meta.is_writable { "W" } else { "R" },
+                    if account_meta.is_signer { "S" } else { "-" },
+                ),
+                children: accounts_states
+                    .get(&account_meta.pubkey)
+                    .map(|account| {
+                        ToolboxIdlPrinterNode::leaf(
+                            self.compute_account_state(account),
+                        )
+                    })
+                    .into_iter()
+                    .collect(),
+            });
+        }
+        children.push(ToolboxIdlPrinterNode {
+            label: "accounts".to_string(),
+            children: accounts_children,
+        });
+        children.push(ToolboxIdlPrinterNode::leaf(match &decoded {
+            Some((_, instruction_payload, _)) => {
+                format!("payload: {}", instruction_payload)
+            },
+            None => format!(
+                "data: {}",
+                ToolboxEndpoint::encode_base16(&instruction.data)
+            ),
+        }));
+        if let Some(step_call) = step_call {
+            children.extend(self.compute_step_call_children(
+                instruction_index,
+                &mut 0,
+                step_call,
+                cpis_events_datas,
+            ));
+        }
+        // Self-CPIs that could not be located in the logs go last
+        let mut inner_indexes = cpis_events_datas
+            .keys()
+            .filter(|key| key.0 == instruction_index)
+            .map(|key| key.1)
+            .collect::<Vec<_>>();
+        inner_indexes.sort();
+        for inner_index in inner_indexes {
+            if let Some((cpi_path, data)) =
+                cpis_events_datas.remove(&(instruction_index, inner_index))
+            {
+                if let Some(program_id) = cpi_path.last() {
+                    children.push(ToolboxIdlPrinterNode::leaf(
+                        self.compute_event_label(program_id, data),
+                    ));
+                }
+            }
+        }
+        ToolboxIdlPrinterNode {
+            label: format!(
+                "instruction #{}: {}.{}",
+                instruction_index + 1,
+                program_name,
+                idl_instruction
+                    .map(|idl_instruction| idl_instruction.name.clone())
+                    .unwrap_or_else(|| "?".to_string()),
+            ),
+            children,
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn compute_step_call_children(
+        &self,
+        instruction_index: usize,
+        inner_index: &mut usize,
+        step_call: &ToolboxEndpointExecutionStepCall,
+        cpis_events_datas: &mut HashMap<(usize, usize), (Vec<Pubkey>, &[u8])>,
+    ) -> Vec<ToolboxIdlPrinterNode> {
+        let mut children = vec![];
+        for step in &step_call.steps {
+            children.push(match step {
+                ToolboxEndpointExecutionStep::Unknown(log) => {
+                    ToolboxIdlPrinterNode::leaf(format!("unknown: {}", log))
+                },
+                ToolboxEndpointExecutionStep::Log(log) => {
+                    ToolboxIdlPrinterNode::leaf(format!("log: {}", log))
+                },
+                ToolboxEndpointExecutionStep::Data(data) => {
+                    ToolboxIdlPrinterNode::leaf(
+                        self.compute_event_label(&step_call.program_id, data),
+                    )
+                },
+                ToolboxEndpointExecutionStep::Call(step_call) => {
+                    // Self-CPI events (emit_cpi!) are the data of the invoke
+                    let mut invoke_children = vec![];
+                    if let Some((cpi_path, data)) = cpis_events_datas
+                        .remove(&(instruction_index, *inner_index))
+                    {
+                        if let Some(program_id) = cpi_path.last() {
+                            invoke_children.push(ToolboxIdlPrinterNode::leaf(
+                                self.compute_event_label(program_id, data),
+                            ));
+                        }
+                    }
+                    *inner_index += 1;
+                    invoke_children.extend(self.compute_step_call_children(
+                        instruction_index,
+                        inner_index,
+                        step_call,
+                        cpis_events_datas,
+                    ));
+                    ToolboxIdlPrinterNode {
+                        label: format!(
+                            "invoke: {}",
+                            self.compute_program_name(&step_call.program_id)
+                        ),
+                        children: invoke_children,
+                    }
+                },
+            });
+        }
+        if let Some(returns) = &step_call.returns {
+            children.push(ToolboxIdlPrinterNode::leaf(format!(
+                "returns: {}",
+                ToolboxEndpoint::encode_base16(returns)
+            )));
+        }
+        if let Some((consumed, budget)) = step_call.consumed {
+            children.push(ToolboxIdlPrinterNode::leaf(format!(
+                "consumed: {} of {} compute units",
+                consumed, budget
+            )));
+        }
+        if let Some(failure) = &step_call.failure {
+            let error_name = failure
+                .strip_prefix("custom program error: 0x")
+                .and_then(|error_code| u64::from_str_radix(error_code, 16).ok())
+                .and_then(|error_code| {
+                    self.compute_error_name(
+                        &step_call.program_id,
+                        Some(step_call),
+                        error_code,
+                    )
+                });
+            children.push(ToolboxIdlPrinterNode::leaf(match error_name {
+                Some(error_name) => {
+                    format!("failure: {} ({})", failure, error_name)
+                },
+                None => format!("failure: {}", failure),
+            }));
+        }
+        children
+    }
+
+    fn compute_account_state(&self, account: &Account) -> String {
+        let program_name = self.compute_program_name(&account.owner);
+        match self.get_program(&account.owner).and_then(|idl_program| {
+            let idl_account = idl_program.guess_account(&account.data)?;
+            let account_state = idl_account.decode(&account.data).ok()?;
+            Some((idl_account, account_state))
+        }) {
+            Some((idl_account, account_state)) => format!(
+                "state: {}.{}: {}",
+                program_name, idl_account.name, account_state
+            ),
+            None => format!(
+                "state: {}.?: {} bytes",
+                program_name,
+                account.data.len()
+            ),
+        }
+    }
+
+    fn compute_event_label(&self, program_id: &Pubkey, data: &[u8]) -> String {
+        let program_name = self.compute_program_name(program_id);
+        match self.get_program(program_id).and_then(|idl_program| {
+            let idl_event = idl_program.guess_event(data)?;
+            let event_value = idl_event.decode(data).ok()?;
+            Some((idl_event, event_value))
+        }) {
+            Some((idl_event, event_value)) => format!(
+                "event: {}.{}: {}",
+                program_name, idl_event.name, event_value
+            ),
+            None => format!("data: {}", ToolboxEndpoint::encode_base16(data)),
+        }
+    }
+
+    // Same lookup as the service: program IDL, anchor framework, then logs
+    fn compute_error_name(
+        &self,
+        program_id: &Pubkey,
+        step_call: Option<&ToolboxEndpointExecutionStepCall>,
+        error_code: u64,
+    ) -> Option<String> {
+        let idl_error = ToolboxIdlService::guess_execution_error(
+            self.get_program(program_id).as_deref(),
+            step_call,
+            error_code,
+        )?;
+        let program_name = self.compute_program_name(program_id);
+        Some(match &idl_error.msg {
+            Some(error_msg) => {
+                format!("{}.{}: {}", program_name, idl_error.name, error_msg)
+            },
+            None => format!("{}.{}", program_name, idl_error.name),
+        })
+    }
+
+    fn compute_program_name(&self, program_id: &Pubkey) -> String {
+        self.get_program(program_id)
+            .and_then(|idl_program| idl_program.metadata.name.clone())
+            .unwrap_or_else(|| program_id.to_string())
+    }
+}
@@ -28,6 +28,7 @@ pub struct ToolboxIdlProgram {
     pub instructions: HashMap<String, Arc<ToolboxIdlInstruction>>,
     pub events: HashMap<String, Arc<ToolboxIdlEvent>>,
     pub errors: HashMap<String, Arc<ToolboxIdlError>>,
+    pub warnings: Vec<String>,
 }
 
 impl ToolboxIdlProgram {
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -6,8 +8,20 @@ use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 
+use crate::toolbox_idl_error::ToolboxIdlError;
 use crate::toolbox_idl_program::ToolboxIdlProgram;
 
+static ANCHOR_FRAMEWORK_PROGRAM: LazyLock<Arc<ToolboxIdlProgram>> =
+    LazyLock::new(|| {
+        Arc::new(
+            ToolboxIdlProgram::try_parse_from_str(include_str!(
+                "lib/anchor_framework.json"
+            ))
+            .context("Anchor Framework")
+            .unwrap(),
+        )
+    });
+
 impl ToolboxIdlProgram {
     pub fn from_lib(program_id: &Pubkey) -> Option<ToolboxIdlProgram> {
         let mut known_programs = HashMap::new();
@@ -58,6 +72,24 @@ impl ToolboxIdlProgram {
         })
     }
 
+    // Anchor's framework errors can only be raised by Anchor programs, so
+    // programs known to use other instruction discriminators are excluded
+    pub fn guess_anchor_framework_error(
+        idl_program: Option<&ToolboxIdlProgram>,
+        error_code: u64,
+    ) -> Option<Arc<ToolboxIdlError>> {
+        if let Some(idl_program) = idl_program {
+            if !idl_program
+                .instructions
+                .values()
+                .all(|idl_instruction| idl_instruction.discriminator.len() == 8)
+            {
+                return None;
+            }
+        }
+        ANCHOR_FRAMEWORK_PROGRAM.guess_error(error_code)
+    }
+
     pub fn find_anchor_address(program_id: &Pubkey) -> Result<Pubkey> {
         let base = Pubkey::find_program_address(&[], program_id).0;
         Ok(Pubkey::create_with_seed(&base, "anchor:idl", program_id)?)
@@ -63,6 +63,10 @@ impl ToolboxIdlProgram {
 
     pub fn try_parse(value: &Value) -> Result<ToolboxIdlProgram> {
         let idl_root = idl_value_as_object_or_else(value).context("Root")?;
+        if idl_object_get_key_as_str(idl_root, "kind") == Some("rootNode") {
+            return ToolboxIdlProgram::try_parse_codama(value)
+                .context("Parse Codama");
+        }
         let metadata = ToolboxIdlProgram::try_parse_metadata(idl_root)?;
         let typedefs = ToolboxIdlProgram::try_parse_typedefs(idl_root)
             .context("Parse Types")?;
@@ -84,6 +88,7 @@ impl ToolboxIdlProgram {
             instructions,
             errors,
             events,
+            warnings: vec![],
         })
     }
 
@@ -0,0 +1,1228 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::toolbox_idl_program::ToolboxIdlProgram;
+use crate::toolbox_idl_utils::idl_convert_to_pascal_case;
+use crate::toolbox_idl_utils::idl_convert_to_snake_case;
+use crate::toolbox_idl_utils::idl_object_get_key_as_array;
+use crate::toolbox_idl_utils::idl_object_get_key_as_bool;
+use crate::toolbox_idl_utils::idl_object_get_key_as_object;
+use crate::toolbox_idl_utils::idl_object_get_key_as_str;
+use crate::toolbox_idl_utils::idl_object_get_key_as_str_or_else;
+use crate::toolbox_idl_utils::idl_object_get_key_as_u64;
+use crate::toolbox_idl_utils::idl_object_get_key_as_u64_or_else;
+use crate::toolbox_idl_utils::idl_object_get_key_or_else;
+use crate::toolbox_idl_utils::idl_value_as_bytes_or_else;
+use crate::toolbox_idl_utils::idl_value_as_object_or_else;
+
+impl ToolboxIdlProgram {
+    pub fn try_parse_codama(value: &Value) -> Result<ToolboxIdlProgram> {
+        let codama_root = idl_value_as_object_or_else(value).context("Root")?;
+        let codama_program = idl_value_as_object_or_else(
+            idl_object_get_key_or_else(codama_root, "program")?,
+        )
+        .context("Program")?;
+        let mut idl_root = Map::new();
+        if let Some(program_id) =
+            idl_object_get_key_as_str(codama_program, "publicKey")
+        {
+            idl_root.insert("address".to_string(), json!(program_id));
+        }
+        if let Some(program_name) =
+            idl_object_get_key_as_str(codama_program, "name")
+        {
+            idl_root.insert(
+                "name".to_string(),
+                json!(idl_convert_to_snake_case(program_name)),
+            );
+        }
+        if let Some(program_version) =
+            idl_object_get_key_as_str(codama_program, "version")
+        {
+            idl_root.insert("version".to_string(), json!(program_version));
+        }
+        ToolboxIdlProgram::convert_codama_docs(codama_program, &mut idl_root);
+        // Unsupported items are skipped instead of failing the whole program
+        let mut warnings = vec![];
+        let idl_types = ToolboxIdlProgram::try_convert_codama_types(
+            codama_program,
+            &mut warnings,
+        )?;
+        let idl_types_names = idl_types.keys().cloned().collect();
+        let mut idl_accounts = Map::new();
+        for codama_account in
+            ToolboxIdlProgram::codama_nodes(codama_program, "accounts")
+        {
+            match ToolboxIdlProgram::try_convert_codama_account(
+                codama_program,
+                &idl_types_names,
+                codama_account,
+            )
+            .with_context(|| {
+                format!(
+                    "Convert Account: {}",
+                    ToolboxIdlProgram::codama_node_name(codama_account)
+                )
+            }) {
+                Ok((name, idl_account)) => {
+                    idl_accounts.insert(name, idl_account);
+                },
+                Err(error) => warnings.push(format!("{:#}", error)),
+            }
+        }
+        let mut idl_instructions = Map::new();
+        for codama_instruction in
+            ToolboxIdlProgram::codama_nodes(codama_program, "instructions")
+        {
+            match ToolboxIdlProgram::try_convert_codama_instruction(
+                codama_program,
+                &idl_types_names,
+                codama_instruction,
+            )
+            .with_context(|| {
+                format!(
+                    "Convert Instruction: {}",
+                    ToolboxIdlProgram::codama_node_name(codama_instruction)
+                )
+            }) {
+                Ok((name, idl_instruction)) => {
+                    idl_instructions.insert(name, idl_instruction);
+                },
+                Err(error) => warnings.push(format!("{:#}", error)),
+            }
+        }
+        let mut idl_events = Map::new();
+        for codama_event in
+            ToolboxIdlProgram::codama_nodes(codama_program, "events")
+        {
+            match ToolboxIdlProgram::try_convert_codama_event(
+                codama_program,
+                &idl_types_names,
+                codama_event,
+            )
+            .with_context(|| {
+                format!(
+                    "Convert Event: {}",
+                    ToolboxIdlProgram::codama_node_name(codama_event)
+                )
+            }) {
+                Ok((name, idl_event)) => {
+                    idl_events.insert(name, idl_event);
+                },
+                Err(error) => warnings.push(format!("{:#}", error)),
+            }
+        }
+        let mut idl_errors = Map::new();
+        for codama_error in
+            ToolboxIdlProgram::codama_nodes(codama_program, "errors")
+        {
+            match ToolboxIdlProgram::try_convert_codama_error(codama_error)
+                .context("Convert Error")
+            {
+                Ok((name, idl_error)) => {
+                    idl_errors.insert(name, idl_error);
+                },
+                Err(error) => warnings.push(format!("{:#}", error)),
+            }
+        }
+        idl_root.insert("types".to_string(), json!(idl_types));
+        idl_root.insert("accounts".to_string(), json!(idl_accounts));
+        idl_root.insert("instructions".to_string(), json!(idl_instructions));
+        idl_root.insert("events".to_string(), json!(idl_events));
+        idl_root.insert("errors".to_string(), json!(idl_errors));
+        let mut idl_program =
+            ToolboxIdlProgram::try_parse(&Value::Object(idl_root))?;
+        idl_program.warnings = warnings;
+        Ok(idl_program)
+    }
+
+    fn try_convert_codama_types(
+        codama_program: &Map<String, Value>,
+        warnings: &mut Vec<String>,
+    ) -> Result<Map<String, Value>> {
+        let codama_defined_types =
+            ToolboxIdlProgram::codama_nodes(codama_program, "definedTypes");
+        let mut idl_types_names = codama_defined_types
+            .iter()
+            .filter_map(|codama_defined_type| {
+                idl_object_get_key_as_str(codama_defined_type, "name")
+            })
+            .map(idl_convert_to_pascal_case)
+            .collect::<HashSet<_>>();
+        // Skipping a type can break the types using it, so retry until stable
+        loop {
+            let mut idl_types = Map::new();
+            let mut skipped = vec![];
+            for codama_defined_type in &codama_defined_types {
+                let name = idl_convert_to_pascal_case(
+                    idl_object_get_key_as_str_or_else(
+                        codama_defined_type,
+                        "name",
+                    )
+                    .context("Convert Type")?,
+                );
+                if !idl_types_names.contains(&name) {
+                    continue;
+                }
+                match ToolboxIdlProgram::try_convert_codama_type(
+                    codama_program,
+                    &idl_types_names,
+                    idl_object_get_key_or_else(codama_defined_type, "type")?,
+                )
+                .with_context(|| format!("Convert Type: {}", name))
+                {
+                    Ok(idl_type) => {
+                        let mut idl_typedef = Map::new();
+                        ToolboxIdlProgram::convert_codama_docs(
+                            codama_defined_type,
+                            &mut idl_typedef,
+                        );
+                        idl_typedef.insert("type".to_string(), idl_type);
+                        idl_types.insert(name, json!(idl_typedef));
+                    },
+                    Err(error) => skipped.push((name, error)),
+                }
+            }
+            if skipped.is_empty() {
+                return Ok(idl_types);
+            }
+            for (name, error) in skipped {
+                idl_types_names.remove(&name);
+                warnings.push(format!("{:#}", error));
+            }
+        }
+    }
+
+    fn try_convert_codama_account(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_account: &Map<String, Value>,
+    ) -> Result<(String, Value)> {
+        let name = idl_convert_to_pascal_case(
+            idl_object_get_key_as_str_or_else(codama_account, "name")?,
+        );
+        let mut idl_account = ToolboxIdlProgram::try_convert_codama_data(
+            codama_program,
+            idl_types_names,
+            codama_account,
+        )?;
+        let mut space = idl_object_get_key_as_u64(codama_account, "size");
+        let mut blobs = vec![];
+        for (offset, bytes) in
+            ToolboxIdlProgram::try_convert_codama_constant_discriminators(
+                codama_program,
+                codama_account,
+            )?
+        {
+            if offset == 0
+                && idl_account.get("discriminator") == Some(&json!([]))
+            {
+                idl_account.insert("discriminator".to_string(), json!(bytes));
+            } else {
+                blobs.push(json!({ "offset": offset, "value": bytes }));
+            }
+        }
+        for codama_discriminator in
+            ToolboxIdlProgram::codama_nodes(codama_account, "discriminators")
+        {
+            if idl_object_get_key_as_str(codama_discriminator, "kind")
+                == Some("sizeDiscriminatorNode")
+            {
+                space = space.or(idl_object_get_key_as_u64(
+                    codama_discriminator,
+                    "size",
+                ));
+            }
+        }
+        if let Some(space) = space {
+            idl_account.insert("space".to_string(), json!(space));
+        }
+        if !blobs.is_empty() {
+            idl_account.insert("blobs".to_string(), json!(blobs));
+        }
+        Ok((name, json!(idl_account)))
+    }
+
+    fn try_convert_codama_event(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_event: &Map<String, Value>,
+    ) -> Result<(String, Value)> {
+        let name = idl_convert_to_pascal_case(
+            idl_object_get_key_as_str_or_else(codama_event, "name")?,
+        );
+        let mut idl_event = ToolboxIdlProgram::try_convert_codama_data(
+            codama_program,
+            idl_types_names,
+            codama_event,
+        )?;
+        for (offset, bytes) in
+            ToolboxIdlProgram::try_convert_codama_constant_discriminators(
+                codama_program,
+                codama_event,
+            )?
+        {
+            if offset == 0 && idl_event.get("discriminator") == Some(&json!([]))
+            {
+                idl_event.insert("discriminator".to_string(), json!(bytes));
+            }
+        }
+        Ok((name, json!(idl_event)))
+    }
+
+    fn try_convert_codama_data(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_node: &Map<String, Value>,
+    ) -> Result<Map<String, Value>> {
+        let codama_data = idl_value_as_object_or_else(
+            idl_object_get_key_or_else(codama_node, "data")?,
+        )?;
+        let mut idl_data = Map::new();
+        ToolboxIdlProgram::convert_codama_docs(codama_node, &mut idl_data);
+        if idl_object_get_key_as_str(codama_data, "kind")
+            == Some("structTypeNode")
+        {
+            let (discriminator, idl_fields) =
+                ToolboxIdlProgram::try_convert_codama_fields(
+                    codama_program,
+                    idl_types_names,
+                    &ToolboxIdlProgram::codama_nodes(codama_data, "fields"),
+                    codama_node,
+                )?;
+            idl_data.insert("discriminator".to_string(), json!(discriminator));
+            idl_data.insert("fields".to_string(), json!(idl_fields));
+        } else {
+            idl_data.insert("discriminator".to_string(), json!([]));
+            idl_data.insert(
+                "type".to_string(),
+                ToolboxIdlProgram::try_convert_codama_type(
+                    codama_program,
+                    idl_types_names,
+                    &json!(codama_data),
+                )?,
+            );
+        }
+        Ok(idl_data)
+    }
+
+    fn try_convert_codama_instruction(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_instruction: &Map<String, Value>,
+    ) -> Result<(String, Value)> {
+        let name =
+            idl_object_get_key_as_str_or_else(codama_instruction, "name")?
+                .to_string();
+        let (mut discriminator, idl_args) =
+            ToolboxIdlProgram::try_convert_codama_fields(
+                codama_program,
+                idl_types_names,
+                &ToolboxIdlProgram::codama_nodes(
+                    codama_instruction,
+                    "arguments",
+                ),
+                codama_instruction,
+            )?;
+        for (offset, bytes) in
+            ToolboxIdlProgram::try_convert_codama_constant_discriminators(
+                codama_program,
+                codama_instruction,
+            )?
+        {
+            if offset == 0 && discriminator.is_empty() {
+                discriminator = bytes;
+            }
+        }
+        let mut idl_accounts = vec![];
+        for codama_instruction_account in
+            ToolboxIdlProgram::codama_nodes(codama_instruction, "accounts")
+        {
+            idl_accounts.push(
+                ToolboxIdlProgram::try_convert_codama_instruction_account(
+                    codama_program,
+                    codama_instruction,
+                    codama_instruction_account,
+                )
+                .with_context(|| {
+                    format!("Convert Instruction Account: {}", name)
+                })?,
+            );
+        }
+        let mut idl_instruction = Map::new();
+        ToolboxIdlProgram::convert_codama_docs(
+            codama_instruction,
+            &mut idl_instruction,
+        );
+        idl_instruction
+            .insert("discriminator".to_string(), json!(discriminator));
+        idl_instruction.insert("args".to_string(), json!(idl_args));
+        idl_instruction.insert("accounts".to_string(), json!(idl_accounts));
+        Ok((name, json!(idl_instruction)))
+    }
+
+    fn try_convert_codama_instruction_account(
+        codama_program: &Map<String, Value>,
+        codama_instruction: &Map<String, Value>,
+        codama_instruction_account: &Map<String, Value>,
+    ) -> Result<Value> {
+        let name =
+            idl_convert_to_snake_case(idl_object_get_key_as_str_or_else(
+                codama_instruction_account,
+                "name",
+            )?);
+        let mut idl_instruction_account = Map::new();
+        idl_instruction_account.insert("name".to_string(), json!(name));
+        ToolboxIdlProgram::convert_codama_docs(
+            codama_instruction_account,
+            &mut idl_instruction_account,
+        );
+        idl_instruction_account.insert(
+            "writable".to_string(),
+            json!(idl_object_get_key_as_bool(
+                codama_instruction_account,
+                "isWritable"
+            )
+            .unwrap_or(false)),
+        );
+        // Accounts that can "either" be signers are left to the caller
+        idl_instruction_account.insert(
+            "signer".to_string(),
+            json!(idl_object_get_key_as_bool(
+                codama_instruction_account,
+                "isSigner"
+            )
+            .unwrap_or(false)),
+        );
+        idl_instruction_account.insert(
+            "optional".to_string(),
+            json!(idl_object_get_key_as_bool(
+                codama_instruction_account,
+                "isOptional"
+            )
+            .unwrap_or(false)),
+        );
+        if let Some(codama_default_value) = idl_object_get_key_as_object(
+            codama_instruction_account,
+            "defaultValue",
+        ) {
+            match idl_object_get_key_as_str(codama_default_value, "kind") {
+                Some("publicKeyValueNode") => {
+                    idl_instruction_account.insert(
+                        "address".to_string(),
+                        json!(idl_object_get_key_as_str_or_else(
+                            codama_default_value,
+                            "publicKey"
+                        )?),
+                    );
+                },
+                Some("programIdValueNode") => {
+                    idl_instruction_account.insert(
+                        "address".to_string(),
+                        json!(idl_object_get_key_as_str_or_else(
+                            codama_program,
+                            "publicKey"
+                        )?),
+                    );
+                },
+                Some("pdaValueNode") => {
+                    idl_instruction_account.insert(
+                        "pda".to_string(),
+                        ToolboxIdlProgram::try_convert_codama_pda_value(
+                            codama_program,
+                            codama_instruction,
+                            codama_default_value,
+                        )
+                        .with_context(|| format!("Convert {} Pda", name))?,
+                    );
+                },
+                _ => {},
+            }
+        }
+        Ok(json!(idl_instruction_account))
+    }
+
+    fn try_convert_codama_pda_value(
+        codama_program: &Map<String, Value>,
+        codama_instruction: &Map<String, Value>,
+        codama_pda_value: &Map<String, Value>,
+    ) -> Result<Value> {
+        let codama_pda = idl_value_as_object_or_else(
+            idl_object_get_key_or_else(codama_pda_value, "pda")?,
+        )?;
+        let codama_pda = match idl_object_get_key_as_str(codama_pda, "kind") {
+            Some("pdaLinkNode") => {
+                let codama_pda_name =
+                    idl_object_get_key_as_str_or_else(codama_pda, "name")?;
+                ToolboxIdlProgram::codama_nodes(codama_program, "pdas")
+                    .into_iter()
+                    .find(|codama_pda| {
+                        idl_object_get_key_as_str(codama_pda, "name")
+                            == Some(codama_pda_name)
+                    })
+                    .with_context(|| {
+                        format!("Unknown pda: {}", codama_pda_name)
+                    })?
+            },
+            _ => codama_pda,
+        };
+        let codama_seeds_values =
+            ToolboxIdlProgram::codama_nodes(codama_pda_value, "seeds");
+        let codama_instruction_accounts =
+            ToolboxIdlProgram::codama_nodes(codama_instruction, "accounts");
+        let mut idl_seeds = vec![];
+        for codama_seed in ToolboxIdlProgram::codama_nodes(codama_pda, "seeds")
+        {
+            let codama_seed_type =
+                idl_object_get_key_or_else(codama_seed, "type")?;
+            match idl_object_get_key_as_str(codama_seed, "kind") {
+                Some("constantPdaSeedNode") => {
+                    idl_seeds.push(json!({
+                        "value": ToolboxIdlProgram::try_convert_codama_value_bytes(
+                            codama_program,
+                            codama_seed_type,
+                            idl_object_get_key_or_else(codama_seed, "value")?,
+                        )?
+                    }));
+                },
+                Some("variablePdaSeedNode") => {
+                    let codama_seed_name =
+                        idl_object_get_key_as_str_or_else(codama_seed, "name")?;
+                    let codama_seed_value = codama_seeds_values
+                        .iter()
+                        .find(|codama_seed_value| {
+                            idl_object_get_key_as_str(codama_seed_value, "name")
+                                == Some(codama_seed_name)
+                        })
+                        .and_then(|codama_seed_value| {
+                            idl_object_get_key_as_object(
+                                codama_seed_value,
+                                "value",
+                            )
+                        });
+                    let codama_seed_value = match codama_seed_value {
+                        Some(codama_seed_value) => codama_seed_value,
+                        None => {
+                            // Unmapped seeds default to the same named account or argument
+                            let kind = if codama_instruction_accounts
+                                .iter()
+                                .any(|codama_instruction_account| {
+                                    idl_object_get_key_as_str(
+                                        codama_instruction_account,
+                                        "name",
+                                    ) == Some(codama_seed_name)
+                                }) {
+                                "accountValueNode"
+                            } else {
+                                "argumentValueNode"
+                            };
+                            idl_seeds.push(
+                                ToolboxIdlProgram::convert_codama_seed_path(
+                                    kind,
+                                    codama_seed_name,
+                                ),
+                            );
+                            continue;
+                        },
+                    };
+                    match idl_object_get_key_as_str(codama_seed_value, "kind") {
+                        Some(kind @ "accountValueNode")
+                        | Some(kind @ "argumentValueNode") => {
+                            idl_seeds.push(
+                                ToolboxIdlProgram::convert_codama_seed_path(
+                                    kind,
+                                    idl_object_get_key_as_str_or_else(
+                                        codama_seed_value,
+                                        "name",
+                                    )?,
+                                ),
+                            );
+                        },
+                        _ => {
+                            idl_seeds.push(json!({
+                                "value": ToolboxIdlProgram::try_convert_codama_value_bytes(
+                                    codama_program,
+                                    codama_seed_type,
+                                    &json!(codama_seed_value),
+                                )?
+                            }));
+                        },
+                    }
+                },
+                _ => {
+                    return Err(anyhow!(
+                        "Unsupported pda seed: {:?}",
+                        codama_seed.get("kind")
+                    ));
+                },
+            }
+        }
+        let mut idl_pda = Map::new();
+        idl_pda.insert("seeds".to_string(), json!(idl_seeds));
+        if let Some(codama_pda_program_id) =
+            idl_object_get_key_as_str(codama_pda, "programId")
+        {
+            idl_pda.insert(
+                "program".to_string(),
+                json!({ "value": codama_pda_program_id, "type": "pubkey" }),
+            );
+        }
+        Ok(json!(idl_pda))
+    }
+
+    fn convert_codama_seed_path(kind: &str, name: &str) -> Value {
+        let path = idl_convert_to_snake_case(name);
+        if kind == "argumentValueNode" {
+            json!({ "kind": "arg", "path": path })
+        } else {
+            json!({ "path": path })
+        }
+    }
+
+    fn try_convert_codama_error(
+        codama_error: &Map<String, Value>,
+    ) -> Result<(String, Value)> {
+        let name = idl_convert_to_pascal_case(
+            idl_object_get_key_as_str_or_else(codama_error, "name")?,
+        );
+        let mut idl_error = Map::new();
+        idl_error.insert(
+            "code".to_string(),
+            json!(idl_object_get_key_as_u64_or_else(codama_error, "code")?),
+        );
+        if let Some(message) =
+            idl_object_get_key_as_str(codama_error, "message")
+        {
+            idl_error.insert("msg".to_string(), json!(message));
+        }
+        ToolboxIdlProgram::convert_codama_docs(codama_error, &mut idl_error);
+        Ok((name, json!(idl_error)))
+    }
+
+    fn try_convert_codama_fields(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_fields: &[&Map<String, Value>],
+        codama_node: &Map<String, Value>,
+    ) -> Result<(Vec<u8>, Vec<Value>)> {
+        let codama_discriminators_names =
+            ToolboxIdlProgram::codama_nodes(codama_node, "discriminators")
+                .into_iter()
+                .filter(|codama_discriminator| {
+                    idl_object_get_key_as_str(codama_discriminator, "kind")
+                        == Some("fieldDiscriminatorNode")
+                })
+                .filter_map(|codama_discriminator| {
+                    idl_object_get_key_as_str(codama_discriminator, "name")
+                })
+                .collect::<HashSet<_>>();
+        let mut discriminator = vec![];
+        let mut idl_fields = vec![];
+        for codama_field in codama_fields {
+            let codama_field_name =
+                idl_object_get_key_as_str_or_else(codama_field, "name")?;
+            let codama_field_type =
+                idl_object_get_key_or_else(codama_field, "type")?;
+            // Leading discriminator fields become the IDL discriminator
+            if idl_fields.is_empty()
+                && codama_discriminators_names.contains(codama_field_name)
+            {
+                discriminator.extend(
+                    ToolboxIdlProgram::try_convert_codama_value_bytes(
+                        codama_program,
+                        codama_field_type,
+                        idl_object_get_key_or_else(
+                            codama_field,
+                            "defaultValue",
+                        )?,
+                    )
+                    .with_context(|| {
+                        format!("Convert Discriminator: {}", codama_field_name)
+                    })?,
+                );
+                continue;
+            }
+            let mut idl_field = Map::new();
+            idl_field.insert(
+                "name".to_string(),
+                json!(idl_convert_to_snake_case(codama_field_name)),
+            );
+            ToolboxIdlProgram::convert_codama_docs(
+                codama_field,
+                &mut idl_field,
+            );
+            idl_field.insert(
+                "type".to_string(),
+                ToolboxIdlProgram::try_convert_codama_type(
+                    codama_program,
+                    idl_types_names,
+                    codama_field_type,
+                )
+                .with_context(|| {
+                    format!("Convert Field: {}", codama_field_name)
+                })?,
+            );
+            idl_fields.push(json!(idl_field));
+        }
+        Ok((discriminator, idl_fields))
+    }
+
+    fn try_convert_codama_constant_discriminators(
+        codama_program: &Map<String, Value>,
+        codama_node: &Map<String, Value>,
+    ) -> Result<Vec<(u64, Vec<u8>)>> {
+        let mut constants = vec![];
+        for codama_discriminator in
+            ToolboxIdlProgram::codama_nodes(codama_node, "discriminators")
+        {
+            if idl_object_get_key_as_str(codama_discriminator, "kind")
+                != Some("constantDiscriminatorNode")
+            {
+                continue;
+            }
+            let codama_constant = idl_value_as_object_or_else(
+                idl_object_get_key_or_else(codama_discriminator, "constant")?,
+            )?;
+            constants.push((
+                idl_object_get_key_as_u64(codama_discriminator, "offset")
+                    .unwrap_or(0),
+                ToolboxIdlProgram::try_convert_codama_value_bytes(
+                    codama_program,
+                    idl_object_get_key_or_else(codama_constant, "type")?,
+                    idl_object_get_key_or_else(codama_constant, "value")?,
+                )?,
+            ));
+        }
+        Ok(constants)
+    }
+
+    fn try_convert_codama_type(
+        codama_program: &Map<String, Value>,
+        idl_types_names: &HashSet<String>,
+        codama_type: &Value,
+    ) -> Result<Value> {
+        let codama_type = idl_value_as_object_or_else(codama_type)?;
+        let kind = idl_object_get_key_as_str_or_else(codama_type, "kind")?;
+        match kind {
+            "numberTypeNode" | "amountTypeNode" | "dateTimeTypeNode"
+            | "solAmountTypeNode" => {
+                Ok(json!(ToolboxIdlProgram::try_convert_codama_number_format(
+                    codama_type
+                )?))
+            },
+            "booleanTypeNode" => {
+                if let Some(codama_size) =
+                    idl_object_get_key_as_object(codama_type, "size")
+                {
+                    let format =
+                        ToolboxIdlProgram::try_convert_codama_number_format(
+                            codama_size,
+                        )?;
+                    if format != "u8" {
+                        return Err(anyhow!(
+                            "Unsupported boolean size: {}",
+                            format
+                        ));
+                    }
+                }
+                Ok(json!("bool"))
+            },
+            "publicKeyTypeNode" => Ok(json!("pubkey")),
+            "sizePrefixTypeNode" => {
+                let bits = ToolboxIdlProgram::try_convert_codama_prefix_bits(
+                    codama_type,
+                )?;
+                let codama_inner = idl_value_as_object_or_else(
+                    idl_object_get_key_or_else(codama_type, "type")?,
+                )?;
+                match idl_object_get_key_as_str(codama_inner, "kind") {
+                    Some("stringTypeNode") => {
+                        Ok(json!(format!("string{}", bits)))
+                    },
+                    Some("bytesTypeNode") => {
+                        Ok(json!({ format!("vec{}", bits): "u8" }))
+                    },
+                    _ => Err(anyhow!(
+                        "Unsupported size prefixed type: {:?}",
+                        codama_inner.get("kind")
+                    )),
+                }
+            },
+            "fixedSizeTypeNode" => {
+                let size =
+                    idl_object_get_key_as_u64_or_else(codama_type, "size")?;
+                let codama_inner =
+                    idl_object_get_key_or_else(codama_type, "type")?;
+                match codama_inner.get("kind").and_then(Value::as_str) {
+                    Some("bytesTypeNode") | Some("stringTypeNode") => {
+                        Ok(json!({ "array": ["u8", size] }))
+                    },
+                    _ => Ok(json!({
+                        "padded": {
+                            "min_size": size,
+                            "type": ToolboxIdlProgram::try_convert_codama_type(
+                                codama_program,
+                                idl_types_names,
+                                codama_inner,
+                            )?,
+                        }
+                    })),
+                }
+            },
+            "optionTypeNode" => {
+                let bits = ToolboxIdlProgram::try_convert_codama_prefix_bits(
+                    codama_type,
+                )?;
+                let codama_item =
+                    idl_object_get_key_or_else(codama_type, "item")?;
+                let idl_item = ToolboxIdlProgram::try_convert_codama_type(
+                    codama_program,
+                    idl_types_names,
+                    codama_item,
+                )?;
+                if idl_object_get_key_as_bool(codama_type, "fixed")
+                    == Some(true)
+                {
+                    let min_size = bits.parse::<usize>()? / 8
+                        + ToolboxIdlProgram::try_compute_codama_type_size(
+                            codama_program,
+                            codama_item,
+                        )?;
+                    return Ok(json!({
+                        "padded": {
+                            "min_size": min_size,
+                            format!("option{}", bits): idl_item,
+                        }
+                    }));
+                }
+                Ok(json!({ format!("option{}", bits): idl_item }))
+            },
+            "zeroableOptionTypeNode" => {
+                if codama_type.contains_key("zeroValue") {
+                    return Err(anyhow!("Unsupported zeroable option value"));
+                }
+                Ok(json!({
+                    "option_zeroable": ToolboxIdlProgram::try_convert_codama_type(
+                        codama_program,
+                        idl_types_names,
+                        idl_object_get_key_or_else(codama_type, "item")?,
+                    )?,
+                }))
+            },
+            "arrayTypeNode" | "setTypeNode" => {
+                ToolboxIdlProgram::try_convert_codama_count(
+                    codama_type,
+                    ToolboxIdlProgram::try_convert_codama_type(
+                        codama_program,
+                        idl_types_names,
+                        idl_object_get_key_or_else(codama_type, "item")?,
+                    )?,
+                )
+            },
+            "mapTypeNode" => ToolboxIdlProgram::try_convert_codama_count(
+                codama_type,
+                json!({
+                    "fields": [
+                        ToolboxIdlProgram::try_convert_codama_type(
+                            codama_program,
+                            idl_types_names,
+                            idl_object_get_key_or_else(codama_type, "key")?,
+                        )?,
+                        ToolboxIdlProgram::try_convert_codama_type(
+                            codama_program,
+                            idl_types_names,
+                            idl_object_get_key_or_else(codama_type, "value")?,
+                        )?,
+                    ]
+                }),
+            ),
+            "tupleTypeNode" => {
+                let mut idl_items = vec![];
+                for codama_item in
+                    idl_object_get_key_as_array(codama_type, "items")
+                        .context("Missing tuple items")?
+                {
+                    idl_items.push(ToolboxIdlProgram::try_convert_codama_type(
+                        codama_program,
+                        idl_types_names,
+                        codama_item,
+                    )?);
+                }
+                Ok(json!({ "fields": idl_items }))
+            },
+            "structTypeNode" => {
+                let (_, idl_fields) =
+                    ToolboxIdlProgram::try_convert_codama_fields(
+                        codama_program,
+                        idl_types_names,
+                        &ToolboxIdlProgram::codama_nodes(codama_type, "fields"),
+                        codama_type,
+                    )?;
+                Ok(json!({ "fields": idl_fields }))
+            },
+            "enumTypeNode" => {
+                let bits =
+                    match idl_object_get_key_as_object(codama_type, "size") {
+                        Some(codama_size) => {
+                            ToolboxIdlProgram::try_convert_codama_number_bits(
+                                codama_size,
+                            )?
+                        },
+                        None => "8",
+                    };
+                let mut idl_variants = vec![];
+                for (index, codama_variant) in
+                    ToolboxIdlProgram::codama_nodes(codama_type, "variants")
+                        .into_iter()
+                        .enumerate()
+                {
+                    let mut idl_variant = Map::new();
+                    idl_variant.insert(
+                        "name".to_string(),
+                        json!(idl_convert_to_pascal_case(
+                            idl_object_get_key_as_str_or_else(
+                                codama_variant,
+                                "name"
+                            )?
+                        )),
+                    );
+                    idl_variant.insert(
+                        "code".to_string(),
+                        json!(idl_object_get_key_as_u64(
+                            codama_variant,
+                            "discriminator"
+                        )
+                        .unwrap_or(u64::try_from(index)?)),
+                    );
+                    ToolboxIdlProgram::convert_codama_docs(
+                        codama_variant,
+                        &mut idl_variant,
+                    );
+                    let codama_variant_type =
+                        match idl_object_get_key_as_str(codama_variant, "kind")
+                        {
+                            Some("enumStructVariantTypeNode") => {
+                                codama_variant.get("struct")
+                            },
+                            Some("enumTupleVariantTypeNode") => {
+                                codama_variant.get("tuple")
+                            },
+                            _ => None,
+                        };
+                    if let Some(codama_variant_type) = codama_variant_type {
+                        let idl_variant_type =
+                            ToolboxIdlProgram::try_convert_codama_type(
+                                codama_program,
+                                idl_types_names,
+                                codama_variant_type,
+                            )?;
+                        idl_variant.insert(
+                            "fields".to_string(),
+                            idl_variant_type
+                                .get("fields")
+                                .cloned()
+                                .context("Variant fields")?,
+                        );
+                    }
+                    idl_variants.push(json!(idl_variant));
+                }
+                Ok(json!({ format!("variants{}", bits): idl_variants }))
+            },
+            "definedTypeLinkNode" => {
+                let name = idl_convert_to_pascal_case(
+                    idl_object_get_key_as_str_or_else(codama_type, "name")?,
+                );
+                if !idl_types_names.contains(&name) {
+                    return Err(anyhow!("Unsupported defined type: {}", name));
+                }
+                Ok(json!({ "defined": name }))
+            },
+            "preOffsetTypeNode" | "postOffsetTypeNode" => {
+                let offset =
+                    idl_object_get_key_as_u64_or_else(codama_type, "offset")?;
+                let strategy =
+                    idl_object_get_key_as_str(codama_type, "strategy")
+                        .unwrap_or("relative");
+                if strategy != "relative" && strategy != "padded" {
+                    return Err(anyhow!(
+                        "Unsupported offset strategy: {}",
+                        strategy
+                    ));
+                }
+                let side = if kind == "preOffsetTypeNode" {
+                    "before"
+                } else {
+                    "after"
+                };
+                Ok(json!({
+                    "padded": {
+                        side: offset,
+                        "type": ToolboxIdlProgram::try_convert_codama_type(
+                            codama_program,
+                            idl_types_names,
+                            idl_object_get_key_or_else(codama_type, "type")?,
+                        )?,
+                    }
+                }))
+            },
+            _ => Err(anyhow!("Unsupported type: {}", kind)),
+        }
+    }
+
+    fn try_convert_codama_count(
+        codama_type: &Map<String, Value>,
+        idl_item: Value,
+    ) -> Result<Value> {
+        let codama_count = idl_value_as_object_or_else(
+            idl_object_get_key_or_else(codama_type, "count")?,
+        )?;
+        match idl_object_get_key_as_str(codama_count, "kind") {
+            Some("fixedCountNode") => Ok(json!({
+                "array": [
+                    idl_item,
+                    idl_object_get_key_as_u64_or_else(codama_count, "value")?
+                ]
+            })),
+            Some("prefixedCountNode") => {
+                let bits = ToolboxIdlProgram::try_convert_codama_prefix_bits(
+                    codama_count,
+                )?;
+                Ok(json!({ format!("vec{}", bits): idl_item }))
+            },
+            _ => Err(anyhow!(
+                "Unsupported count: {:?}",
+                codama_count.get("kind")
+            )),
+        }
+    }
+
+    fn try_convert_codama_number_format(
+        codama_number: &Map<String, Value>,
+    ) -> Result<&str> {
+        if let Some(codama_number_inner) =
+            idl_object_get_key_as_object(codama_number, "number")
+        {
+            return ToolboxIdlProgram::try_convert_codama_number_format(
+                codama_number_inner,
+            );
+        }
+        if idl_object_get_key_as_str(codama_number, "endian") == Some("be") {
+            return Err(anyhow!("Unsupported big endian number"));
+        }
+        let format =
+            idl_object_get_key_as_str_or_else(codama_number, "format")?;
+        ToolboxIdlProgram::codama_number_format_size(format)?;
+        Ok(format)
+    }
+
+    fn try_convert_codama_prefix_bits(
+        codama_node: &Map<String, Value>,
+    ) -> Result<&str> {
+        match idl_object_get_key_as_object(codama_node, "prefix") {
+            Some(codama_prefix) => {
+                ToolboxIdlProgram::try_convert_codama_number_bits(codama_prefix)
+            },
+            None => Ok("8"),
+        }
+    }
+
+    fn try_convert_codama_number_bits(
+        codama_number: &Map<String, Value>,
+    ) -> Result<&str> {
+        let format =
+            ToolboxIdlProgram::try_convert_codama_number_format(codama_number)?;
+        format
+            .strip_prefix('u')
+            .ok_or_else(|| anyhow!("Unsupported prefix: {}", format))
+    }
+
+    fn try_compute_codama_type_size(
+        codama_program: &Map<String, Value>,
+        codama_type: &Value,
+    ) -> Result<usize> {
+        let codama_type = idl_value_as_object_or_else(codama_type)?;
+        let kind = idl_object_get_key_as_str_or_else(codama_type, "kind")?;
+        match kind {
+            "numberTypeNode" | "amountTypeNode" | "dateTimeTypeNode"
+            | "solAmountTypeNode" => {
+                ToolboxIdlProgram::codama_number_format_size(
+                    ToolboxIdlProgram::try_convert_codama_number_format(
+                        codama_type,
+                    )?,
+                )
+            },
+            "booleanTypeNode" => Ok(1),
+            "publicKeyTypeNode" => Ok(32),
+            "zeroableOptionTypeNode" => {
+                ToolboxIdlProgram::try_compute_codama_type_size(
+                    codama_program,
+                    idl_object_get_key_or_else(codama_type, "item")?,
+                )
+            },
+            "fixedSizeTypeNode" => Ok(usize::try_from(
+                idl_object_get_key_as_u64_or_else(codama_type, "size")?,
+            )?),
+            "structTypeNode" => {
+                let mut size = 0;
+                for codama_field in
+                    ToolboxIdlProgram::codama_nodes(codama_type, "fields")
+                {
+                    size += ToolboxIdlProgram::try_compute_codama_type_size(
+                        codama_program,
+                        idl_object_get_key_or_else(codama_field, "type")?,
+                    )?;
+                }
+                Ok(size)
+            },
+            "tupleTypeNode" => {
+                let mut size = 0;
+                for codama_item in
+                    idl_object_get_key_as_array(codama_type, "items")
+                        .context("Missing tuple items")?
+                {
+                    size += ToolboxIdlProgram::try_compute_codama_type_size(
+                        codama_program,
+                        codama_item,
+                    )?;
+                }
+                Ok(size)
+            },
+            "definedTypeLinkNode" => {
+                let name =
+                    idl_object_get_key_as_str_or_else(codama_type, "name")?;
+                let codama_defined_type = ToolboxIdlProgram::codama_nodes(
+                    codama_program,
+                    "definedTypes",
+                )
+                .into_iter()
+                .find(|codama_defined_type| {
+                    idl_object_get_key_as_str(codama_defined_type, "name")
+                        == Some(name)
+                })
+                .with_context(|| format!("Unknown defined type: {}", name))?;
+                ToolboxIdlProgram::try_compute_codama_type_size(
+                    codama_program,
+                    idl_object_get_key_or_else(codama_defined_type, "type")?,
+                )
+            },
+            _ => Err(anyhow!("Unsupported fixed size type: {}", kind)),
+        }
+    }
+
+    fn try_convert_codama_value_bytes(
+        codama_program: &Map<String, Value>,
+        codama_type: &Value,
+        codama_value: &Value,
+    ) -> Result<Vec<u8>> {
+        let codama_value = idl_value_as_object_or_else(codama_value)?;
+        let kind = idl_object_get_key_as_str_or_else(codama_value, "kind")?;
+        match kind {
+            "numberValueNode" => {
+                let format =
+                    ToolboxIdlProgram::try_convert_codama_number_format(
+                        idl_value_as_object_or_else(codama_type)?,
+                    )?;
+                let size =
+                    ToolboxIdlProgram::codama_number_format_size(format)?;
+                let codama_number =
+                    idl_object_get_key_or_else(codama_value, "number")?;
+                if format == "f32" {
+                    return Ok((codama_number.as_f64().context("Number")?
+                        as f32)
+                        .to_le_bytes()
+                        .to_vec());
+                }
+                if format == "f64" {
+                    return Ok(codama_number
+                        .as_f64()
+                        .context("Number")?
+                        .to_le_bytes()
+                        .to_vec());
+                }
+                let number = match codama_number.as_u64() {
+                    Some(number) => i128::from(number),
+                    None => i128::from(
+                        codama_number.as_i64().context("Integer number")?,
+                    ),
+                };
+                Ok(number.to_le_bytes()[..size].to_vec())
+            },
+            "bytesValueNode" => idl_value_as_bytes_or_else(&json!({
+                idl_object_get_key_as_str_or_else(codama_value, "encoding")?:
+                    idl_object_get_key_as_str_or_else(codama_value, "data")?,
+            })),
+            "stringValueNode" => {
+                Ok(idl_object_get_key_as_str_or_else(codama_value, "string")?
+                    .as_bytes()
+                    .to_vec())
+            },
+            "booleanValueNode" => Ok(vec![u8::from(
+                idl_object_get_key_as_bool(codama_value, "boolean")
+                    .context("Boolean")?,
+            )]),
+            "publicKeyValueNode" => Ok(Pubkey::from_str(
+                idl_object_get_key_as_str_or_else(codama_value, "publicKey")?,
+            )?
+            .to_bytes()
+            .to_vec()),
+            "programIdValueNode" => Ok(Pubkey::from_str(
+                idl_object_get_key_as_str_or_else(codama_program, "publicKey")?,
+            )?
+            .to_bytes()
+            .to_vec()),
+            "constantValueNode" => {
+                ToolboxIdlProgram::try_convert_codama_value_bytes(
+                    codama_program,
+                    idl_object_get_key_or_else(codama_value, "type")?,
+                    idl_object_get_key_or_else(codama_value, "value")?,
+                )
+            },
+            _ => Err(anyhow!("Unsupported value: {}", kind)),
+        }
+    }
+
+    fn codama_number_format_size(format: &str) -> Result<usize> {
+        match format {
+            "u8" | "i8" => Ok(1),
+            "u16" | "i16" => Ok(2),
+            "u32" | "i32" | "f32" => Ok(4),
+            "u64" | "i64" | "f64" => Ok(8),
+            "u128" | "i128" => Ok(16),
+            _ => Err(anyhow!("Unsupported number format: {}", format)),
+        }
+    }
+
+    fn codama_node_name(codama_node: &Map<String, Value>) -> &str {
+        idl_object_get_key_as_str(codama_node, "name").unwrap_or("?")
+    }
+
+    fn codama_nodes<'a>(
+        codama_node: &'a Map<String, Value>,
+        key: &str,
+    ) -> Vec<&'a Map<String, Value>> {
+        idl_object_get_key_as_array(codama_node, key)
+            .map(|codama_nodes| {
+                codama_nodes.iter().filter_map(Value::as_object).collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn convert_codama_docs(
+        codama_node: &Map<String, Value>,
+        idl_node: &mut Map<String, Value>,
+    ) {
+        if let Some(docs) = idl_object_get_key_as_array(codama_node, "docs") {
+            if !docs.is_empty() {
+                idl_node.insert("docs".to_string(), json!(docs));
+            }
+        }
+    }
+}
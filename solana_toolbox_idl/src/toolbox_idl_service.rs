@@ -48,6 +48,17 @@ impl ToolboxIdlService {
         Ok(idl_program)
     }
 
+    // Without an endpoint, only the cached and builtin programs can be known
+    pub(crate) fn get_cached_or_lib_program(
+        &self,
+        program_id: &Pubkey,
+    ) -> Option<Arc<ToolboxIdlProgram>> {
+        match self.cached_programs.get(program_id) {
+            Some(idl_program) => idl_program.clone(),
+            None => ToolboxIdlProgram::from_lib(program_id).map(Arc::new),
+        }
+    }
+
     async fn resolve_program(
         endpoint: &mut ToolboxEndpoint,
         program_id: &Pubkey,
@@ -0,0 +1,484 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use serde_json::Value;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionFlame;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionInnerInstruction;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStep;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStepCall;
+
+use crate::toolbox_idl_error::ToolboxIdlError;
+use crate::toolbox_idl_event::ToolboxIdlEvent;
+use crate::toolbox_idl_program::ToolboxIdlProgram;
+use crate::toolbox_idl_service::ToolboxIdlService;
+
+pub struct ToolboxIdlServiceEventInfo {
+    pub program_id: Pubkey,
+    pub program: Arc<ToolboxIdlProgram>,
+    pub event: Arc<ToolboxIdlEvent>,
+    pub data: Vec<u8>,
+    pub value: Result<Value>,
+    pub instruction_index: usize,
+    pub cpi_path: Vec<Pubkey>,
+}
+
+pub struct ToolboxIdlServiceErrorInfo {
+    pub program_id: Pubkey,
+    pub program: Arc<ToolboxIdlProgram>,
+    pub error: Arc<ToolboxIdlError>,
+    pub instruction_index: usize,
+}
+
+impl ToolboxIdlService {
+    pub async fn infer_and_decode_execution_error(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<Option<ToolboxIdlServiceErrorInfo>> {
+        let Some((instruction_index, error_code, program_id, step_call)) =
+            ToolboxIdlService::find_execution_error_call(execution)
+        else {
+            return Ok(None);
+        };
+        let idl_program = self
+            .get_or_resolve_program(endpoint, &program_id)
+            .await
+            .context("Resolve Program")?;
+        let idl_error = ToolboxIdlService::guess_execution_error(
+            idl_program.as_deref(),
+            step_call,
+            error_code,
+        )
+        .unwrap_or_else(|| {
+            Arc::new(ToolboxIdlError {
+                code: error_code,
+                ..Default::default()
+            })
+        });
+        Ok(Some(ToolboxIdlServiceErrorInfo {
+            program_id,
+            program: idl_program.unwrap_or_default(),
+            error: idl_error,
+            instruction_index,
+        }))
+    }
+
+    // The error may have been raised by a CPI, look for the deepest failure
+    pub(crate) fn find_execution_error_call(
+        execution: &ToolboxEndpointExecution,
+    ) -> Option<(
+        usize,
+        u64,
+        Pubkey,
+        Option<&ToolboxEndpointExecutionStepCall>,
+    )> {
+        let Some(TransactionError::InstructionError(
+            instruction_index,
+            InstructionError::Custom(error_code),
+        )) = &execution.error
+        else {
+            return None;
+        };
+        let instruction_index = usize::from(*instruction_index);
+        let error_code = u64::from(*error_code);
+        let instruction = execution.instructions.get(instruction_index)?;
+        let step_call =
+            ToolboxIdlService::find_instructions_steps_calls(execution)
+                .into_iter()
+                .nth(instruction_index)
+                .flatten()
+                .and_then(|step_call| {
+                    ToolboxIdlService::find_step_call_failure(
+                        step_call,
+                        &format!("custom program error: {:#x}", error_code),
+                    )
+                });
+        let program_id = step_call
+            .map(|step_call| step_call.program_id)
+            .unwrap_or(instruction.program_id);
+        Some((instruction_index, error_code, program_id, step_call))
+    }
+
+    pub(crate) fn guess_execution_error(
+        idl_program: Option<&ToolboxIdlProgram>,
+        step_call: Option<&ToolboxEndpointExecutionStepCall>,
+        error_code: u64,
+    ) -> Option<Arc<ToolboxIdlError>> {
+        idl_program
+            .and_then(|idl_program| idl_program.guess_error(error_code))
+            .or_else(|| {
+                ToolboxIdlProgram::guess_anchor_framework_error(
+                    idl_program,
+                    error_code,
+                )
+            })
+            .or_else(|| {
+                step_call
+                    .map(|step_call| &step_call.steps)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|step| match step {
+                        ToolboxEndpointExecutionStep::Log(log) => {
+                            ToolboxIdlService::parse_anchor_error_log(log)
+                        },
+                        _ => None,
+                    })
+                    .find(|idl_error| idl_error.code == error_code)
+                    .map(Arc::new)
+            })
+    }
+
+    // Precompiles do not log their invocation, so the top-level calls are
+    // matched to the instructions by program id rather than by position
+    pub(crate) fn find_instructions_steps_calls(
+        execution: &ToolboxEndpointExecution,
+    ) -> Vec<Option<&ToolboxEndpointExecutionStepCall>> {
+        let mut steps_calls = execution
+            .steps
+            .iter()
+            .flatten()
+            .filter_map(|step| match step {
+                ToolboxEndpointExecutionStep::Call(step_call) => {
+                    Some(step_call)
+                },
+                _ => None,
+            })
+            .peekable();
+        execution
+            .instructions
+            .iter()
+            .map(|instruction| {
+                steps_calls.next_if(|step_call| {
+                    step_call.program_id == instruction.program_id
+                })
+            })
+            .collect()
+    }
+
+    fn find_step_call_failure<'a>(
+        step_call: &'a ToolboxEndpointExecutionStepCall,
+        failure: &str,
+    ) -> Option<&'a ToolboxEndpointExecutionStepCall> {
+        for step in &step_call.steps {
+            if let ToolboxEndpointExecutionStep::Call(step_call) = step {
+                if let Some(step_call) =
+                    ToolboxIdlService::find_step_call_failure(
+                        step_call, failure,
+                    )
+                {
+                    return Some(step_call);
+                }
+            }
+        }
+        if step_call.failure.as_deref() == Some(failure) {
+            return Some(step_call);
+        }
+        None
+    }
+
+    // Anchor logs: "AnchorError ... Error Code: X. Error Number: N. Error Message: M."
+    fn parse_anchor_error_log(log: &str) -> Option<ToolboxIdlError> {
+        let name = log.split("Error Code: ").nth(1)?.split('.').next()?;
+        let code = log
+            .split("Error Number: ")
+            .nth(1)?
+            .split('.')
+            .next()?
+            .parse()
+            .ok()?;
+        let msg = log
+            .split("Error Message: ")
+            .nth(1)
+            .map(|msg| msg.trim_end_matches('.').to_string());
+        Some(ToolboxIdlError {
+            name: name.trim().to_string(),
+            docs: None,
+            code,
+            msg,
+        })
+    }
+
+    pub async fn infer_and_decode_execution_events(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<Vec<ToolboxIdlServiceEventInfo>> {
+        let mut cpis_events_datas = HashMap::new();
+        for (instruction_index, inner_instructions) in
+            execution.inner_instructions.iter().flatten().enumerate()
+        {
+            ToolboxIdlService::collect_inner_instructions_events_datas(
+                instruction_index,
+                execution.instructions.get(instruction_index),
+                inner_instructions,
+                &mut cpis_events_datas,
+            );
+        }
+        let mut events_datas = vec![];
+        for (instruction_index, step_call) in
+            ToolboxIdlService::find_instructions_steps_calls(execution)
+                .into_iter()
+                .enumerate()
+        {
+            if let Some(step_call) = step_call {
+                ToolboxIdlService::collect_steps_events_datas(
+                    instruction_index,
+                    &mut 0,
+                    &mut vec![step_call.program_id],
+                    &step_call.steps,
+                    &mut cpis_events_datas,
+                    &mut events_datas,
+                );
+            }
+        }
+        // Self-CPIs that could not be located in the logs go last
+        let mut cpis_events_datas =
+            cpis_events_datas.into_iter().collect::<Vec<_>>();
+        cpis_events_datas.sort_by_key(|(position, _)| *position);
+        for ((instruction_index, _), (cpi_path, event_data)) in
+            cpis_events_datas
+        {
+            events_datas.push((instruction_index, cpi_path, event_data));
+        }
+        let mut events_infos = vec![];
+        for (instruction_index, cpi_path, event_data) in events_datas {
+            let program_id = *cpi_path.last().unwrap();
+            let idl_program = self
+                .get_or_resolve_program(endpoint, &program_id)
+                .await
+                .context("Resolve Program")?
+                .unwrap_or_default();
+            // A single undecodable event should not hide the other ones
+            let idl_event = idl_program.guess_event(event_data);
+            let event_value = match &idl_event {
+                Some(idl_event) => {
+                    idl_event.decode(event_data).with_context(|| {
+                        format!("Decode Event: {}", idl_event.name)
+                    })
+                },
+                None => Err(anyhow!("Unknown event")),
+            };
+            events_infos.push(ToolboxIdlServiceEventInfo {
+                program_id,
+                program: idl_program,
+                event: idl_event.unwrap_or_default(),
+                data: event_data.to_vec(),
+                value: event_value,
+                instruction_index,
+                cpi_path,
+            });
+        }
+        Ok(events_infos)
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn collect_inner_instructions_events_datas<'a>(
+        instruction_index: usize,
+        instruction: Option<&Instruction>,
+        inner_instructions: &'a [ToolboxEndpointExecutionInnerInstruction],
+        cpis_events_datas: &mut HashMap<
+            (usize, usize),
+            (Vec<Pubkey>, &'a [u8]),
+        >,
+    ) {
+        let mut cpi_path = instruction
+            .map(|instruction| vec![instruction.program_id])
+            .unwrap_or_default();
+        for (inner_index, inner_instruction) in
+            inner_instructions.iter().enumerate()
+        {
+            let stack_height = inner_instruction.stack_height.unwrap_or(2);
+            let caller_depth = usize::try_from(stack_height)
+                .unwrap_or_default()
+                .saturating_sub(1)
+                .max(1);
+            cpi_path.truncate(caller_depth);
+            let program_id = inner_instruction.instruction.program_id;
+            let data = &inner_instruction.instruction.data;
+            if cpi_path.last() == Some(&program_id)
+                && data.starts_with(ToolboxIdlEvent::CPI_TAG)
+            {
+                cpis_events_datas.insert(
+                    (instruction_index, inner_index),
+                    (cpi_path.clone(), &data[ToolboxIdlEvent::CPI_TAG.len()..]),
+                );
+            }
+            cpi_path.push(program_id);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn collect_steps_events_datas<'a>(
+        instruction_index: usize,
+        inner_index: &mut usize,
+        cpi_path: &mut Vec<Pubkey>,
+        steps: &'a [ToolboxEndpointExecutionStep],
+        cpis_events_datas: &mut HashMap<
+            (usize, usize),
+            (Vec<Pubkey>, &'a [u8]),
+        >,
+        events_datas: &mut Vec<(usize, Vec<Pubkey>, &'a [u8])>,
+    ) {
+        for step in steps {
+            match step {
+                ToolboxEndpointExecutionStep::Data(data) => {
+                    events_datas.push((
+                        instruction_index,
+                        cpi_path.clone(),
+                        data,
+                    ));
+                },
+                ToolboxEndpointExecutionStep::Call(step_call) => {
+                    if let Some((cpi_event_path, cpi_event_data)) =
+                        cpis_events_datas
+                            .remove(&(instruction_index, *inner_index))
+                    {
+                        events_datas.push((
+                            instruction_index,
+                            cpi_event_path,
+                            cpi_event_data,
+                        ));
+                    }
+                    *inner_index += 1;
+                    cpi_path.push(step_call.program_id);
+                    ToolboxIdlService::collect_steps_events_datas(
+                        instruction_index,
+                        inner_index,
+                        cpi_path,
+                        &step_call.steps,
+                        cpis_events_datas,
+                        events_datas,
+                    );
+                    cpi_path.pop();
+                },
+                _ => {},
+            }
+        }
+    }
+
+    pub async fn infer_and_decode_execution_returns(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<Vec<Option<Result<Value>>>> {
+        let mut returns_values = vec![];
+        for (instruction, step_call) in execution
+            .instructions
+            .iter()
+            .zip(ToolboxIdlService::find_instructions_steps_calls(execution))
+        {
+            let Some(return_data) =
+                step_call.and_then(|step_call| step_call.returns.as_ref())
+            else {
+                returns_values.push(None);
+                continue;
+            };
+            let idl_instruction = self
+                .get_or_resolve_program(endpoint, &instruction.program_id)
+                .await
+                .context("Resolve Program")?
+                .unwrap_or_default()
+                .guess_instruction(&instruction.data);
+            // A single undecodable return should not hide the other ones
+            returns_values.push(Some(match idl_instruction {
+                Some(idl_instruction) => {
+                    idl_instruction.decode_return(return_data).with_context(
+                        || format!("Decode Return: {}", idl_instruction.name),
+                    )
+                },
+                None => Err(anyhow!("Unknown instruction")),
+            }));
+        }
+        Ok(returns_values)
+    }
+
+    pub async fn infer_and_label_execution_flames(
+        &mut self,
+        endpoint: &mut ToolboxEndpoint,
+        execution: &ToolboxEndpointExecution,
+    ) -> Result<Vec<ToolboxEndpointExecutionFlame>> {
+        let mut flames = execution.compute_flames();
+        let mut programs_ids = vec![];
+        ToolboxIdlService::collect_flames_programs_ids(
+            &flames,
+            &mut programs_ids,
+        );
+        let mut programs_names = HashMap::new();
+        for program_id in programs_ids {
+            if programs_names.contains_key(&program_id) {
+                continue;
+            }
+            let program_name = self
+                .get_or_resolve_program(endpoint, &program_id)
+                .await
+                .context("Resolve Program")?
+                .and_then(|idl_program| idl_program.metadata.name.clone())
+                .unwrap_or_else(|| program_id.to_string());
+            programs_names.insert(program_id, program_name);
+        }
+        ToolboxIdlService::label_flames(&mut flames, &programs_names);
+        // Unlogged instructions (precompiles) have no flame, skip them
+        let mut flames_iter = flames.iter_mut();
+        for (instruction, step_call) in execution
+            .instructions
+            .iter()
+            .zip(ToolboxIdlService::find_instructions_steps_calls(execution))
+        {
+            if step_call.is_none() {
+                continue;
+            }
+            let Some(flame) = flames_iter.next() else {
+                break;
+            };
+            if let Some(idl_instruction) = self
+                .get_or_resolve_program(endpoint, &instruction.program_id)
+                .await
+                .context("Resolve Program")?
+                .and_then(|idl_program| {
+                    idl_program.guess_instruction(&instruction.data)
+                })
+            {
+                flame.label =
+                    format!("{}.{}", flame.label, idl_instruction.name);
+            }
+        }
+        Ok(flames)
+    }
+
+    fn collect_flames_programs_ids(
+        flames: &[ToolboxEndpointExecutionFlame],
+        programs_ids: &mut Vec<Pubkey>,
+    ) {
+        for flame in flames {
+            programs_ids.push(flame.program_id);
+            ToolboxIdlService::collect_flames_programs_ids(
+                &flame.children,
+                programs_ids,
+            );
+        }
+    }
+
+    fn label_flames(
+        flames: &mut [ToolboxEndpointExecutionFlame],
+        programs_names: &HashMap<Pubkey, String>,
+    ) {
+        for flame in flames {
+            if let Some(program_name) = programs_names.get(&flame.program_id) {
+                flame.label = program_name.to_string();
+            }
+            ToolboxIdlService::label_flames(
+                &mut flame.children,
+                programs_names,
+            );
+        }
+    }
+}
@@ -16,6 +16,9 @@ pub enum ToolboxIdlTypeFlat {
         prefix: ToolboxIdlTypePrefix,
         content: Box<ToolboxIdlTypeFlat>,
     },
+    OptionZeroable {
+        content: Box<ToolboxIdlTypeFlat>,
+    },
     Vec {
         prefix: ToolboxIdlTypePrefix,
         items: Box<ToolboxIdlTypeFlat>,
@@ -53,6 +53,9 @@ impl ToolboxIdlTypeFlat {
                 };
                 json!({ key: content.export(format) })
             },
+            ToolboxIdlTypeFlat::OptionZeroable { content } => {
+                json!({ "option_zeroable": content.export(format) })
+            },
             ToolboxIdlTypeFlat::Vec { prefix, items } => {
                 let key = match prefix {
                     ToolboxIdlTypePrefix::U8 => "vec8",
@@ -97,6 +97,13 @@ impl ToolboxIdlTypeFlat {
                     content_flat.try_hydrate(generics_by_symbol, typedefs)?,
                 ),
             },
+            ToolboxIdlTypeFlat::OptionZeroable {
+                content: content_flat,
+            } => ToolboxIdlTypeFull::OptionZeroable {
+                content: Box::new(
+                    content_flat.try_hydrate(generics_by_symbol, typedefs)?,
+                ),
+            },
             ToolboxIdlTypeFlat::Vec {
                 prefix,
                 items: items_flat,
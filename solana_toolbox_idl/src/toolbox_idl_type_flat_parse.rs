@@ -35,6 +35,7 @@ impl ToolboxIdlTypeFlat {
             || idl_type_object.contains_key("option32")
             || idl_type_object.contains_key("option64")
             || idl_type_object.contains_key("option128")
+            || idl_type_object.contains_key("option_zeroable")
             || idl_type_object.contains_key("vec")
             || idl_type_object.contains_key("vec8")
             || idl_type_object.contains_key("vec16")
@@ -132,6 +133,9 @@ impl ToolboxIdlTypeFlat {
             )
             .context("Option128");
         }
+        if let Some(idl_option) = idl_type_object.get("option_zeroable") {
+            return ToolboxIdlTypeFlat::try_parse_option_zeroable(idl_option);
+        }
         if let Some(idl_vec) = idl_type_object.get("vec") {
             return ToolboxIdlTypeFlat::try_parse_vec(
                 ToolboxIdlTypePrefix::U32,
@@ -364,6 +368,16 @@ impl ToolboxIdlTypeFlat {
         })
     }
 
+    fn try_parse_option_zeroable(
+        idl_option_content: &Value,
+    ) -> Result<ToolboxIdlTypeFlat> {
+        Ok(ToolboxIdlTypeFlat::OptionZeroable {
+            content: Box::new(ToolboxIdlTypeFlat::try_parse(
+                idl_option_content,
+            )?),
+        })
+    }
+
     fn try_parse_vec(
         idl_vec_prefix: ToolboxIdlTypePrefix,
         idl_vec_items: &Value,
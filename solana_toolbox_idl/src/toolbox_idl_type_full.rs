@@ -1,3 +1,8 @@
+use std::cmp::max;
+
+use anyhow::anyhow;
+use anyhow::Result;
+
 use crate::toolbox_idl_type_prefix::ToolboxIdlTypePrefix;
 use crate::toolbox_idl_type_primitive::ToolboxIdlTypePrimitive;
 
@@ -12,6 +17,9 @@ pub enum ToolboxIdlTypeFull {
         prefix: ToolboxIdlTypePrefix,
         content: Box<ToolboxIdlTypeFull>,
     },
+    OptionZeroable {
+        content: Box<ToolboxIdlTypeFull>,
+    },
     Vec {
         prefix: ToolboxIdlTypePrefix,
         items: Box<ToolboxIdlTypeFull>,
@@ -114,6 +122,40 @@ impl ToolboxIdlTypeFull {
             _ => None,
         }
     }
+
+    pub fn try_fixed_size(&self) -> Result<usize> {
+        Ok(match self {
+            ToolboxIdlTypeFull::Typedef { content, .. } => {
+                content.try_fixed_size()?
+            },
+            ToolboxIdlTypeFull::OptionZeroable { content } => {
+                content.try_fixed_size()?
+            },
+            ToolboxIdlTypeFull::Array { items, length } => {
+                items.try_fixed_size()? * length
+            },
+            ToolboxIdlTypeFull::Struct { fields } => fields.try_fixed_size()?,
+            ToolboxIdlTypeFull::Enum { prefix, variants } => {
+                if variants.iter().any(|variant| !variant.fields.is_empty()) {
+                    return Err(anyhow!("Enum with fields has no fixed size"));
+                }
+                prefix.to_size()
+            },
+            ToolboxIdlTypeFull::Padded {
+                before,
+                min_size,
+                after,
+                content,
+            } => before + max(*min_size, content.try_fixed_size()?) + after,
+            ToolboxIdlTypeFull::Primitive { primitive } => primitive.size(),
+            ToolboxIdlTypeFull::Option { .. }
+            | ToolboxIdlTypeFull::Vec { .. }
+            | ToolboxIdlTypeFull::String { .. }
+            | ToolboxIdlTypeFull::Const { .. } => {
+                return Err(anyhow!("Type has no fixed size: {:?}", self));
+            },
+        })
+    }
 }
 
 impl ToolboxIdlTypeFullFields {
@@ -127,4 +169,21 @@ impl ToolboxIdlTypeFullFields {
             ToolboxIdlTypeFullFields::Unnamed(fields) => fields.is_empty(),
         }
     }
+
+    pub fn try_fixed_size(&self) -> Result<usize> {
+        let mut size = 0;
+        match self {
+            ToolboxIdlTypeFullFields::Named(fields) => {
+                for field in fields {
+                    size += field.content.try_fixed_size()?;
+                }
+            },
+            ToolboxIdlTypeFullFields::Unnamed(fields) => {
+                for field in fields {
+                    size += field.content.try_fixed_size()?;
+                }
+            },
+        }
+        Ok(size)
+    }
 }
@@ -25,6 +25,16 @@ impl ToolboxIdlTypeFull {
             ToolboxIdlTypeFull::Option { prefix, content } => {
                 ToolboxIdlTypeFull::bytemuck_c_option(prefix, *content)?
             },
+            ToolboxIdlTypeFull::OptionZeroable { content } => {
+                let (alignment, size, content_c) = content.bytemuck_c()?;
+                (
+                    alignment,
+                    size,
+                    ToolboxIdlTypeFull::OptionZeroable {
+                        content: Box::new(content_c),
+                    },
+                )
+            },
             ToolboxIdlTypeFull::Vec { .. } => {
                 return Err(anyhow!("Bytemuck: Repr(C): Vec is not supported"));
             },
@@ -25,6 +25,17 @@ impl ToolboxIdlTypeFull {
             ToolboxIdlTypeFull::Option { prefix, content } => {
                 ToolboxIdlTypeFull::bytemuck_rust_option(prefix, *content)?
             },
+            ToolboxIdlTypeFull::OptionZeroable { content } => {
+                let (alignment, size, content_rust) =
+                    content.bytemuck_rust()?;
+                (
+                    alignment,
+                    size,
+                    ToolboxIdlTypeFull::OptionZeroable {
+                        content: Box::new(content_rust),
+                    },
+                )
+            },
             ToolboxIdlTypeFull::Vec { .. } => {
                 return Err(anyhow!(
                     "Bytemuck: Repr(Rust): Vec is not supported"
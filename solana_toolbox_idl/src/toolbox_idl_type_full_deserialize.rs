@@ -51,6 +51,19 @@ impl ToolboxIdlTypeFull {
                     data_offset
                 )
             }),
+            ToolboxIdlTypeFull::OptionZeroable { content } => {
+                ToolboxIdlTypeFull::try_deserialize_option_zeroable(
+                    content,
+                    data,
+                    data_offset,
+                )
+                .with_context(|| {
+                    format!(
+                        "Deserialize Option Zeroable (offset: {})",
+                        data_offset
+                    )
+                })
+            },
             ToolboxIdlTypeFull::Vec { prefix, items, .. } => {
                 ToolboxIdlTypeFull::try_deserialize_vec(
                     prefix,
@@ -174,6 +187,20 @@ impl ToolboxIdlTypeFull {
         Ok((data_size, data_content))
     }
 
+    fn try_deserialize_option_zeroable(
+        option_content: &ToolboxIdlTypeFull,
+        data: &[u8],
+        data_offset: usize,
+    ) -> Result<(usize, Value)> {
+        let option_size = option_content.try_fixed_size()?;
+        let option_bytes =
+            idl_slice_from_bytes(data, data_offset, option_size)?;
+        if option_bytes.iter().all(|byte| *byte == 0) {
+            return Ok((option_size, Value::Null));
+        }
+        option_content.try_deserialize(data, data_offset)
+    }
+
     fn try_deserialize_vec(
         vec_prefix: &ToolboxIdlTypePrefix,
         vec_items: &ToolboxIdlTypeFull,
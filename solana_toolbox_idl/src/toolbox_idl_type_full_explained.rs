@@ -9,7 +9,8 @@ impl ToolboxIdlTypeFull {
     pub fn explained(&self) -> Value {
         match self {
             ToolboxIdlTypeFull::Typedef { content, .. } => content.explained(),
-            ToolboxIdlTypeFull::Option { content, .. } => {
+            ToolboxIdlTypeFull::Option { content, .. }
+            | ToolboxIdlTypeFull::OptionZeroable { content } => {
                 json!({ "option": content.explained() })
             },
             ToolboxIdlTypeFull::Vec { items, .. } => {
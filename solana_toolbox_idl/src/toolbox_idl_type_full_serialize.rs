@@ -44,6 +44,11 @@ impl ToolboxIdlTypeFull {
             } => ToolboxIdlTypeFull::try_serialize_option(
                 prefix, content, value, data, prefixed,
             ),
+            ToolboxIdlTypeFull::OptionZeroable { content } => {
+                ToolboxIdlTypeFull::try_serialize_option_zeroable(
+                    content, value, data, prefixed,
+                )
+            },
             ToolboxIdlTypeFull::Vec { prefix, items, .. } => {
                 ToolboxIdlTypeFull::try_serialize_vec(
                     prefix, items, value, data, prefixed,
@@ -102,6 +107,21 @@ impl ToolboxIdlTypeFull {
         Ok(())
     }
 
+    fn try_serialize_option_zeroable(
+        option_content: &ToolboxIdlTypeFull,
+        value: &Value,
+        data: &mut Vec<u8>,
+        prefixed: bool,
+    ) -> Result<()> {
+        if value.is_null() {
+            let option_size = option_content.try_fixed_size()?;
+            data.resize(data.len() + option_size, 0);
+        } else {
+            option_content.try_serialize(value, data, prefixed)?;
+        }
+        Ok(())
+    }
+
     fn try_serialize_vec(
         vec_prefix: &ToolboxIdlTypePrefix,
         vec_items: &ToolboxIdlTypeFull,
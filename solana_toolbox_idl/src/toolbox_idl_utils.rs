@@ -308,6 +308,12 @@ pub(crate) fn idl_convert_to_camel_case(name: &str) -> String {
     }
 }
 
+pub(crate) fn idl_convert_to_pascal_case(name: &str) -> String {
+    name.without_boundaries(&[Boundary::UPPER_DIGIT])
+        .without_boundaries(&[Boundary::LOWER_DIGIT])
+        .to_case(Case::Pascal)
+}
+
 pub(crate) fn idl_hash_discriminator_from_string(value: &str) -> Vec<u8> {
     let mut hasher = Hasher::default();
     hasher.hash(value.as_bytes());
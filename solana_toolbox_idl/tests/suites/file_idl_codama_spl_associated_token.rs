@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::fs::read_to_string;
+
+use serde_json::from_str;
+use serde_json::json;
+use serde_json::Value;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_idl::ToolboxIdlProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // The token-2022 Codama file also ships the associated token program
+    let codama_root = from_str::<Value>(
+        &read_to_string("./tests/fixtures/idl_codama_spl_token_2022.json")
+            .unwrap(),
+    )
+    .unwrap();
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "kind": "rootNode",
+        "program": codama_root["additionalPrograms"][0],
+    }))
+    .unwrap();
+    assert!(idl_program.warnings.is_empty());
+    // Check the program metadata
+    let program_id = ToolboxEndpoint::SPL_ASSOCIATED_TOKEN_PROGRAM_ID;
+    assert_eq!(idl_program.metadata.address, Some(program_id));
+    assert_eq!(
+        idl_program.metadata.name,
+        Some("associated_token".to_string())
+    );
+    // The associated token accounts default to the token-2022 program
+    let token_program_id =
+        pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+    let find_associated_token_account = |owner: &Pubkey, mint: &Pubkey| {
+        Pubkey::find_program_address(
+            &[owner.as_ref(), token_program_id.as_ref(), mint.as_ref()],
+            &program_id,
+        )
+        .0
+    };
+    // Important account addresses
+    let payer = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    // Generate all missing IX accounts using the PDA links and default values
+    let idl_instruction = idl_program
+        .instructions
+        .get("create_associated_token_idempotent")
+        .unwrap();
+    assert_eq!(idl_instruction.discriminator, vec![1]);
+    let instruction_addresses = idl_instruction.find_addresses(
+        &program_id,
+        &json!({}),
+        &HashMap::from([
+            ("payer".to_string(), payer),
+            ("owner".to_string(), owner),
+            ("mint".to_string(), mint),
+        ]),
+    );
+    // Check outcome
+    assert_eq!(
+        *instruction_addresses.get("ata").unwrap(),
+        find_associated_token_account(&owner, &mint),
+    );
+    assert_eq!(
+        *instruction_addresses.get("system_program").unwrap(),
+        ToolboxEndpoint::SYSTEM_PROGRAM_ID,
+    );
+    assert_eq!(
+        *instruction_addresses.get("token_program").unwrap(),
+        token_program_id,
+    );
+    // Check that the instruction encodes like the native one
+    let instruction = idl_instruction
+        .encode(&program_id, &json!({}), &instruction_addresses)
+        .unwrap();
+    assert_eq!(instruction.data, vec![1]);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(
+                find_associated_token_account(&owner, &mint),
+                false
+            ),
+            AccountMeta::new_readonly(owner, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(
+                ToolboxEndpoint::SYSTEM_PROGRAM_ID,
+                false
+            ),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+    );
+    // Nested accounts chain the PDAs using different accounts as seeds
+    let wallet = Pubkey::new_unique();
+    let owner_mint = Pubkey::new_unique();
+    let nested_mint = Pubkey::new_unique();
+    let owner_ata = find_associated_token_account(&wallet, &owner_mint);
+    let recover_addresses = idl_program
+        .instructions
+        .get("recover_nested_associated_token")
+        .unwrap()
+        .find_addresses(
+            &program_id,
+            &json!({}),
+            &HashMap::from([
+                ("wallet_address".to_string(), wallet),
+                ("owner_token_mint_address".to_string(), owner_mint),
+                ("nested_token_mint_address".to_string(), nested_mint),
+            ]),
+        );
+    assert_eq!(
+        *recover_addresses
+            .get("owner_associated_account_address")
+            .unwrap(),
+        owner_ata,
+    );
+    assert_eq!(
+        *recover_addresses
+            .get("nested_associated_account_address")
+            .unwrap(),
+        find_associated_token_account(&owner_ata, &nested_mint),
+    );
+    assert_eq!(
+        *recover_addresses
+            .get("destination_associated_account_address")
+            .unwrap(),
+        find_associated_token_account(&wallet, &nested_mint),
+    );
+    // Check the errors
+    assert_eq!(idl_program.guess_error(0).unwrap().name, "InvalidOwner");
+}
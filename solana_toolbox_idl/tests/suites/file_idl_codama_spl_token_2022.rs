@@ -0,0 +1,128 @@
+use std::fs::read_to_string;
+
+use serde_json::json;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_idl::ToolboxIdlProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // Parse IDL from the Codama file JSON directly
+    let idl_program = ToolboxIdlProgram::try_parse_from_str(
+        &read_to_string("./tests/fixtures/idl_codama_spl_token_2022.json")
+            .unwrap(),
+    )
+    .unwrap();
+    // Check the program metadata
+    assert_eq!(
+        idl_program.metadata.address,
+        Some(pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"))
+    );
+    assert_eq!(idl_program.metadata.name, Some("token_2022".to_string()));
+    assert_eq!(idl_program.metadata.version, Some("3.0.2".to_string()));
+    // Unsupported items (extensions, unprefixed strings) are skipped
+    let warnings_prefixes = [
+        "Convert Type: Extension: ",
+        "Convert Account: mint: ",
+        "Convert Account: token: ",
+        "Convert Instruction: uiAmountToAmount: ",
+        "Convert Instruction: reallocate: ",
+    ];
+    assert_eq!(idl_program.warnings.len(), warnings_prefixes.len());
+    for (warning, warning_prefix) in
+        idl_program.warnings.iter().zip(warnings_prefixes)
+    {
+        assert!(warning.starts_with(warning_prefix), "{}", warning);
+    }
+    assert!(!idl_program.typedefs.contains_key("Extension"));
+    assert!(!idl_program.accounts.contains_key("Mint"));
+    assert!(!idl_program.instructions.contains_key("reallocate"));
+    assert_eq!(idl_program.instructions.len(), 80);
+    // Accounts are discriminated by their size
+    let idl_account_multisig = idl_program.accounts.get("Multisig").unwrap();
+    assert_eq!(idl_account_multisig.space, Some(355));
+    assert_eq!(idl_account_multisig.discriminator, Vec::<u8>::new());
+    // Encode a multisig and compare with the expected layout
+    let signers = (0..11).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    let multisig_state = json!({
+        "m": 2,
+        "n": 3,
+        "is_initialized": true,
+        "signers": signers
+            .iter()
+            .map(|signer| signer.to_string())
+            .collect::<Vec<_>>(),
+    });
+    let mut multisig_data = vec![2, 3, 1];
+    for signer in &signers {
+        multisig_data.extend_from_slice(signer.as_ref());
+    }
+    assert_eq!(
+        idl_account_multisig.encode(&multisig_state).unwrap(),
+        multisig_data
+    );
+    assert_eq!(
+        idl_account_multisig.decode(&multisig_data).unwrap(),
+        multisig_state
+    );
+    assert_eq!(
+        idl_program.guess_account(&multisig_data).unwrap().name,
+        "Multisig"
+    );
+    // Instructions use their leading discriminator argument
+    let idl_instruction_transfer =
+        idl_program.instructions.get("transfer").unwrap();
+    assert_eq!(idl_instruction_transfer.discriminator, vec![3]);
+    assert_eq!(
+        idl_instruction_transfer
+            .encode_payload(&json!({ "amount": 42 }))
+            .unwrap(),
+        vec![3, 42, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_eq!(
+        idl_program
+            .instructions
+            .get("transfer_checked")
+            .unwrap()
+            .encode_payload(&json!({ "amount": 1, "decimals": 9 }))
+            .unwrap(),
+        vec![12, 1, 0, 0, 0, 0, 0, 0, 0, 9],
+    );
+    assert_eq!(
+        idl_program
+            .instructions
+            .get("set_authority")
+            .unwrap()
+            .encode_payload(&json!({
+                "authority_type": "AccountOwner",
+                "new_authority": null,
+            }))
+            .unwrap(),
+        vec![6, 2, 0],
+    );
+    assert_eq!(
+        idl_program.guess_instruction(&[21]).unwrap().name,
+        "get_account_data_size"
+    );
+    // Accounts with default values get their address
+    let idl_instruction_initialize_mint =
+        idl_program.instructions.get("initialize_mint").unwrap();
+    assert_eq!(idl_instruction_initialize_mint.accounts[0].name, "mint");
+    assert!(idl_instruction_initialize_mint.accounts[0].writable);
+    assert_eq!(
+        idl_instruction_initialize_mint.accounts[1].address,
+        Some(solana_sdk::sysvar::rent::ID)
+    );
+    // Signers that can be a multisig are left to the caller
+    assert!(!idl_instruction_transfer.accounts[2].signer);
+    // Check the defined types and errors
+    assert!(idl_program.typedefs.contains_key("AccountState"));
+    assert!(idl_program.typedefs.contains_key("AuthorityType"));
+    assert!(idl_program.typedefs.contains_key("ExtensionType"));
+    let idl_error = idl_program.guess_error(3).unwrap();
+    assert_eq!(idl_error.name, "MintMismatch");
+    assert_eq!(
+        idl_error.msg,
+        Some("Account not associated with this Mint".to_string())
+    );
+}
@@ -18,6 +18,14 @@ pub mod file_idl_anchor_generics_export;
 pub mod file_idl_anchor_generics_typing;
 pub mod file_idl_anchor_new_export;
 pub mod file_idl_anchor_old_export;
+pub mod file_idl_codama_spl_associated_token;
+pub mod file_idl_codama_spl_token_2022;
+pub mod program_test_execution_error;
+pub mod program_test_execution_events;
+pub mod program_test_execution_events_cpi;
+pub mod program_test_execution_flames;
+pub mod program_test_execution_returns;
+pub mod program_test_printer;
 pub mod unit_export_lib_programs;
 pub mod unit_export_program_account;
 pub mod unit_export_program_enum;
@@ -37,6 +45,7 @@ pub mod unit_parse_account_inlined;
 pub mod unit_parse_error;
 pub mod unit_parse_instruction_basics;
 pub mod unit_parse_program_basic;
+pub mod unit_parse_program_codama;
 pub mod unit_parse_typedef_enum;
 pub mod unit_parse_typedef_enum_generics;
 pub mod unit_parse_typedef_enum_variants;
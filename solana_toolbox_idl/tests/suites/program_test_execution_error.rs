@@ -0,0 +1,228 @@
+use serde_json::json;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::create_account;
+use solana_sdk::transaction::TransactionError;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_idl::ToolboxIdlProgram;
+use solana_toolbox_idl::ToolboxIdlService;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Prepare custom programs, one with a known IDL
+    let program_id1 = Pubkey::new_unique();
+    let program_id2 = Pubkey::new_unique();
+    let mut idl_service = ToolboxIdlService::new();
+    idl_service.set_program(
+        &program_id1,
+        Some(
+            ToolboxIdlProgram::try_parse(&json!({
+                "name": "my_program",
+                "errors": {
+                    "MyError": {
+                        "code": 6000,
+                        "msg": "My error message",
+                        "docs": ["My error docs"],
+                    },
+                },
+            }))
+            .unwrap()
+            .into(),
+        ),
+    );
+    idl_service.set_program(&program_id2, None);
+    // Run a builtin instruction that should fail
+    let account = Keypair::new();
+    let (_, execution) = endpoint
+        .process_instructions_with_options(
+            &payer,
+            &[create_account(
+                &payer.pubkey(),
+                &account.pubkey(),
+                10_000_000_000,
+                42,
+                &program_id1,
+            )],
+            &[&account],
+            &[],
+            false,
+        )
+        .await
+        .unwrap();
+    let error_info = idl_service
+        .infer_and_decode_execution_error(&mut endpoint, &execution)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.program_id, ToolboxEndpoint::SYSTEM_PROGRAM_ID);
+    assert_eq!(error_info.instruction_index, 0);
+    assert_eq!(error_info.error.name, "ResultWithNegativeLamports");
+    assert_eq!(error_info.error.code, 1);
+    // Check that an error raised in a CPI is resolved using the IDL
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(
+                &[program_id2, program_id1],
+                vec![
+                    format!("Program {} invoke [1]", program_id2),
+                    format!("Program {} success", program_id2),
+                    format!("Program {} invoke [1]", program_id2),
+                    format!("Program {} invoke [2]", program_id1),
+                    format!(
+                        "Program {} failed: custom program error: 0x1770",
+                        program_id1
+                    ),
+                    format!(
+                        "Program {} failed: custom program error: 0x1770",
+                        program_id2
+                    ),
+                ],
+                1,
+                6000,
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.program_id, program_id1);
+    assert_eq!(error_info.instruction_index, 1);
+    assert_eq!(error_info.error.name, "MyError");
+    assert_eq!(error_info.error.msg, Some("My error message".to_string()));
+    assert_eq!(error_info.error.docs, Some(json!(["My error docs"])));
+    // Check that anchor's framework errors are known
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(&[program_id2], vec![], 0, 2000),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.program_id, program_id2);
+    assert_eq!(error_info.error.name, "ConstraintMut");
+    assert_eq!(
+        error_info.error.msg,
+        Some("A mut constraint was violated".to_string())
+    );
+    // Check that builtin programs errors are known
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(
+                &[ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID],
+                vec![],
+                0,
+                1,
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.error.name, "InsufficientFunds");
+    // Check that newer anchor's framework errors are known
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(&[program_id2], vec![], 0, 2040),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.error.name, "ConstraintDuplicateMutableAccount");
+    // Check that anchor's framework errors are not used for non-anchor programs
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(
+                &[ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID],
+                vec![],
+                0,
+                2000,
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.error.name, "UnknownError");
+    assert_eq!(error_info.error.code, 2000);
+    // Check that the anchor logs are used when the IDL is not available
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(
+                &[program_id2],
+                vec![
+                    format!("Program {} invoke [1]", program_id2),
+                    "Program log: AnchorError occurred. Error Code: Oops. Error Number: 6042. Error Message: Something went wrong.".to_string(),
+                    format!(
+                        "Program {} failed: custom program error: 0x179a",
+                        program_id2
+                    ),
+                ],
+                0,
+                6042,
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.error.name, "Oops");
+    assert_eq!(error_info.error.code, 6042);
+    assert_eq!(
+        error_info.error.msg,
+        Some("Something went wrong".to_string())
+    );
+    // Check that unknown errors still have their code
+    let error_info = idl_service
+        .infer_and_decode_execution_error(
+            &mut endpoint,
+            &dummy_execution(&[program_id2], vec![], 0, 6043),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_info.error.name, "UnknownError");
+    assert_eq!(error_info.error.code, 6043);
+}
+
+fn dummy_execution(
+    programs_ids: &[Pubkey],
+    logs: Vec<String>,
+    instruction_index: u8,
+    error_code: u32,
+) -> ToolboxEndpointExecution {
+    ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 0,
+        payer: Pubkey::new_unique(),
+        instructions: programs_ids
+            .iter()
+            .map(|program_id| Instruction {
+                program_id: *program_id,
+                accounts: vec![],
+                data: vec![],
+            })
+            .collect(),
+        steps: Some(ToolboxEndpointExecution::try_parse_steps(&logs).unwrap()),
+        inner_instructions: None,
+        logs: Some(logs),
+        error: Some(TransactionError::InstructionError(
+            instruction_index,
+            InstructionError::Custom(error_code),
+        )),
+        units_consumed: None,
+    }
+}
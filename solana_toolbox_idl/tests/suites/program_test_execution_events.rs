@@ -0,0 +1,151 @@
+use serde_json::json;
+use solana_sdk::ed25519_program;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_idl::ToolboxIdlProgram;
+use solana_toolbox_idl::ToolboxIdlService;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Prepare two custom programs with known IDLs
+    let program_id1 = Pubkey::new_unique();
+    let program_id2 = Pubkey::new_unique();
+    let mut idl_service = ToolboxIdlService::new();
+    idl_service.set_program(
+        &program_id1,
+        Some(
+            ToolboxIdlProgram::try_parse(&json!({
+                "events": {
+                    "MyEvent1": {
+                        "discriminator": [11],
+                        "fields": [{ "name": "value", "type": "u8" }],
+                    },
+                },
+            }))
+            .unwrap()
+            .into(),
+        ),
+    );
+    idl_service.set_program(
+        &program_id2,
+        Some(
+            ToolboxIdlProgram::try_parse(&json!({
+                "events": {
+                    "MyEvent2": {
+                        "discriminator": [22],
+                        "fields": [{ "name": "flag", "type": "bool" }],
+                    },
+                },
+            }))
+            .unwrap()
+            .into(),
+        ),
+    );
+    // Events emitted from within CPIs, across two instructions after a precompile
+    let logs = vec![
+        format!("Program {} invoke [1]", program_id1),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[11, 1])),
+        format!("Program {} invoke [2]", program_id2),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[22, 1])),
+        format!("Program {} success", program_id2),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[11, 2])),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[11])),
+        format!("Program {} success", program_id1),
+        format!("Program {} invoke [1]", program_id2),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[99])),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[22, 0])),
+        format!("Program {} success", program_id2),
+    ];
+    let execution = ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 0,
+        payer: Pubkey::new_unique(),
+        instructions: vec![
+            Instruction {
+                program_id: ed25519_program::ID,
+                accounts: vec![],
+                data: vec![],
+            },
+            Instruction {
+                program_id: program_id1,
+                accounts: vec![],
+                data: vec![],
+            },
+            Instruction {
+                program_id: program_id2,
+                accounts: vec![],
+                data: vec![],
+            },
+        ],
+        steps: Some(ToolboxEndpointExecution::try_parse_steps(&logs).unwrap()),
+        inner_instructions: None,
+        logs: Some(logs),
+        error: None,
+        units_consumed: None,
+    };
+    // Decode all events in order, undecodable events are kept with their error
+    let events = idl_service
+        .infer_and_decode_execution_events(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (
+                event.program_id,
+                event.event.name.to_string(),
+                event.value.as_ref().ok().cloned(),
+                event.instruction_index,
+                event.cpi_path.clone(),
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                program_id1,
+                "MyEvent1".to_string(),
+                Some(json!({ "value": 1 })),
+                1,
+                vec![program_id1],
+            ),
+            (
+                program_id2,
+                "MyEvent2".to_string(),
+                Some(json!({ "flag": true })),
+                1,
+                vec![program_id1, program_id2],
+            ),
+            (
+                program_id1,
+                "MyEvent1".to_string(),
+                Some(json!({ "value": 2 })),
+                1,
+                vec![program_id1],
+            ),
+            (
+                program_id1,
+                "MyEvent1".to_string(),
+                None,
+                1,
+                vec![program_id1],
+            ),
+            (
+                program_id2,
+                "Unknown".to_string(),
+                None,
+                2,
+                vec![program_id2]
+            ),
+            (
+                program_id2,
+                "MyEvent2".to_string(),
+                Some(json!({ "flag": false })),
+                2,
+                vec![program_id2],
+            ),
+        ]
+    );
+}
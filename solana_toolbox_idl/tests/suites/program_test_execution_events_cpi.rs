@@ -0,0 +1,160 @@
+use serde_json::json;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionInnerInstruction;
+use solana_toolbox_idl::ToolboxIdlEvent;
+use solana_toolbox_idl::ToolboxIdlProgram;
+use solana_toolbox_idl::ToolboxIdlService;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Prepare two custom programs with known IDLs
+    let program_id1 = Pubkey::new_unique();
+    let program_id2 = Pubkey::new_unique();
+    let mut idl_service = ToolboxIdlService::new();
+    idl_service.set_program(
+        &program_id1,
+        Some(
+            ToolboxIdlProgram::try_parse(&json!({
+                "events": {
+                    "MyEvent1": {
+                        "discriminator": [11],
+                        "fields": [{ "name": "value", "type": "u8" }],
+                    },
+                },
+            }))
+            .unwrap()
+            .into(),
+        ),
+    );
+    idl_service.set_program(
+        &program_id2,
+        Some(
+            ToolboxIdlProgram::try_parse(&json!({
+                "events": {
+                    "MyEvent2": {
+                        "discriminator": [22],
+                        "fields": [{ "name": "flag", "type": "bool" }],
+                    },
+                },
+            }))
+            .unwrap()
+            .into(),
+        ),
+    );
+    // Events emitted through self-CPIs (and one through the logs)
+    let logs = vec![
+        format!("Program {} invoke [1]", program_id1),
+        format!("Program data: {}", ToolboxEndpoint::encode_base64(&[11, 1])),
+        format!("Program {} invoke [2]", program_id1),
+        format!("Program {} success", program_id1),
+        format!("Program {} invoke [2]", program_id2),
+        format!("Program {} invoke [3]", program_id2),
+        format!("Program {} success", program_id2),
+        format!("Program {} success", program_id2),
+        format!("Program {} success", program_id1),
+    ];
+    let inner_instruction = |program_id, data, stack_height| {
+        ToolboxEndpointExecutionInnerInstruction {
+            instruction: Instruction {
+                program_id,
+                accounts: vec![],
+                data,
+            },
+            stack_height: Some(stack_height),
+        }
+    };
+    let execution = ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 0,
+        payer: Pubkey::new_unique(),
+        instructions: vec![Instruction {
+            program_id: program_id1,
+            accounts: vec![],
+            data: vec![],
+        }],
+        steps: Some(ToolboxEndpointExecution::try_parse_steps(&logs).unwrap()),
+        inner_instructions: Some(vec![vec![
+            inner_instruction(
+                program_id1,
+                [ToolboxIdlEvent::CPI_TAG, &[11, 5]].concat(),
+                2,
+            ),
+            inner_instruction(program_id2, vec![], 2),
+            inner_instruction(
+                program_id2,
+                [ToolboxIdlEvent::CPI_TAG, &[22, 1]].concat(),
+                3,
+            ),
+        ]]),
+        logs: Some(logs),
+        error: None,
+        units_consumed: None,
+    };
+    // Decode all events in order
+    let events = idl_service
+        .infer_and_decode_execution_events(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (
+                event.program_id,
+                event.event.name.to_string(),
+                event.value.as_ref().ok().cloned(),
+                event.instruction_index,
+                event.cpi_path.clone(),
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                program_id1,
+                "MyEvent1".to_string(),
+                Some(json!({ "value": 1 })),
+                0,
+                vec![program_id1],
+            ),
+            (
+                program_id1,
+                "MyEvent1".to_string(),
+                Some(json!({ "value": 5 })),
+                0,
+                vec![program_id1],
+            ),
+            (
+                program_id2,
+                "MyEvent2".to_string(),
+                Some(json!({ "flag": true })),
+                0,
+                vec![program_id1, program_id2],
+            ),
+        ]
+    );
+    // Without logs, the self-CPIs events can still be decoded
+    let events = idl_service
+        .infer_and_decode_execution_events(
+            &mut endpoint,
+            &ToolboxEndpointExecution {
+                steps: None,
+                logs: None,
+                ..execution
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (event.program_id, event.cpi_path.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (program_id1, vec![program_id1]),
+            (program_id2, vec![program_id1, program_id2]),
+        ]
+    );
+}
@@ -0,0 +1,113 @@
+use serde_json::json;
+use solana_sdk::ed25519_program;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionFlame;
+use solana_toolbox_idl::ToolboxIdlProgram;
+use solana_toolbox_idl::ToolboxIdlService;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Run a builtin instruction and label its flames
+    let (_, execution) = endpoint
+        .process_instruction(
+            &payer,
+            transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000_000),
+        )
+        .await
+        .unwrap();
+    let mut idl_service = ToolboxIdlService::new();
+    let flames = idl_service
+        .infer_and_label_execution_flames(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(flames.len(), 1);
+    assert_eq!(flames[0].label, "system.transfer");
+    // Prepare a custom program with a known IDL
+    let program_id = Pubkey::new_unique();
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "name": "my_program",
+        "instructions": {
+            "my_ix": { "discriminator": [77], "accounts": [], "args": [] },
+        },
+    }))
+    .unwrap();
+    idl_service.set_program(&program_id, Some(idl_program.into()));
+    // Label a nested execution
+    let program_unknown = Pubkey::new_unique();
+    let logs = vec![
+        format!("Program {} invoke [1]", program_id),
+        format!("Program {} invoke [2]", program_unknown),
+        format!(
+            "Program {} consumed 100 of 1000 compute units",
+            program_unknown
+        ),
+        format!("Program {} success", program_unknown),
+        format!("Program {} invoke [2]", ToolboxEndpoint::SYSTEM_PROGRAM_ID),
+        format!("Program {} success", ToolboxEndpoint::SYSTEM_PROGRAM_ID),
+        format!("Program {} consumed 500 of 2000 compute units", program_id),
+        format!("Program {} success", program_id),
+    ];
+    let execution = ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 0,
+        payer: payer.pubkey(),
+        instructions: vec![Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![77],
+        }],
+        steps: Some(ToolboxEndpointExecution::try_parse_steps(&logs).unwrap()),
+        inner_instructions: None,
+        logs: Some(logs),
+        error: None,
+        units_consumed: Some(500),
+    };
+    let flames = idl_service
+        .infer_and_label_execution_flames(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(
+        ToolboxEndpointExecutionFlame::export_folded(&flames),
+        format!(
+            "my_program.my_ix 400\nmy_program.my_ix;{} 100\n",
+            program_unknown
+        )
+    );
+    assert_eq!(flames[0].children[1].label, "system");
+    // Precompiles are not logged and must not shift the instructions labels
+    let execution = ToolboxEndpointExecution {
+        instructions: vec![
+            Instruction {
+                program_id: ed25519_program::ID,
+                accounts: vec![],
+                data: vec![],
+            },
+            Instruction {
+                program_id,
+                accounts: vec![],
+                data: vec![77],
+            },
+        ],
+        ..execution
+    };
+    let flames = idl_service
+        .infer_and_label_execution_flames(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(flames.len(), 1);
+    assert_eq!(flames[0].label, "my_program.my_ix");
+}
@@ -0,0 +1,114 @@
+use serde_json::json;
+use solana_sdk::ed25519_program;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::transfer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_idl::ToolboxIdlProgram;
+use solana_toolbox_idl::ToolboxIdlService;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Create a mint
+    let mint = endpoint
+        .process_spl_token_mint_new(&payer, &payer.pubkey(), None, 6)
+        .await
+        .unwrap();
+    // Simulate a view-style instruction along with a regular instruction
+    let execution = endpoint
+        .simulate_instructions_with_signers(
+            &payer,
+            &[
+                Instruction {
+                    program_id: ToolboxEndpoint::SPL_TOKEN_PROGRAM_ID,
+                    accounts: vec![AccountMeta::new_readonly(mint, false)],
+                    data: vec![21],
+                },
+                transfer(&payer.pubkey(), &payer.pubkey(), 1),
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+    // Decode the returned values using the IDL
+    let mut idl_service = ToolboxIdlService::new();
+    let returns_values = idl_service
+        .infer_and_decode_execution_returns(&mut endpoint, &execution)
+        .await
+        .unwrap();
+    assert_eq!(returns_values.len(), 2);
+    assert_eq!(
+        returns_values[0].as_ref().unwrap().as_ref().unwrap(),
+        &json!(165)
+    );
+    assert!(returns_values[1].is_none());
+    // Returns after an unlogged precompile must stay on their instruction
+    let mut execution_with_precompile = execution.clone();
+    execution_with_precompile.instructions.insert(
+        0,
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data: vec![],
+        },
+    );
+    let returns_values = idl_service
+        .infer_and_decode_execution_returns(
+            &mut endpoint,
+            &execution_with_precompile,
+        )
+        .await
+        .unwrap();
+    assert_eq!(returns_values.len(), 3);
+    assert!(returns_values[0].is_none());
+    assert_eq!(
+        returns_values[1].as_ref().unwrap().as_ref().unwrap(),
+        &json!(165)
+    );
+    assert!(returns_values[2].is_none());
+    // An undecodable return is reported without failing the others
+    let mut execution_with_unknown = execution.clone();
+    execution_with_unknown.instructions[0].data = vec![255];
+    let returns_values = idl_service
+        .infer_and_decode_execution_returns(
+            &mut endpoint,
+            &execution_with_unknown,
+        )
+        .await
+        .unwrap();
+    assert!(returns_values[0].as_ref().unwrap().is_err());
+    assert!(returns_values[1].is_none());
+    // Check that a more complex return type can be decoded directly
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "instructions": {
+            "my_view": {
+                "accounts": [],
+                "args": [],
+                "returns": {
+                    "fields": [
+                        { "name": "count", "type": "u16" },
+                        { "name": "values", "type": ["u8"] },
+                    ]
+                },
+            },
+        },
+    }))
+    .unwrap();
+    let idl_instruction = idl_program.instructions.get("my_view").unwrap();
+    assert_eq!(
+        idl_instruction
+            .decode_return(&[42, 0, 2, 0, 0, 0, 7, 8])
+            .unwrap(),
+        json!({ "count": 42, "values": [7, 8] }),
+    );
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::json;
+use solana_sdk::ed25519_program;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::create_account;
+use solana_sdk::transaction::TransactionError;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointExecution;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionInnerInstruction;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStep;
+use solana_toolbox_endpoint::ToolboxEndpointExecutionStepCall;
+use solana_toolbox_idl::ToolboxIdlEvent;
+use solana_toolbox_idl::ToolboxIdlPrinter;
+use solana_toolbox_idl::ToolboxIdlProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // Initialize the endpoint, using the IDL printer as a logger
+    let mut endpoint = ToolboxEndpoint::new_program_test().await;
+    endpoint.add_logger(Box::new(ToolboxIdlPrinter::new()));
+    // Make a payer
+    let payer = Keypair::new();
+    endpoint
+        .request_airdrop(&payer.pubkey(), 2_000_000_000)
+        .await
+        .unwrap();
+    // Run a system instruction
+    let account = Keypair::new();
+    let (_, execution) = endpoint
+        .process_instruction_with_signers(
+            &payer,
+            create_account(
+                &payer.pubkey(),
+                &account.pubkey(),
+                100_000_000,
+                42,
+                &Pubkey::new_unique(),
+            ),
+            &[&account],
+        )
+        .await
+        .unwrap();
+    // The builtin IDLs should be used to decode the instruction
+    let mut printer = ToolboxIdlPrinter::new();
+    let rendered = printer
+        .render_execution(&mut endpoint, &execution, &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(
+        rendered[0],
+        format!(
+            "execution: slot: {}, payer: {}",
+            execution.slot,
+            payer.pubkey()
+        )
+    );
+    assert_eq!(rendered[1], "├─ instruction #1: system.create");
+    assert_eq!(rendered[2], "│  ├─ accounts");
+    assert_eq!(
+        rendered[3],
+        format!("│  │  ├─ #001: payer: {} (WS)", payer.pubkey())
+    );
+    assert_eq!(
+        rendered[4],
+        format!("│  │  └─ #002: created: {} (WS)", account.pubkey())
+    );
+    assert!(rendered[5].starts_with("│  └─ payload: {\"lamports\":100000000,"));
+    assert!(rendered.contains(&"├─ result: Ok".to_string()));
+    // Accounts can be printed with their decoded state
+    let account_state = endpoint.get_account(&account.pubkey()).await.unwrap();
+    let rendered = printer
+        .render_account(&mut endpoint, &account.pubkey(), &account_state)
+        .await
+        .unwrap();
+    assert_eq!(rendered[0], format!("account: {}", account.pubkey()));
+    assert_eq!(rendered[1], "├─ lamports: 100000000");
+    // Prepare a custom program IDL with events and errors
+    let program_id = Pubkey::new_unique();
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "name": "my_program",
+        "instructions": {
+            "my_ix": {
+                "discriminator": [77],
+                "accounts": [{ "name": "user", "signer": true }],
+                "args": [{ "name": "amount", "type": "u16" }],
+            },
+        },
+        "events": {
+            "MyEvent": {
+                "discriminator": [12, 34],
+                "fields": [{ "name": "value", "type": "u8" }],
+            },
+        },
+        "errors": {
+            "MyError": { "code": 42, "msg": "Something went wrong" },
+        },
+    }))
+    .unwrap();
+    printer.set_program(&program_id, Some(Arc::new(idl_program)));
+    // Render a failed execution with events and CPIs
+    let user = Pubkey::new_unique();
+    let execution = ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 42,
+        payer: user,
+        instructions: vec![
+            Instruction {
+                program_id: ed25519_program::ID,
+                accounts: vec![],
+                data: vec![],
+            },
+            Instruction {
+                program_id,
+                accounts: vec![AccountMeta::new_readonly(user, true)],
+                data: vec![77, 1, 0],
+            },
+        ],
+        steps: Some(vec![ToolboxEndpointExecutionStep::Call(
+            ToolboxEndpointExecutionStepCall {
+                program_id,
+                steps: vec![
+                    ToolboxEndpointExecutionStep::Log("Hello".to_string()),
+                    ToolboxEndpointExecutionStep::Data(vec![12, 34, 99]),
+                    ToolboxEndpointExecutionStep::Call(
+                        ToolboxEndpointExecutionStepCall {
+                            program_id: ToolboxEndpoint::SYSTEM_PROGRAM_ID,
+                            steps: vec![],
+                            consumed: None,
+                            returns: None,
+                            failure: None,
+                        },
+                    ),
+                ],
+                consumed: Some((1_000, 200_000)),
+                returns: None,
+                failure: Some("custom program error: 0x2a".to_string()),
+            },
+        )]),
+        inner_instructions: None,
+        logs: None,
+        error: Some(TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(42),
+        )),
+        units_consumed: Some(1_000),
+    };
+    // The precompile is not logged, the steps must go to the next instruction
+    assert_eq!(
+        printer
+            .render_execution(&mut endpoint, &execution, &HashMap::new())
+            .await
+            .unwrap(),
+        vec![
+            format!("execution: slot: 42, payer: {}", user),
+            format!(
+                "├─ instruction #1: {}.?",
+                ed25519_program::ID
+            ),
+            "│  ├─ accounts".to_string(),
+            "│  └─ data: ".to_string(),
+            "├─ instruction #2: my_program.my_ix".to_string(),
+            "│  ├─ accounts".to_string(),
+            format!("│  │  └─ #001: user: {} (RS)", user),
+            "│  ├─ payload: {\"amount\":1}".to_string(),
+            "│  ├─ log: Hello".to_string(),
+            "│  ├─ event: my_program.MyEvent: {\"value\":99}".to_string(),
+            "│  ├─ invoke: system".to_string(),
+            "│  ├─ consumed: 1000 of 200000 compute units".to_string(),
+            "│  └─ failure: custom program error: 0x2a (my_program.MyError: Something went wrong)".to_string(),
+            "├─ result: Err(InstructionError(1, Custom(42)))".to_string(),
+            "│  └─ error: my_program.MyError: Something went wrong".to_string(),
+            "└─ units_consumed: 1000".to_string(),
+        ]
+    );
+    // Render a CPI failure in another program, with an emit_cpi event
+    let other_program_id = Pubkey::new_unique();
+    let execution = ToolboxEndpointExecution {
+        processed_time: None,
+        slot: 42,
+        payer: user,
+        instructions: vec![Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new_readonly(user, true)],
+            data: vec![77, 2, 0],
+        }],
+        steps: Some(vec![ToolboxEndpointExecutionStep::Call(
+            ToolboxEndpointExecutionStepCall {
+                program_id,
+                steps: vec![
+                    ToolboxEndpointExecutionStep::Call(
+                        ToolboxEndpointExecutionStepCall {
+                            program_id,
+                            steps: vec![],
+                            consumed: None,
+                            returns: None,
+                            failure: None,
+                        },
+                    ),
+                    ToolboxEndpointExecutionStep::Call(
+                        ToolboxEndpointExecutionStepCall {
+                            program_id: other_program_id,
+                            steps: vec![ToolboxEndpointExecutionStep::Log(
+                                "AnchorError occurred. Error Code: OtherError. Error Number: 42. Error Message: Other went wrong.".to_string(),
+                            )],
+                            consumed: None,
+                            returns: None,
+                            failure: Some(
+                                "custom program error: 0x2a".to_string(),
+                            ),
+                        },
+                    ),
+                ],
+                consumed: None,
+                returns: None,
+                failure: Some("custom program error: 0x2a".to_string()),
+            },
+        )]),
+        inner_instructions: Some(vec![vec![
+            ToolboxEndpointExecutionInnerInstruction {
+                instruction: Instruction {
+                    program_id,
+                    accounts: vec![],
+                    data: [ToolboxIdlEvent::CPI_TAG, &[12, 34, 7]].concat(),
+                },
+                stack_height: Some(2),
+            },
+            ToolboxEndpointExecutionInnerInstruction {
+                instruction: Instruction {
+                    program_id: other_program_id,
+                    accounts: vec![],
+                    data: vec![],
+                },
+                stack_height: Some(2),
+            },
+        ]]),
+        logs: None,
+        error: Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(42),
+        )),
+        units_consumed: None,
+    };
+    // The error must be named after the deepest failing program
+    let rendered = printer
+        .render_execution(&mut endpoint, &execution, &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(
+        rendered[5..],
+        vec![
+            "│  ├─ invoke: my_program".to_string(),
+            "│  │  └─ event: my_program.MyEvent: {\"value\":7}".to_string(),
+            format!("│  ├─ invoke: {}", other_program_id),
+            "│  │  ├─ log: AnchorError occurred. Error Code: OtherError. Error Number: 42. Error Message: Other went wrong.".to_string(),
+            format!("│  │  └─ failure: custom program error: 0x2a ({}.OtherError: Other went wrong)", other_program_id),
+            "│  └─ failure: custom program error: 0x2a (my_program.MyError: Something went wrong)".to_string(),
+            "└─ result: Err(InstructionError(0, Custom(42)))".to_string(),
+            format!("   └─ error: {}.OtherError: Other went wrong", other_program_id),
+        ]
+    );
+}
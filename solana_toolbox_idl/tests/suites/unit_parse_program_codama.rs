@@ -0,0 +1,267 @@
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_idl::ToolboxIdlProgram;
+
+#[tokio::test]
+pub async fn run() {
+    // Create a Codama IDL on the fly
+    let number = |format: &str| json!({ "kind": "numberTypeNode", "format": format, "endian": "le" });
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "kind": "rootNode",
+        "standard": "codama",
+        "version": "1.0.0",
+        "program": {
+            "kind": "programNode",
+            "name": "myProgram",
+            "publicKey": "UC2cQRtrbGmvuLKA3Jv719Cc6DS4r661ZRpyZduxu2j",
+            "version": "0.1.0",
+            "accounts": [{
+                "kind": "accountNode",
+                "name": "myConfig",
+                "data": {
+                    "kind": "structTypeNode",
+                    "fields": [
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "shape",
+                            "type": { "kind": "definedTypeLinkNode", "name": "myShape" },
+                        },
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "pairs",
+                            "type": {
+                                "kind": "arrayTypeNode",
+                                "item": { "kind": "definedTypeLinkNode", "name": "myPair" },
+                                "count": { "kind": "prefixedCountNode", "prefix": number("u8") },
+                            },
+                        },
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "lookup",
+                            "type": {
+                                "kind": "mapTypeNode",
+                                "key": number("u8"),
+                                "value": number("u16"),
+                                "count": { "kind": "prefixedCountNode", "prefix": number("u32") },
+                            },
+                        },
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "label",
+                            "type": {
+                                "kind": "fixedSizeTypeNode",
+                                "size": 4,
+                                "type": { "kind": "bytesTypeNode" },
+                            },
+                        },
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "authority",
+                            "type": { "kind": "definedTypeLinkNode", "name": "myMaybeKey" },
+                        },
+                    ],
+                },
+                "discriminators": [{
+                    "kind": "constantDiscriminatorNode",
+                    "offset": 0,
+                    "constant": {
+                        "kind": "constantValueNode",
+                        "type": { "kind": "bytesTypeNode" },
+                        "value": { "kind": "bytesValueNode", "encoding": "base16", "data": "c0ffee" },
+                    },
+                }],
+            }],
+            "instructions": [],
+            "events": [{
+                "kind": "eventNode",
+                "name": "myUpdated",
+                "data": {
+                    "kind": "structTypeNode",
+                    "fields": [
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "discriminator",
+                            "type": {
+                                "kind": "fixedSizeTypeNode",
+                                "size": 8,
+                                "type": { "kind": "bytesTypeNode" },
+                            },
+                            "defaultValue": {
+                                "kind": "bytesValueNode",
+                                "encoding": "base16",
+                                "data": "0102030405060708",
+                            },
+                            "defaultValueStrategy": "omitted",
+                        },
+                        {
+                            "kind": "structFieldTypeNode",
+                            "name": "newValue",
+                            "type": number("u64"),
+                        },
+                    ],
+                },
+                "discriminators": [{
+                    "kind": "fieldDiscriminatorNode",
+                    "name": "discriminator",
+                    "offset": 0,
+                }],
+            }],
+            "definedTypes": [
+                {
+                    "kind": "definedTypeNode",
+                    "name": "myPair",
+                    "type": {
+                        "kind": "tupleTypeNode",
+                        "items": [number("u16"), { "kind": "publicKeyTypeNode" }],
+                    },
+                },
+                {
+                    "kind": "definedTypeNode",
+                    "name": "myShape",
+                    "type": {
+                        "kind": "enumTypeNode",
+                        "size": number("u8"),
+                        "variants": [
+                            {
+                                "kind": "enumStructVariantTypeNode",
+                                "name": "circle",
+                                "struct": {
+                                    "kind": "structTypeNode",
+                                    "fields": [{
+                                        "kind": "structFieldTypeNode",
+                                        "name": "radius",
+                                        "type": number("u32"),
+                                    }],
+                                },
+                            },
+                            {
+                                "kind": "enumTupleVariantTypeNode",
+                                "name": "rectangle",
+                                "discriminator": 7,
+                                "tuple": {
+                                    "kind": "tupleTypeNode",
+                                    "items": [number("u8"), number("u8")],
+                                },
+                            },
+                        ],
+                    },
+                },
+                {
+                    "kind": "definedTypeNode",
+                    "name": "myMaybeKey",
+                    "type": {
+                        "kind": "zeroableOptionTypeNode",
+                        "item": { "kind": "publicKeyTypeNode" },
+                    },
+                },
+            ],
+            "pdas": [],
+            "errors": [{
+                "kind": "errorNode",
+                "name": "myError",
+                "code": 6000,
+                "message": "My error message",
+            }],
+        },
+        "additionalPrograms": [],
+    }))
+    .unwrap();
+    // Check the program metadata
+    assert_eq!(idl_program.metadata.name, Some("my_program".to_string()));
+    assert_eq!(idl_program.metadata.version, Some("0.1.0".to_string()));
+    // Check the defined types
+    assert!(idl_program.typedefs.contains_key("MyPair"));
+    assert!(idl_program.typedefs.contains_key("MyShape"));
+    assert!(idl_program.typedefs.contains_key("MyMaybeKey"));
+    // Check the account encoding using the constant discriminator
+    let pubkey = Pubkey::new_unique();
+    let idl_account = idl_program.accounts.get("MyConfig").unwrap();
+    assert_eq!(idl_account.discriminator, vec![0xC0, 0xFF, 0xEE]);
+    let account_state = json!({
+        "shape": { "Rectangle": [2, 3] },
+        "pairs": [[42, pubkey.to_string()]],
+        "lookup": [[1, 9]],
+        "label": [1, 2, 3, 4],
+        "authority": null,
+    });
+    let mut account_data = vec![0xC0, 0xFF, 0xEE];
+    account_data.extend_from_slice(&[7, 2, 3]);
+    account_data.extend_from_slice(&[1, 42, 0]);
+    account_data.extend_from_slice(pubkey.as_ref());
+    account_data.extend_from_slice(&[1, 0, 0, 0, 1, 9, 0]);
+    account_data.extend_from_slice(&[1, 2, 3, 4]);
+    account_data.extend_from_slice(&[0; 32]);
+    assert_eq!(idl_account.encode(&account_state).unwrap(), account_data);
+    assert_eq!(idl_account.decode(&account_data).unwrap(), account_state);
+    let mut account_data =
+        vec![0xC0, 0xFF, 0xEE, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4];
+    account_data.extend_from_slice(pubkey.as_ref());
+    assert_eq!(
+        idl_account.decode(&account_data).unwrap(),
+        json!({
+            "shape": { "Circle": { "radius": 5 } },
+            "pairs": [],
+            "lookup": [],
+            "label": [1, 2, 3, 4],
+            "authority": pubkey.to_string(),
+        })
+    );
+    // Check the event using its leading discriminator field
+    let idl_event = idl_program.events.get("MyUpdated").unwrap();
+    assert_eq!(idl_event.discriminator, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(
+        idl_event
+            .decode(&[1, 2, 3, 4, 5, 6, 7, 8, 42, 0, 0, 0, 0, 0, 0, 0])
+            .unwrap(),
+        json!({ "new_value": 42 })
+    );
+    // Check the error
+    let idl_error = idl_program.guess_error(6000).unwrap();
+    assert_eq!(idl_error.name, "MyError");
+    assert_eq!(idl_error.msg, Some("My error message".to_string()));
+    // Items relying on unsupported encodings are skipped with a warning
+    let idl_program = ToolboxIdlProgram::try_parse(&json!({
+        "kind": "rootNode",
+        "standard": "codama",
+        "version": "1.0.0",
+        "program": {
+            "kind": "programNode",
+            "name": "myProgram",
+            "definedTypes": [
+                {
+                    "kind": "definedTypeNode",
+                    "name": "myWrapper",
+                    "type": { "kind": "definedTypeLinkNode", "name": "myUnsupported" },
+                },
+                {
+                    "kind": "definedTypeNode",
+                    "name": "myUnsupported",
+                    "type": { "kind": "stringTypeNode", "encoding": "utf8" },
+                },
+                {
+                    "kind": "definedTypeNode",
+                    "name": "mySupported",
+                    "type": number("u16"),
+                },
+            ],
+            "instructions": [{
+                "kind": "instructionNode",
+                "name": "myIx",
+                "accounts": [],
+                "arguments": [{
+                    "kind": "instructionArgumentNode",
+                    "name": "wrapped",
+                    "type": { "kind": "definedTypeLinkNode", "name": "myWrapper" },
+                }],
+            }],
+        },
+    }))
+    .unwrap();
+    assert!(idl_program.typedefs.contains_key("MySupported"));
+    assert!(!idl_program.typedefs.contains_key("MyWrapper"));
+    assert!(!idl_program.instructions.contains_key("my_ix"));
+    assert_eq!(idl_program.warnings.len(), 3);
+    assert!(idl_program.warnings[0].starts_with("Convert Type: MyUnsupported"));
+    assert!(idl_program.warnings[1].starts_with("Convert Type: MyWrapper"));
+    assert!(idl_program.warnings[2].starts_with("Convert Instruction: myIx"));
+}